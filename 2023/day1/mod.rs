@@ -0,0 +1,153 @@
+use aoc_common::{Answer, ParseError, Rng, Size, Solution};
+
+const NUM_STRS: [&str; 9] = [
+    "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+];
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    type Parsed = Vec<String>;
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed, ParseError> {
+        input
+            .lines()
+            .map(|line| {
+                if line.is_ascii() {
+                    Ok(line.to_owned())
+                } else {
+                    Err(ParseError::new(line, "Expected only ASCII characters"))
+                }
+            })
+            .collect()
+    }
+
+    fn part1(&self, lines: &Self::Parsed) -> Answer {
+        let sum: u32 = lines
+            .iter()
+            .map(|line| {
+                let leftmost = line.chars().find(|c| c.is_ascii_digit());
+                let rightmost = line.chars().rfind(|c| c.is_ascii_digit());
+
+                match (leftmost, rightmost) {
+                    (Some(leftmost), Some(rightmost)) => {
+                        leftmost.to_digit(10).unwrap() * 10 + rightmost.to_digit(10).unwrap()
+                    }
+                    _ => panic!("Didn't find a single digit in the line!"),
+                }
+            })
+            .sum();
+
+        sum.into()
+    }
+
+    fn part2(&self, lines: &Self::Parsed) -> Answer {
+        let sum: u64 = lines
+            .iter()
+            .map(|line| calibration_value_with_words(line) as u64)
+            .sum();
+
+        sum.into()
+    }
+
+    fn generate(&self, rng: &mut Rng, size: Size) -> Option<String> {
+        let mut input = String::new();
+
+        for _ in 0..size.pick(10, 100, 1000) {
+            let mut pieces = Vec::new();
+
+            for _ in 0..rng.int(0..=6) {
+                let piece = match rng.index(3) {
+                    0 => rng.int(1..=9).to_string(),
+                    1 => rng.pick(&NUM_STRS).to_string(),
+                    _ => (0..rng.int(1..=6))
+                        .map(|_| (b'a' + rng.index(26) as u8) as char)
+                        .collect(),
+                };
+                pieces.push(piece);
+            }
+
+            // Part 1 needs at least one digit on every line
+            let idx = rng.index(pieces.len() + 1);
+            pieces.insert(idx, rng.int(1..=9).to_string());
+
+            input += &pieces.concat();
+            input.push('\n');
+        }
+
+        Some(input)
+    }
+}
+
+enum SearchDirection {
+    Left,
+    Right,
+}
+
+fn calibration_value_with_words(line: &str) -> u8 {
+    let mut leftmost = None;
+    let mut rightmost = None;
+
+    let line_bytes = line.as_bytes();
+
+    for (idx, c) in line_bytes.iter().enumerate() {
+        if c.is_ascii_digit() {
+            leftmost = Some(c - 0x30);
+            break;
+        }
+
+        if let Some(num) = matches_num_str(line_bytes, idx, SearchDirection::Left) {
+            leftmost = Some(num);
+            break;
+        }
+    }
+
+    for (idx, c) in line_bytes.iter().enumerate().rev() {
+        if c.is_ascii_digit() {
+            rightmost = Some(c - 0x30);
+            break;
+        }
+
+        if let Some(num) = matches_num_str(line_bytes, idx, SearchDirection::Right) {
+            rightmost = Some(num);
+            break;
+        }
+    }
+
+    match (leftmost, rightmost) {
+        (Some(leftmost), Some(rightmost)) => leftmost * 10 + rightmost,
+        _ => panic!("Didn't find a single digit in the line!"),
+    }
+}
+
+fn matches_num_str(line_bytes: &[u8], idx: usize, search_direction: SearchDirection) -> Option<u8> {
+    for (num_idx, num_str) in NUM_STRS.iter().enumerate() {
+        let search_range = match search_direction {
+            SearchDirection::Left => {
+                let rbound = std::cmp::min(idx + num_str.len(), line_bytes.len() - 1);
+                &line_bytes[idx..rbound]
+            }
+            SearchDirection::Right => {
+                let lbound = std::cmp::max(idx as isize - (num_str.len() - 1) as isize, 0) as usize;
+                &line_bytes[lbound..=idx]
+            }
+        };
+
+        if num_str.as_bytes() == search_range {
+            return Some(num_idx as u8 + 1);
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    aoc_common::example_tests! {
+        Day1,
+        part1_example: part1(include_str!("example")) == 142,
+        part2_example: part2(include_str!("example2")) == 281,
+    }
+}
@@ -0,0 +1,336 @@
+use aoc_common::{generate, Answer, Direction, Grid, ParseError, Rng, Size, Solution};
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Parsed = Grid<Tile>;
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed, ParseError> {
+        parse_tile_grid(input)
+    }
+
+    fn part1(&self, tile_grid: &Self::Parsed) -> Answer {
+        let steps = count_steps_in_grid(tile_grid);
+
+        let farthest_steps = steps / 2;
+
+        farthest_steps.into()
+    }
+
+    fn part2(&self, tile_grid: &Self::Parsed) -> Answer {
+        let marked_grid = map_to_marked_grid(tile_grid);
+
+        let tiles_enclosed = count_enclosed_tiles(&marked_grid);
+
+        tiles_enclosed.into()
+    }
+
+    fn generate(&self, rng: &mut Rng, size: Size) -> Option<String> {
+        let side = size.pick(5, 20, 69);
+        let region = generate::region(rng, side, side, side * side / 2);
+        let corners = generate::outline(&region);
+
+        // The loop runs through the corners of the region's cells, with a
+        // pipe between each two of them
+        let mut tiles = Grid::new(side * 2 + 1, side * 2 + 1, '.');
+        let mut on_loop = Vec::new();
+
+        for (idx, &(x, y)) in corners.iter().enumerate() {
+            let (next_x, next_y) = corners[(idx + 1) % corners.len()];
+            on_loop.push((x * 2, y * 2));
+            on_loop.push((x + next_x, y + next_y));
+        }
+
+        for (idx, &(x, y)) in on_loop.iter().enumerate() {
+            let prev = on_loop[(idx + on_loop.len() - 1) % on_loop.len()];
+            let next = on_loop[(idx + 1) % on_loop.len()];
+            let towards = |(to_x, to_y): (usize, usize)| {
+                (to_x as isize - x as isize, to_y as isize - y as isize)
+            };
+
+            let mut ends = [towards(prev), towards(next)];
+            ends.sort_unstable();
+
+            tiles[(x, y)] = match ends {
+                [(0, -1), (0, 1)] => '|',
+                [(-1, 0), (1, 0)] => '-',
+                [(0, -1), (1, 0)] => 'L',
+                [(-1, 0), (0, -1)] => 'J',
+                [(-1, 0), (0, 1)] => '7',
+                [(0, 1), (1, 0)] => 'F',
+                _ => unreachable!("Loop tiles connect to two neighbours"),
+            };
+        }
+
+        let start = *rng.pick(&on_loop);
+
+        for pos in tiles.positions().collect::<Vec<_>>() {
+            let next_to_start = pos.0.abs_diff(start.0) + pos.1.abs_diff(start.1) == 1;
+
+            // Junk pipes around the loop, but none connecting to the start
+            if tiles[pos] == '.' && !next_to_start && rng.chance(0.6) {
+                tiles[pos] = *rng.pick(&['|', '-', 'L', 'J', '7', 'F']);
+            }
+        }
+        tiles[start] = 'S';
+
+        Some(
+            tiles
+                .rows()
+                .map(|row| row.iter().collect::<String>() + "\n")
+                .collect(),
+        )
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum Tile {
+    Vertical,
+    Horizontal,
+    NorthEast,
+    NorthWest,
+    SouthWest,
+    SouthEast,
+    Ground,
+    Start,
+}
+
+impl TryFrom<char> for Tile {
+    type Error = ();
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            '|' => Ok(Tile::Vertical),
+            '-' => Ok(Tile::Horizontal),
+            'L' => Ok(Tile::NorthEast),
+            'J' => Ok(Tile::NorthWest),
+            '7' => Ok(Tile::SouthWest),
+            'F' => Ok(Tile::SouthEast),
+            '.' => Ok(Tile::Ground),
+            'S' => Ok(Tile::Start),
+            _ => Err(()),
+        }
+    }
+}
+
+impl Tile {
+    pub fn out_direction(&self, in_direction: Direction) -> Option<Direction> {
+        match self {
+            Tile::Vertical => match in_direction {
+                Direction::Up => Some(Direction::Up),
+                Direction::Right => None,
+                Direction::Down => Some(Direction::Down),
+                Direction::Left => None,
+            },
+            Tile::Horizontal => match in_direction {
+                Direction::Up => None,
+                Direction::Right => Some(Direction::Right),
+                Direction::Down => None,
+                Direction::Left => Some(Direction::Left),
+            },
+            Tile::NorthEast => match in_direction {
+                Direction::Up => None,
+                Direction::Right => None,
+                Direction::Down => Some(Direction::Right),
+                Direction::Left => Some(Direction::Up),
+            },
+            Tile::NorthWest => match in_direction {
+                Direction::Up => None,
+                Direction::Right => Some(Direction::Up),
+                Direction::Down => Some(Direction::Left),
+                Direction::Left => None,
+            },
+            Tile::SouthWest => match in_direction {
+                Direction::Up => Some(Direction::Left),
+                Direction::Right => Some(Direction::Down),
+                Direction::Down => None,
+                Direction::Left => None,
+            },
+            Tile::SouthEast => match in_direction {
+                Direction::Up => Some(Direction::Right),
+                Direction::Right => None,
+                Direction::Down => None,
+                Direction::Left => Some(Direction::Down),
+            },
+            Tile::Ground => match in_direction {
+                Direction::Up => None,
+                Direction::Right => None,
+                Direction::Down => None,
+                Direction::Left => None,
+            },
+            Tile::Start => match in_direction {
+                Direction::Up => None,
+                Direction::Right => None,
+                Direction::Down => None,
+                Direction::Left => None,
+            },
+        }
+    }
+
+    pub fn grid_marking(&self) -> GridMarking {
+        match self {
+            Tile::Vertical => GridMarking::Vertical,
+            Tile::Horizontal => GridMarking::Horizontal,
+            Tile::NorthEast => GridMarking::CornerUp,
+            Tile::NorthWest => GridMarking::CornerUp,
+            Tile::SouthWest => GridMarking::CornerDown,
+            Tile::SouthEast => GridMarking::CornerDown,
+            Tile::Ground => GridMarking::None,
+            Tile::Start => GridMarking::None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum GridMarking {
+    None,
+    Horizontal,
+    Vertical,
+    CornerUp,
+    CornerDown,
+}
+
+impl GridMarking {
+    pub fn crosses(&self, dir: Direction) -> bool {
+        match self {
+            GridMarking::None => false,
+            GridMarking::Horizontal => matches!(dir, Direction::Up | Direction::Down),
+            GridMarking::Vertical => matches!(dir, Direction::Right | Direction::Left),
+            GridMarking::CornerUp => false,
+            GridMarking::CornerDown => false,
+        }
+    }
+}
+
+fn count_enclosed_tiles(marked_grid: &Grid<GridMarking>) -> usize {
+    let mut count = 0;
+
+    let max_x = marked_grid.width() - 1;
+
+    for row in marked_grid.rows() {
+        for x in 0..=max_x {
+            if matches!(row[x], GridMarking::None) {
+                let mut corners_up: usize = 0;
+                let mut corners_down: usize = 0;
+
+                let crossings: usize = (0..=x).fold(0, |acc, x_pos| {
+                    match row[x_pos] {
+                        GridMarking::CornerUp => {
+                            corners_up += 1;
+                        }
+                        GridMarking::CornerDown => {
+                            corners_down += 1;
+                        }
+                        _ => {}
+                    }
+
+                    if row[x_pos].crosses(Direction::Left) {
+                        acc + 1
+                    } else {
+                        acc
+                    }
+                });
+
+                let corner_crossings = std::cmp::min(corners_up, corners_down);
+
+                if (crossings + corner_crossings) % 2 == 1 {
+                    count += 1;
+                }
+            }
+        }
+    }
+
+    count
+}
+
+fn count_steps_in_grid(tile_grid: &Grid<Tile>) -> usize {
+    let mut steps = 0;
+
+    let mut pos = tile_grid
+        .position(|t| matches!(t, Tile::Start))
+        .expect("No start found");
+    let mut direction = find_start_direction(tile_grid, pos).expect("No start direction found");
+
+    loop {
+        steps += 1;
+
+        pos = tile_grid
+            .step(pos, direction)
+            .expect("Failed to step in direction");
+
+        let tile = tile_grid[pos];
+
+        if matches!(tile, Tile::Start) {
+            break;
+        }
+
+        direction = tile.out_direction(direction).expect("Can't walk that way");
+    }
+
+    steps
+}
+
+fn map_to_marked_grid(tile_grid: &Grid<Tile>) -> Grid<GridMarking> {
+    let mut marked_grid = Grid::new(tile_grid.width(), tile_grid.height(), GridMarking::None);
+
+    let mut pos = tile_grid
+        .position(|t| matches!(t, Tile::Start))
+        .expect("No start found");
+    let mut direction = find_start_direction(tile_grid, pos).expect("No start direction found");
+    let start_direction = direction;
+
+    loop {
+        pos = tile_grid
+            .step(pos, direction)
+            .expect("Failed to step in direction");
+
+        let tile = tile_grid[pos];
+
+        if matches!(tile, Tile::Start) {
+            // The start tile connects to where we left it and to where we came back from
+            let start_marking = match (start_direction, direction.opposite()) {
+                (Direction::Up, Direction::Down) | (Direction::Down, Direction::Up) => {
+                    GridMarking::Vertical
+                }
+                (Direction::Right, Direction::Left) | (Direction::Left, Direction::Right) => {
+                    GridMarking::Horizontal
+                }
+                (Direction::Up, _) | (_, Direction::Up) => GridMarking::CornerUp,
+                (Direction::Down, _) | (_, Direction::Down) => GridMarking::CornerDown,
+                _ => unreachable!(),
+            };
+            marked_grid[pos] = start_marking;
+
+            break;
+        }
+
+        marked_grid[pos] = tile.grid_marking();
+
+        direction = tile.out_direction(direction).expect("Can't walk that way");
+    }
+
+    marked_grid
+}
+
+fn find_start_direction(tile_grid: &Grid<Tile>, start: (usize, usize)) -> Option<Direction> {
+    Direction::ALL.into_iter().find(|direction| {
+        tile_grid
+            .step(start, *direction)
+            .is_some_and(|pos| tile_grid[pos].out_direction(*direction).is_some())
+    })
+}
+
+fn parse_tile_grid(input: &str) -> Result<Grid<Tile>, ParseError> {
+    Grid::parse(input, |c| Tile::try_from(c).ok())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    aoc_common::example_tests! {
+        Day10,
+        part1_example: part1(include_str!("example")) == 8,
+        part2_example: part2(include_str!("example2")) == 4,
+    }
+}
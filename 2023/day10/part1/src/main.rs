@@ -1,210 +0,0 @@
-use std::{
-    env,
-    fs::File,
-    io::{BufRead, BufReader},
-};
-
-fn main() {
-    let Some(filepath) = env::args().nth(1) else {
-        println!("Usage: part1 <filepath>");
-        return;
-    };
-
-    let file = match File::open(filepath) {
-        Ok(file) => file,
-        Err(err) => {
-            println!("Error opening input file: {err}");
-            return;
-        }
-    };
-
-    let reader = BufReader::new(file);
-
-    let tile_grid = parse_tile_grid(reader);
-
-    let steps = count_steps_in_grid(&tile_grid);
-
-    let farthest_steps = steps / 2;
-
-    println!("Farthest steps: {farthest_steps}");
-}
-
-#[derive(Debug, Clone, Copy)]
-enum Direction {
-    North,
-    East,
-    South,
-    West,
-}
-
-impl Direction {
-    pub fn step(&self, x: usize, y: usize) -> (usize, usize) {
-        match self {
-            Direction::North => (x, y - 1),
-            Direction::East => (x + 1, y),
-            Direction::South => (x, y + 1),
-            Direction::West => (x - 1, y),
-        }
-    }
-}
-
-#[derive(Debug, Clone, Copy)]
-enum Tile {
-    Vertical,
-    Horizontal,
-    NorthEast,
-    NorthWest,
-    SouthWest,
-    SouthEast,
-    Ground,
-    Start,
-}
-
-impl TryFrom<char> for Tile {
-    type Error = ();
-
-    fn try_from(value: char) -> Result<Self, Self::Error> {
-        match value {
-            '|' => Ok(Tile::Vertical),
-            '-' => Ok(Tile::Horizontal),
-            'L' => Ok(Tile::NorthEast),
-            'J' => Ok(Tile::NorthWest),
-            '7' => Ok(Tile::SouthWest),
-            'F' => Ok(Tile::SouthEast),
-            '.' => Ok(Tile::Ground),
-            'S' => Ok(Tile::Start),
-            _ => Err(()),
-        }
-    }
-}
-
-impl Tile {
-    pub fn out_direction(&self, in_direction: Direction) -> Option<Direction> {
-        match self {
-            Tile::Vertical => match in_direction {
-                Direction::North => Some(Direction::North),
-                Direction::East => None,
-                Direction::South => Some(Direction::South),
-                Direction::West => None,
-            },
-            Tile::Horizontal => match in_direction {
-                Direction::North => None,
-                Direction::East => Some(Direction::East),
-                Direction::South => None,
-                Direction::West => Some(Direction::West),
-            },
-            Tile::NorthEast => match in_direction {
-                Direction::North => None,
-                Direction::East => None,
-                Direction::South => Some(Direction::East),
-                Direction::West => Some(Direction::North),
-            },
-            Tile::NorthWest => match in_direction {
-                Direction::North => None,
-                Direction::East => Some(Direction::North),
-                Direction::South => Some(Direction::West),
-                Direction::West => None,
-            },
-            Tile::SouthWest => match in_direction {
-                Direction::North => Some(Direction::West),
-                Direction::East => Some(Direction::South),
-                Direction::South => None,
-                Direction::West => None,
-            },
-            Tile::SouthEast => match in_direction {
-                Direction::North => Some(Direction::East),
-                Direction::East => None,
-                Direction::South => None,
-                Direction::West => Some(Direction::South),
-            },
-            Tile::Ground => match in_direction {
-                Direction::North => None,
-                Direction::East => None,
-                Direction::South => None,
-                Direction::West => None,
-            },
-            Tile::Start => match in_direction {
-                Direction::North => None,
-                Direction::East => None,
-                Direction::South => None,
-                Direction::West => None,
-            },
-        }
-    }
-}
-
-fn count_steps_in_grid(tile_grid: &[Vec<Tile>]) -> usize {
-    let mut steps = 0;
-
-    let (mut x, mut y) = find_start(tile_grid).expect("No start found");
-    let mut direction = find_start_direction(tile_grid, (x, y)).expect("No start direction found");
-
-    loop {
-        steps += 1;
-
-        let (new_x, new_y) = direction.step(x, y);
-        x = new_x;
-        y = new_y;
-
-        let tile = tile_grid[new_y][new_x];
-
-        if matches!(tile, Tile::Start) {
-            break;
-        }
-
-        direction = tile.out_direction(direction).expect("Can't walk that way");
-    }
-
-    steps
-}
-
-fn find_start_direction(tile_grid: &[Vec<Tile>], start: (usize, usize)) -> Option<Direction> {
-    const DIRECTIONS: [Direction; 4] = [
-        Direction::North,
-        Direction::East,
-        Direction::South,
-        Direction::West,
-    ];
-
-    for direction in DIRECTIONS {
-        let (x, y) = direction.step(start.0, start.1);
-
-        let tile = tile_grid[y][x];
-
-        if tile.out_direction(direction).is_some() {
-            return Some(direction);
-        }
-    }
-
-    None
-}
-
-fn find_start(tile_grid: &[Vec<Tile>]) -> Option<(usize, usize)> {
-    let mut x_pos = None;
-
-    let y = tile_grid.iter().position(|tiles| {
-        let x = tiles.iter().position(|t| matches!(t, Tile::Start));
-
-        x_pos = x;
-
-        x.is_some()
-    });
-
-    match (x_pos, y) {
-        (Some(x), Some(y)) => Some((x, y)),
-        _ => None,
-    }
-}
-
-fn parse_tile_grid(reader: BufReader<File>) -> Vec<Vec<Tile>> {
-    reader
-        .lines()
-        .map(|line| {
-            let line = line.expect("Failed to read line");
-
-            line.chars()
-                .map(|c| Tile::try_from(c).expect("Unexpected tile char"))
-                .collect()
-        })
-        .collect()
-}
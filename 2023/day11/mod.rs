@@ -0,0 +1,149 @@
+use std::collections::HashSet;
+
+use aoc_common::{Answer, ParseError, Rng, Size, Solution};
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Parsed = GalaxyMap;
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed, ParseError> {
+        parse_map(input)
+    }
+
+    fn part1(&self, galaxy_map: &Self::Parsed) -> Answer {
+        sum_of_distances(&galaxy_map.expanded_positions(2)).into()
+    }
+
+    fn part2(&self, galaxy_map: &Self::Parsed) -> Answer {
+        sum_of_distances(&galaxy_map.expanded_positions(1_000_000)).into()
+    }
+
+    fn generate(&self, rng: &mut Rng, size: Size) -> Option<String> {
+        let (side, density) = size.pick((10, 0.1), (40, 0.05), (140, 0.025));
+        let empty_rows = (0..side).map(|_| rng.chance(0.05)).collect::<Vec<_>>();
+        let empty_columns = (0..side).map(|_| rng.chance(0.05)).collect::<Vec<_>>();
+
+        let mut input = String::new();
+
+        for empty_row in &empty_rows {
+            for empty_column in &empty_columns {
+                let galaxy = !empty_row && !empty_column && rng.chance(density);
+                input.push(if galaxy { '#' } else { '.' });
+            }
+            input.push('\n');
+        }
+
+        Some(input)
+    }
+}
+
+/// Galaxy positions before expansion, plus the columns and rows without any galaxy.
+pub struct GalaxyMap {
+    positions: Vec<(usize, usize)>,
+    free_xs: HashSet<usize>,
+    free_ys: HashSet<usize>,
+}
+
+impl GalaxyMap {
+    /// Positions after every empty column and row grew to `factor` times its size.
+    pub fn expanded_positions(&self, factor: usize) -> Vec<(usize, usize)> {
+        self.positions
+            .iter()
+            .map(|&(x, y)| {
+                let x_add = self.free_xs.iter().filter(|fx| x > **fx).count();
+                let y_add = self.free_ys.iter().filter(|fy| y > **fy).count();
+
+                (x + (x_add * (factor - 1)), y + (y_add * (factor - 1)))
+            })
+            .collect()
+    }
+}
+
+fn sum_of_distances(galaxy_positions: &[(usize, usize)]) -> usize {
+    let galaxy_pairs = get_galaxy_pairs(galaxy_positions);
+
+    galaxy_pairs.into_iter().map(|gp| gp.step_distance()).sum()
+}
+
+#[derive(Debug)]
+struct GalaxyPair {
+    a: (usize, usize),
+    b: (usize, usize),
+}
+
+impl GalaxyPair {
+    pub fn step_distance(&self) -> usize {
+        self.a.0.abs_diff(self.b.0) + self.a.1.abs_diff(self.b.1)
+    }
+}
+
+fn get_galaxy_pairs(galaxy_positions: &[(usize, usize)]) -> Vec<GalaxyPair> {
+    let mut pairs = Vec::new();
+
+    for (idx, pos1) in galaxy_positions.iter().enumerate() {
+        for pos2 in galaxy_positions.iter().skip(idx + 1) {
+            pairs.push(GalaxyPair {
+                a: (pos1.0, pos1.1),
+                b: (pos2.0, pos2.1),
+            })
+        }
+    }
+
+    pairs
+}
+
+fn parse_map(input: &str) -> Result<GalaxyMap, ParseError> {
+    let mut positions = Vec::new();
+    let mut x_coords = HashSet::new();
+    let mut y_coords = HashSet::new();
+
+    let mut max_y = 0;
+    let mut max_x = 0;
+
+    for (y, line) in input.lines().enumerate() {
+        max_y = y;
+
+        for (x, (idx, c)) in line.char_indices().enumerate() {
+            max_x = x;
+
+            match c {
+                '#' => {
+                    positions.push((x, y));
+                    x_coords.insert(x);
+                    y_coords.insert(y);
+                }
+                '.' => {}
+                _ => {
+                    return Err(ParseError::new(
+                        &line[idx..idx + c.len_utf8()],
+                        "Unexpected character",
+                    ));
+                }
+            }
+        }
+    }
+
+    let all_ys: HashSet<usize> = (0..=max_y).collect();
+    let all_xs: HashSet<usize> = (0..=max_x).collect();
+
+    let free_ys: HashSet<usize> = all_ys.difference(&y_coords).copied().collect();
+    let free_xs: HashSet<usize> = all_xs.difference(&x_coords).copied().collect();
+
+    Ok(GalaxyMap {
+        positions,
+        free_xs,
+        free_ys,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    aoc_common::example_tests! {
+        Day11,
+        part1_example: part1(include_str!("example")) == 374,
+        part2_example: part2(include_str!("example")) == 82000210,
+    }
+}
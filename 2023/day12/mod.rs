@@ -0,0 +1,322 @@
+use aoc_common::{parse_num, Answer, ParseError, Rng, Size, Solution};
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Parsed = Vec<ConditionRecord>;
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed, ParseError> {
+        parse_condition_records(input)
+    }
+
+    fn part1(&self, condition_records: &Self::Parsed) -> Answer {
+        let sum: usize = condition_records
+            .iter()
+            .map(|cr| cr.count_possible_arrangements())
+            .sum();
+
+        sum.into()
+    }
+
+    fn part2(&self, condition_records: &Self::Parsed) -> Answer {
+        let unfolded_condition_records: Vec<ConditionRecord> = condition_records
+            .iter()
+            .map(|cr| {
+                let cr = cr.unfold();
+
+                tracing::trace!(?cr, "unfolded");
+
+                cr
+            })
+            .collect();
+
+        let sum: usize = unfolded_condition_records
+            .into_iter()
+            .map(|cr| {
+                let arr = cr.count_possible_arrangements();
+
+                tracing::debug!(arrangements = arr, "counted");
+
+                arr
+            })
+            .sum();
+
+        sum.into()
+    }
+
+    fn generate(&self, rng: &mut Rng, size: Size) -> Option<String> {
+        let (records, max_len) = size.pick((10, 10), (100, 15), (1000, 20));
+        let mut input = String::new();
+
+        for _ in 0..records {
+            // Lay out a valid arrangement first, then hide some of it
+            let mut conditions = ".".repeat(rng.int(0..=2) as usize);
+            let mut groups = Vec::new();
+
+            loop {
+                let group = rng.int(1..=6) as usize;
+                if !groups.is_empty() && conditions.len() + group > max_len {
+                    break;
+                }
+
+                conditions += &"#".repeat(group);
+                conditions += &".".repeat(rng.int(1..=3) as usize);
+                groups.push(group.to_string());
+            }
+            conditions.truncate(max_len.max(conditions.trim_end_matches('.').len()));
+
+            let conditions = conditions
+                .chars()
+                .map(|c| if rng.chance(0.5) { '?' } else { c })
+                .collect::<String>();
+
+            input += &format!("{conditions} {}\n", groups.join(","));
+        }
+
+        Some(input)
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum Condition {
+    Operational,
+    Damaged,
+    Unknown,
+}
+
+impl TryFrom<char> for Condition {
+    type Error = ();
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            '.' => Ok(Condition::Operational),
+            '#' => Ok(Condition::Damaged),
+            '?' => Ok(Condition::Unknown),
+            _ => Err(()),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct ConditionRecord {
+    conditions: Vec<Condition>,
+    damaged_groups: Vec<usize>,
+}
+
+impl ConditionRecord {
+    /// The record with its conditions repeated five times, joined by an unknown.
+    pub fn unfold(&self) -> ConditionRecord {
+        let mut unfolded = self.clone();
+
+        let mut conditions_copy = self.conditions.clone();
+        conditions_copy.insert(0, Condition::Unknown);
+
+        for _ in 0..4 {
+            unfolded.conditions.append(&mut conditions_copy.clone());
+            unfolded
+                .damaged_groups
+                .append(&mut self.damaged_groups.clone());
+        }
+
+        unfolded
+    }
+
+    pub fn count_possible_arrangements(&self) -> usize {
+        let conditions = &self.conditions;
+        let groups = &self.damaged_groups;
+        let len = conditions.len();
+
+        // counts[idx][group]: the arrangements of conditions[idx..] that hold
+        // damaged_groups[group..]
+        let mut counts = vec![vec![0; groups.len() + 1]; len + 2];
+        counts[len][groups.len()] = 1;
+        counts[len + 1][groups.len()] = 1;
+
+        for idx in (0..len).rev() {
+            for group in 0..=groups.len() {
+                let mut count = 0;
+
+                if !matches!(conditions[idx], Condition::Damaged) {
+                    count += counts[idx + 1][group];
+                }
+
+                if let Some(&group_len) = groups.get(group) {
+                    let end = idx + group_len;
+                    let fits = end <= len
+                        && !conditions[idx..end]
+                            .iter()
+                            .any(|c| matches!(c, Condition::Operational))
+                        && !matches!(conditions.get(end), Some(Condition::Damaged));
+
+                    // The group and the operational spring after it
+                    if !matches!(conditions[idx], Condition::Operational) && fits {
+                        count += counts[end + 1][group + 1];
+                    }
+                }
+
+                counts[idx][group] = count;
+            }
+        }
+
+        counts[0][0]
+    }
+
+    /// Counts the arrangements by trying every one of them, which is only
+    /// feasible for short records.
+    #[cfg(test)]
+    fn count_possible_arrangements_by_enumeration(&self) -> usize {
+        let mut count = 0;
+
+        for possibility in self.get_all_possible_conditions() {
+            let damaged_counts = count_damaged_groups(possibility);
+
+            if damaged_counts == self.damaged_groups {
+                count += 1;
+            }
+        }
+
+        count
+    }
+
+    #[cfg(test)]
+    fn get_all_possible_conditions(&self) -> Vec<Vec<Condition>> {
+        let mut possibilities = vec![Vec::with_capacity(self.conditions.len())];
+
+        for c in self.conditions.iter() {
+            match c {
+                Condition::Operational | Condition::Damaged => {
+                    possibilities
+                        .iter_mut()
+                        .for_each(|possibility| possibility.push(*c));
+                }
+                Condition::Unknown => {
+                    possibilities
+                        .iter_mut()
+                        .for_each(|possibility| possibility.push(Condition::Operational));
+
+                    for i in 0..possibilities.len() {
+                        let mut clone = possibilities[i].clone();
+                        let len = clone.len();
+                        clone[len - 1] = Condition::Damaged;
+                        possibilities.push(clone);
+                    }
+                }
+            }
+        }
+
+        possibilities
+    }
+}
+
+#[cfg(test)]
+fn count_damaged_groups(conditions: Vec<Condition>) -> Vec<usize> {
+    let mut counts = Vec::new();
+
+    let mut in_damaged_group = false;
+
+    for c in conditions {
+        match c {
+            Condition::Damaged => {
+                if !in_damaged_group {
+                    counts.push(0);
+                    in_damaged_group = true;
+                }
+
+                *counts.last_mut().unwrap() += 1;
+            }
+            Condition::Operational | Condition::Unknown => {
+                in_damaged_group = false;
+            }
+        }
+    }
+
+    counts
+}
+
+fn parse_condition_records(input: &str) -> Result<Vec<ConditionRecord>, ParseError> {
+    input
+        .lines()
+        .map(|line| {
+            let Some((conditions_part, groups_part)) = line.split_once(' ') else {
+                return Err(ParseError::new(line, "Expected '<conditions> <groups>'"));
+            };
+
+            let conditions = conditions_part
+                .char_indices()
+                .map(|(idx, c)| {
+                    Condition::try_from(c).map_err(|_| {
+                        ParseError::new(
+                            &conditions_part[idx..idx + c.len_utf8()],
+                            "Failed to parse condition",
+                        )
+                    })
+                })
+                .collect::<Result<_, _>>()?;
+
+            let damaged_groups = groups_part
+                .split(',')
+                .map(parse_num)
+                .collect::<Result<_, _>>()?;
+
+            Ok(ConditionRecord {
+                conditions,
+                damaged_groups,
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use aoc_common::differential::{assert_equivalent, shrink_vec};
+
+    aoc_common::example_tests! {
+        Day12,
+        part1_example: part1(include_str!("example")) == 21,
+        part2_example: part2(include_str!("example")) == 525152,
+    }
+
+    #[test]
+    fn counting_matches_enumeration() {
+        let records = |rng: &mut Rng| {
+            let input = Day12.generate(rng, Size::Small).unwrap();
+            parse_condition_records(&input).unwrap()
+        };
+        let shrink = |records: &Vec<ConditionRecord>| {
+            let mut smaller = shrink_vec(records);
+
+            for (idx, record) in records.iter().enumerate() {
+                for conditions in shrink_vec(&record.conditions) {
+                    let mut records = records.clone();
+                    records[idx].conditions = conditions;
+                    smaller.push(records);
+                }
+                for damaged_groups in shrink_vec(&record.damaged_groups) {
+                    let mut records = records.clone();
+                    records[idx].damaged_groups = damaged_groups;
+                    smaller.push(records);
+                }
+            }
+
+            smaller
+        };
+        let count = |records: &Vec<ConditionRecord>, f: fn(&ConditionRecord) -> usize| {
+            records.iter().map(f).collect::<Vec<_>>()
+        };
+
+        assert_equivalent(
+            50,
+            records,
+            shrink,
+            |records| {
+                count(
+                    records,
+                    ConditionRecord::count_possible_arrangements_by_enumeration,
+                )
+            },
+            |records| count(records, ConditionRecord::count_possible_arrangements),
+        );
+    }
+}
@@ -0,0 +1,251 @@
+use aoc_common::{diagnostics, Answer, ParseError, Rng, Size, Solution};
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    type Parsed = Vec<Pattern>;
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed, ParseError> {
+        parse_patterns(input)
+    }
+
+    fn part1(&self, patterns: &Self::Parsed) -> Answer {
+        summarize(patterns, 0).into()
+    }
+
+    fn part2(&self, patterns: &Self::Parsed) -> Answer {
+        summarize(patterns, 1).into()
+    }
+
+    fn generate(&self, rng: &mut Rng, size: Size) -> Option<String> {
+        let patterns = (0..size.pick(5, 20, 100))
+            .map(|_| {
+                let rows = generate_pattern(rng);
+
+                // Mirrored between columns instead of rows half of the time
+                let rows = if rng.chance(0.5) {
+                    (0..rows[0].len())
+                        .map(|x| rows.iter().map(|row| row[x]).collect())
+                        .collect()
+                } else {
+                    rows
+                };
+
+                rows.iter()
+                    .map(|row| {
+                        row.iter()
+                            .map(|ash| if *ash { '#' } else { '.' })
+                            .collect::<String>()
+                            + "\n"
+                    })
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>();
+
+        Some(patterns.join("\n"))
+    }
+}
+
+#[derive(Debug)]
+pub enum AxisOrientation {
+    Horizontal,
+    Vertical,
+}
+
+pub struct Pattern {
+    rows: Vec<u64>,
+    columns: Vec<u64>,
+}
+
+impl Pattern {
+    /// Finds the axis at which both halves differ in exactly `smudges` cells.
+    pub fn get_mirror_axis(&self, smudges: u32) -> (AxisOrientation, usize) {
+        for axis_idx in 1..self.rows.len() {
+            let range_len = std::cmp::min(axis_idx, self.rows.len() - axis_idx);
+
+            let range_before = &self.rows[(axis_idx - range_len)..axis_idx];
+            let range_after = &self.rows[axis_idx..(axis_idx + range_len)];
+
+            if count_differences(range_len, range_before, range_after) == smudges {
+                return (AxisOrientation::Horizontal, axis_idx);
+            }
+        }
+
+        for axis_idx in 1..self.columns.len() {
+            let range_len = std::cmp::min(axis_idx, self.columns.len() - axis_idx);
+
+            let range_before = &self.columns[(axis_idx - range_len)..axis_idx];
+            let range_after = &self.columns[axis_idx..(axis_idx + range_len)];
+
+            if count_differences(range_len, range_before, range_after) == smudges {
+                return (AxisOrientation::Vertical, axis_idx);
+            }
+        }
+
+        unreachable!()
+    }
+}
+
+fn count_differences(range_len: usize, range_a: &[u64], range_b: &[u64]) -> u32 {
+    range_a
+        .iter()
+        .enumerate()
+        .map(|(idx, a)| (a ^ range_b[range_len - idx - 1]).count_ones())
+        .sum()
+}
+
+fn summarize(patterns: &[Pattern], smudges: u32) -> usize {
+    patterns
+        .iter()
+        .map(|p| {
+            let (orientation, axis_idx) = p.get_mirror_axis(smudges);
+
+            diagnostics::record("mirror_axis", format!("{orientation:?} {axis_idx}"));
+
+            match orientation {
+                AxisOrientation::Horizontal => axis_idx * 100,
+                AxisOrientation::Vertical => axis_idx,
+            }
+        })
+        .sum()
+}
+
+fn parse_patterns(input: &str) -> Result<Vec<Pattern>, ParseError> {
+    let mut patterns = Vec::new();
+
+    let mut rows = Vec::new();
+
+    let mut row_len = 0;
+
+    for line in input.lines() {
+        if !line.is_empty() {
+            row_len = line.chars().count();
+
+            if row_len > 64 {
+                return Err(ParseError::new(line, "Rows can't be longer than 64"));
+            }
+
+            let mut num = 0;
+            for (idx, c) in line.char_indices() {
+                match c {
+                    '#' => num |= 1 << idx,
+                    '.' => {}
+                    _ => {
+                        return Err(ParseError::new(
+                            &line[idx..idx + c.len_utf8()],
+                            "Unexpected character",
+                        ))
+                    }
+                }
+            }
+            rows.push(num);
+        } else {
+            let mut columns = Vec::new();
+
+            for col_idx in 0..row_len {
+                let mut num = 0;
+
+                let current_pos = 1 << col_idx;
+
+                for (row_idx, row) in rows.iter().enumerate() {
+                    if row & current_pos > 0 {
+                        num |= 1 << row_idx;
+                    }
+                }
+
+                columns.push(num);
+            }
+
+            patterns.push(Pattern {
+                rows: std::mem::take(&mut rows),
+                columns,
+            });
+        }
+    }
+
+    let mut columns = Vec::new();
+
+    for col_idx in 0..row_len {
+        let mut num = 0;
+
+        let current_pos = 1 << col_idx;
+
+        for (row_idx, row) in rows.iter().enumerate() {
+            if row & current_pos > 0 {
+                num |= 1 << row_idx;
+            }
+        }
+
+        columns.push(num);
+    }
+
+    patterns.push(Pattern {
+        rows: std::mem::take(&mut rows),
+        columns,
+    });
+
+    Ok(patterns)
+}
+
+/// Random rows mirrored perfectly at one axis and with exactly one difference
+/// at another, so both parts find an axis.
+fn generate_pattern(rng: &mut Rng) -> Vec<Vec<bool>> {
+    loop {
+        let height = rng.int(5..=17) as usize;
+        let width = rng.int(5..=17) as usize;
+        let (clean, smudged) = (
+            rng.int(1..=height as i64 - 1),
+            rng.int(1..=height as i64 - 1),
+        );
+        if clean == smudged {
+            continue;
+        }
+
+        let mirrored = |axis: i64| {
+            let axis = axis as usize;
+            (0..axis.min(height - axis)).map(move |i| (axis - 1 - i, axis + i))
+        };
+        let smudge = rng.int(0..=mirrored(smudged).count() as i64 - 1) as usize;
+        let smudge = mirrored(smudged).nth(smudge).unwrap();
+
+        // Rows that have to be equal end up with the same root
+        let mut parents = (0..height).collect::<Vec<_>>();
+        let root = |parents: &[usize], mut row: usize| {
+            while parents[row] != row {
+                row = parents[row];
+            }
+            row
+        };
+
+        for (a, b) in mirrored(clean).chain(mirrored(smudged).filter(|pair| *pair != smudge)) {
+            let (a, b) = (root(&parents, a), root(&parents, b));
+            parents[a] = b;
+        }
+
+        let (a, b) = (root(&parents, smudge.0), root(&parents, smudge.1));
+        if a == b {
+            continue;
+        }
+
+        let mut values = (0..height)
+            .map(|_| (0..width).map(|_| rng.chance(0.5)).collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+        values[b] = values[a].clone();
+        values[b][rng.index(width)] ^= true;
+
+        return (0..height)
+            .map(|row| values[root(&parents, row)].clone())
+            .collect();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    aoc_common::example_tests! {
+        Day13,
+        part1_example: part1(include_str!("example")) == 405,
+        part2_example: part2(include_str!("example")) == 400,
+    }
+}
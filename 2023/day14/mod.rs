@@ -1,72 +1,64 @@
-use std::{
-    collections::{hash_map::DefaultHasher, HashMap},
-    env,
-    fs::File,
-    hash::{Hash, Hasher},
-    io::{BufRead, BufReader},
-};
-
-fn main() {
-    let Some(filepath) = env::args().nth(1) else {
-        println!("Usage: part2 <filepath>");
-        return;
-    };
-
-    let file = match File::open(filepath) {
-        Ok(file) => file,
-        Err(err) => {
-            println!("Error opening input file: {err}");
-            return;
-        }
-    };
-
-    let reader = BufReader::new(file);
+use aoc_common::{cycle::fast_forward, Answer, ParseError, Rng, Size, Solution};
 
-    let mut columns = parse_columns(reader);
+pub struct Day14;
 
-    do_cycles(&mut columns);
+impl Solution for Day14 {
+    type Parsed = Vec<Vec<Object>>;
 
-    let sum: usize = columns.iter().map(|c| weight_of_column(c)).sum();
+    fn parse(&self, input: &str) -> Result<Self::Parsed, ParseError> {
+        parse_columns(input)
+    }
 
-    println!("Sum {sum}");
-}
+    fn part1(&self, columns: &Self::Parsed) -> Answer {
+        let mut columns = columns.to_vec();
 
-#[derive(Debug, Hash)]
-enum Object {
-    BlockRock,
-    RoundRock,
-    Empty,
-}
+        move_north_south(&mut columns, true);
 
-fn do_cycles(columns: &mut [Vec<Object>]) {
-    const CYCLES: usize = 1000000000;
+        let sum: usize = columns.iter().map(|c| weight_of_column(c)).sum();
 
-    let mut set = HashMap::new();
+        sum.into()
+    }
 
-    let mut done_steps = 0;
-    let mut cycle_step = 0;
+    fn part2(&self, columns: &Self::Parsed) -> Answer {
+        let columns = fast_forward(
+            columns.to_vec(),
+            |columns| {
+                let mut columns = columns.clone();
+                cycle(&mut columns);
+                columns
+            },
+            1000000000,
+        );
 
-    for i in 0..CYCLES {
-        cycle(columns);
+        let sum: usize = columns.iter().map(|c| weight_of_column(c)).sum();
 
-        let mut hasher = DefaultHasher::new();
-        columns.hash(&mut hasher);
-        let hash = hasher.finish();
+        sum.into()
+    }
 
-        if let Some(cycle_idx) = set.get(&hash) {
-            done_steps = i + 1;
-            cycle_step = i - cycle_idx;
-            break;
+    fn generate(&self, rng: &mut Rng, size: Size) -> Option<String> {
+        let side = size.pick(10, 30, 100);
+        let mut input = String::new();
+
+        for _ in 0..side {
+            for _ in 0..side {
+                input.push(match rng.index(20) {
+                    0..=3 => 'O',
+                    4..=5 => '#',
+                    _ => '.',
+                });
+            }
+            input.push('\n');
         }
 
-        set.insert(hash, i);
+        Some(input)
     }
+}
 
-    let remaining = (CYCLES - done_steps) % cycle_step;
-
-    for _ in 0..remaining {
-        cycle(columns);
-    }
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Object {
+    BlockRock,
+    RoundRock,
+    Empty,
 }
 
 fn cycle(columns: &mut [Vec<Object>]) {
@@ -85,13 +77,13 @@ fn cycle(columns: &mut [Vec<Object>]) {
 
 fn move_north_south(columns: &mut [Vec<Object>], north: bool) {
     if north {
-        columns.iter_mut().for_each(move_column_up);
+        columns.iter_mut().for_each(|c| move_column_up(c));
     } else {
-        columns.iter_mut().for_each(move_column_down);
+        columns.iter_mut().for_each(|c| move_column_down(c));
     }
 }
 
-fn move_column_up(column: &mut Vec<Object>) {
+fn move_column_up(column: &mut [Object]) {
     let col_len = column.len() as isize;
     let mut last_block_idx = -1;
     let mut round_rock_count = 0;
@@ -133,7 +125,7 @@ fn move_column_up(column: &mut Vec<Object>) {
     }
 }
 
-fn move_column_down(column: &mut Vec<Object>) {
+fn move_column_down(column: &mut [Object]) {
     let col_len = column.len() as isize;
     let mut last_block_idx = column.len();
     let mut round_rock_count = 0;
@@ -142,11 +134,11 @@ fn move_column_down(column: &mut Vec<Object>) {
         let o = &column[col_idx];
         match o {
             Object::BlockRock => {
-                for i in (last_block_idx - round_rock_count)..last_block_idx {
-                    column[i] = Object::RoundRock;
+                for item in &mut column[(last_block_idx - round_rock_count)..last_block_idx] {
+                    *item = Object::RoundRock;
                 }
-                for i in (col_idx + 1)..(last_block_idx - round_rock_count) {
-                    column[i] = Object::Empty;
+                for item in &mut column[(col_idx + 1)..(last_block_idx - round_rock_count)] {
+                    *item = Object::Empty;
                 }
 
                 round_rock_count = 0;
@@ -159,11 +151,11 @@ fn move_column_down(column: &mut Vec<Object>) {
         }
     }
 
-    for i in (last_block_idx - round_rock_count)..last_block_idx {
-        column[i] = Object::RoundRock;
+    for item in &mut column[(last_block_idx - round_rock_count)..last_block_idx] {
+        *item = Object::RoundRock;
     }
-    for i in 0..(last_block_idx - round_rock_count) {
-        column[i] = Object::Empty;
+    for item in &mut column[0..(last_block_idx - round_rock_count)] {
+        *item = Object::Empty;
     }
 }
 
@@ -192,11 +184,11 @@ fn move_column_left(columns: &mut [Vec<Object>], row_idx: usize) {
             Object::BlockRock => {
                 let start = (last_block_idx + 1) as usize;
 
-                for i in start..(start + round_rock_count) {
-                    columns[i][row_idx] = Object::RoundRock;
+                for column in &mut columns[start..(start + round_rock_count)] {
+                    column[row_idx] = Object::RoundRock;
                 }
-                for i in (start + round_rock_count)..col_idx {
-                    columns[i][row_idx] = Object::Empty;
+                for column in &mut columns[(start + round_rock_count)..col_idx] {
+                    column[row_idx] = Object::Empty;
                 }
 
                 round_rock_count = 0;
@@ -211,11 +203,11 @@ fn move_column_left(columns: &mut [Vec<Object>], row_idx: usize) {
 
     let start = (last_block_idx + 1) as usize;
 
-    for i in start..(start + round_rock_count) {
-        columns[i][row_idx] = Object::RoundRock;
+    for column in &mut columns[start..(start + round_rock_count)] {
+        column[row_idx] = Object::RoundRock;
     }
-    for i in (start + round_rock_count)..columns.len() {
-        columns[i][row_idx] = Object::Empty;
+    for column in &mut columns[(start + round_rock_count)..] {
+        column[row_idx] = Object::Empty;
     }
 }
 
@@ -228,11 +220,11 @@ fn move_column_right(columns: &mut [Vec<Object>], row_idx: usize, row_len: usize
 
         match o {
             Object::BlockRock => {
-                for i in (last_block_idx - round_rock_count)..last_block_idx {
-                    columns[i][row_idx] = Object::RoundRock;
+                for column in &mut columns[(last_block_idx - round_rock_count)..last_block_idx] {
+                    column[row_idx] = Object::RoundRock;
                 }
-                for i in (col_idx + 1)..(last_block_idx - round_rock_count) {
-                    columns[i][row_idx] = Object::Empty;
+                for column in &mut columns[(col_idx + 1)..(last_block_idx - round_rock_count)] {
+                    column[row_idx] = Object::Empty;
                 }
 
                 round_rock_count = 0;
@@ -245,11 +237,11 @@ fn move_column_right(columns: &mut [Vec<Object>], row_idx: usize, row_len: usize
         }
     }
 
-    for i in (last_block_idx - round_rock_count)..last_block_idx {
-        columns[i][row_idx] = Object::RoundRock;
+    for column in &mut columns[(last_block_idx - round_rock_count)..last_block_idx] {
+        column[row_idx] = Object::RoundRock;
     }
-    for i in 0..(last_block_idx - round_rock_count) {
-        columns[i][row_idx] = Object::Empty;
+    for column in &mut columns[0..(last_block_idx - round_rock_count)] {
+        column[row_idx] = Object::Empty;
     }
 }
 
@@ -263,29 +255,50 @@ fn weight_of_column(column: &[Object]) -> usize {
     })
 }
 
-fn parse_columns(reader: BufReader<File>) -> Vec<Vec<Object>> {
+fn parse_columns(input: &str) -> Result<Vec<Vec<Object>>, ParseError> {
     let mut columns = Vec::new();
 
-    for (idx, line) in reader.lines().enumerate() {
-        let line = line.expect("Failed to read line");
-
+    for (idx, line) in input.lines().enumerate() {
         if idx == 0 {
             for _ in 0..line.chars().count() {
                 columns.push(Vec::new());
             }
         }
 
-        for (c_idx, c) in line.chars().enumerate() {
+        if line.chars().count() != columns.len() {
+            return Err(ParseError::new(
+                line,
+                "Row length differs from the first row",
+            ));
+        }
+
+        for (c_idx, (byte_idx, c)) in line.char_indices().enumerate() {
             let block = match c {
                 '#' => Object::BlockRock,
                 'O' => Object::RoundRock,
                 '.' => Object::Empty,
-                _ => panic!("Unexpected char"),
+                _ => {
+                    return Err(ParseError::new(
+                        &line[byte_idx..byte_idx + c.len_utf8()],
+                        "Unexpected char",
+                    ))
+                }
             };
 
             columns[c_idx].push(block);
         }
     }
 
-    columns
+    Ok(columns)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    aoc_common::example_tests! {
+        Day14,
+        part1_example: part1(include_str!("example")) == 136,
+        part2_example: part2(include_str!("example")) == 64,
+    }
 }
@@ -0,0 +1,140 @@
+use aoc_common::{parse_num, Answer, ParseError, Rng, Size, Solution};
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    type Parsed = Vec<Step>;
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed, ParseError> {
+        let Some(line) = input.lines().next() else {
+            return Err(ParseError::new("", "Expected one line"));
+        };
+
+        line.split(',').map(parse_step).collect()
+    }
+
+    fn part1(&self, steps: &Self::Parsed) -> Answer {
+        let sum: usize = steps.iter().map(|s| hash_string(&s.text)).sum();
+
+        sum.into()
+    }
+
+    fn part2(&self, steps: &Self::Parsed) -> Answer {
+        let mut boxes = std::iter::repeat_n(Vec::new(), 256).collect::<Vec<_>>();
+
+        steps.iter().for_each(|s| add_step_to_boxes(s, &mut boxes));
+
+        let sum = get_lens_sum(&boxes);
+
+        sum.into()
+    }
+
+    fn generate(&self, rng: &mut Rng, size: Size) -> Option<String> {
+        let (step_count, label_count) = size.pick((20, 5), (400, 50), (4000, 500));
+
+        let labels = (0..label_count)
+            .map(|_| {
+                (0..rng.int(2..=6))
+                    .map(|_| (b'a' + rng.index(26) as u8) as char)
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>();
+
+        let steps = (0..step_count)
+            .map(|_| {
+                let label = rng.pick(&labels);
+
+                if rng.chance(0.4) {
+                    format!("{label}-")
+                } else {
+                    format!("{label}={}", rng.int(1..=9))
+                }
+            })
+            .collect::<Vec<_>>();
+
+        Some(steps.join(",") + "\n")
+    }
+}
+
+fn get_lens_sum(boxes: &[Vec<(String, usize)>]) -> usize {
+    let mut sum = 0;
+
+    for (box_idx, b) in boxes.iter().enumerate() {
+        for (slot_idx, (_, focal_length)) in b.iter().enumerate() {
+            sum += (box_idx + 1) * (slot_idx + 1) * focal_length;
+        }
+    }
+
+    sum
+}
+
+/// A lens to insert, or with no focal length one to remove.
+pub struct Step {
+    text: String,
+    label: String,
+    focal_length: Option<usize>,
+}
+
+fn parse_step(string: &str) -> Result<Step, ParseError> {
+    if let Some(label) = string.strip_suffix('-') {
+        return Ok(Step {
+            text: string.to_owned(),
+            label: label.to_owned(),
+            focal_length: None,
+        });
+    }
+
+    let Some((label, num_part)) = string.split_once('=') else {
+        return Err(ParseError::new(
+            string,
+            "Expected '<label>-' or '<label>=<num>'",
+        ));
+    };
+
+    Ok(Step {
+        text: string.to_owned(),
+        label: label.to_owned(),
+        focal_length: Some(parse_num(num_part)?),
+    })
+}
+
+fn add_step_to_boxes(step: &Step, boxes: &mut [Vec<(String, usize)>]) {
+    let label = &step.label;
+
+    let box_num = hash_string(label);
+
+    if let Some(focal_length) = step.focal_length {
+        let exisiting_lens = boxes[box_num].iter_mut().find(|(l, _)| l == label);
+
+        if let Some(exisiting_lens) = exisiting_lens {
+            exisiting_lens.1 = focal_length;
+        } else {
+            boxes[box_num].push((label.to_owned(), focal_length));
+        }
+    } else {
+        boxes[box_num].retain(|(l, _)| l != label);
+    }
+}
+
+fn hash_string(string: &str) -> usize {
+    let mut val = 0;
+
+    for c in string.chars() {
+        val += c as usize;
+        val *= 17;
+        val %= 256;
+    }
+
+    val
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    aoc_common::example_tests! {
+        Day15,
+        part1_example: part1(include_str!("example")) == 1320,
+        part2_example: part2(include_str!("example")) == 145,
+    }
+}
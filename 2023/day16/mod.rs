@@ -0,0 +1,160 @@
+use std::collections::HashSet;
+
+use aoc_common::{Answer, Direction, Grid, ParseError, Rng, Size, Solution};
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    type Parsed = Grid<Tile>;
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed, ParseError> {
+        parse_tiles(input)
+    }
+
+    fn part1(&self, tiles: &Self::Parsed) -> Answer {
+        let tiles_marked = tiles_marked_from_starting_point((0, 0), Direction::Right, tiles);
+
+        tiles_marked.into()
+    }
+
+    fn part2(&self, tiles: &Self::Parsed) -> Answer {
+        let max_x = tiles.width() - 1;
+        let max_y = tiles.height() - 1;
+
+        let top_bottom_max = (0..=max_x)
+            .flat_map(|i| {
+                vec![
+                    tiles_marked_from_starting_point((i, 0), Direction::Down, tiles),
+                    tiles_marked_from_starting_point((i, max_y), Direction::Up, tiles),
+                ]
+            })
+            .max()
+            .unwrap_or(0);
+
+        let left_right_max = (0..=max_y)
+            .flat_map(|i| {
+                vec![
+                    tiles_marked_from_starting_point((0, i), Direction::Right, tiles),
+                    tiles_marked_from_starting_point((max_x, i), Direction::Left, tiles),
+                ]
+            })
+            .max()
+            .unwrap_or(0);
+
+        let tiles_marked = std::cmp::max(top_bottom_max, left_right_max);
+
+        tiles_marked.into()
+    }
+
+    fn generate(&self, rng: &mut Rng, size: Size) -> Option<String> {
+        let side = size.pick(10, 40, 110);
+        let mut input = String::new();
+
+        for _ in 0..side {
+            for _ in 0..side {
+                input.push(if rng.chance(0.1) {
+                    *rng.pick(&['/', '\\', '|', '-'])
+                } else {
+                    '.'
+                });
+            }
+            input.push('\n');
+        }
+
+        Some(input)
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum Tile {
+    MirrorSlash,
+    MirrorBackSlash,
+    Vertical,
+    Horizontal,
+    Empty,
+}
+
+impl Tile {
+    pub fn out_directions(&self, in_direction: Direction) -> Vec<Direction> {
+        match self {
+            Tile::MirrorSlash => match in_direction {
+                Direction::Up => vec![Direction::Right],
+                Direction::Down => vec![Direction::Left],
+                Direction::Left => vec![Direction::Down],
+                Direction::Right => vec![Direction::Up],
+            },
+            Tile::MirrorBackSlash => match in_direction {
+                Direction::Up => vec![Direction::Left],
+                Direction::Down => vec![Direction::Right],
+                Direction::Left => vec![Direction::Up],
+                Direction::Right => vec![Direction::Down],
+            },
+            Tile::Vertical => match in_direction {
+                Direction::Up => vec![Direction::Up],
+                Direction::Down => vec![Direction::Down],
+                Direction::Left => vec![Direction::Up, Direction::Down],
+                Direction::Right => vec![Direction::Up, Direction::Down],
+            },
+            Tile::Horizontal => match in_direction {
+                Direction::Up => vec![Direction::Left, Direction::Right],
+                Direction::Down => vec![Direction::Left, Direction::Right],
+                Direction::Left => vec![Direction::Left],
+                Direction::Right => vec![Direction::Right],
+            },
+            Tile::Empty => vec![in_direction],
+        }
+    }
+}
+
+fn tiles_marked_from_starting_point(
+    pos: (usize, usize),
+    direction: Direction,
+    tiles: &Grid<Tile>,
+) -> usize {
+    let mut markings = Grid::new(tiles.width(), tiles.height(), HashSet::new());
+
+    mark_pos(pos, direction, tiles, &mut markings);
+
+    markings.iter().filter(|(_, v)| !v.is_empty()).count()
+}
+
+fn mark_pos(
+    pos: (usize, usize),
+    direction: Direction,
+    tiles: &Grid<Tile>,
+    markings: &mut Grid<HashSet<Direction>>,
+) {
+    if !markings[pos].insert(direction) {
+        return;
+    }
+
+    let out_dirs = tiles[pos].out_directions(direction);
+
+    for out_dir in out_dirs {
+        if let Some(new_pos) = tiles.step(pos, out_dir) {
+            mark_pos(new_pos, out_dir, tiles, markings);
+        }
+    }
+}
+
+fn parse_tiles(input: &str) -> Result<Grid<Tile>, ParseError> {
+    Grid::parse(input, |c| match c {
+        '/' => Some(Tile::MirrorSlash),
+        '\\' => Some(Tile::MirrorBackSlash),
+        '|' => Some(Tile::Vertical),
+        '-' => Some(Tile::Horizontal),
+        '.' => Some(Tile::Empty),
+        _ => None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    aoc_common::example_tests! {
+        Day16,
+        part1_example: part1(include_str!("example")) == 46,
+        part2_example: part2(include_str!("example")) == 51,
+    }
+}
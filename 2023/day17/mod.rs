@@ -0,0 +1,126 @@
+use aoc_common::{search, Answer, Direction, Grid, ParseError, Rng, Size, Solution};
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    type Parsed = Grid<u32>;
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed, ParseError> {
+        parse_map(input)
+    }
+
+    fn part1(&self, map: &Self::Parsed) -> Answer {
+        let shortest_path = shortest_path(map, 1, 3).expect("No path found");
+
+        shortest_path.into()
+    }
+
+    fn part2(&self, map: &Self::Parsed) -> Answer {
+        let shortest_path = shortest_path(map, 4, 10).expect("No path found");
+
+        shortest_path.into()
+    }
+
+    fn generate(&self, rng: &mut Rng, size: Size) -> Option<String> {
+        let side = size.pick(13, 40, 141);
+        let mut input = String::new();
+
+        for _ in 0..side {
+            for _ in 0..side {
+                input += &rng.int(1..=9).to_string();
+            }
+            input.push('\n');
+        }
+
+        Some(input)
+    }
+}
+
+/// Where the crucible is, the direction it last moved in and how many steps it
+/// has taken in that direction.
+type Crucible = ((usize, usize), Option<Direction>, usize);
+
+/// Least heat loss to the bottom right corner for a crucible that moves at least `min_steps`
+/// (also before reaching an edge) and at most `max_steps` in one direction before turning.
+fn shortest_path(map: &Grid<u32>, min_steps: usize, max_steps: usize) -> Option<u32> {
+    let end = (map.width() - 1, map.height() - 1);
+
+    let successors = |&(pos, direction, steps): &Crucible| {
+        neighbours(map, pos, direction).filter_map(move |(next, dir)| {
+            let next_steps = if direction == Some(dir) {
+                // Straight
+                (steps < max_steps).then_some(steps + 1)
+            } else {
+                // Turning
+                ((steps >= min_steps && map.distance_to_edge(pos, dir) >= min_steps)
+                    || direction.is_none())
+                .then_some(1)
+            };
+
+            next_steps.map(|next_steps| ((next, Some(dir), next_steps), map[next]))
+        })
+    };
+    // Every block loses at least 1 heat
+    let distance = |&((x, y), _, _): &Crucible| (end.0 - x + end.1 - y) as u32;
+
+    let path = search::astar(((0, 0), None, 0), successors, distance, |state| {
+        state.0 == end
+    })?;
+
+    tracing::debug!(
+        expanded = path.stats.expanded,
+        frontier_peak = path.stats.frontier_peak,
+        "searched"
+    );
+    tracing::trace!("path:\n{}", format_path(map, &path.states));
+
+    Some(path.cost)
+}
+
+/// The map with the path drawn on it like in the puzzle text.
+fn format_path(map: &Grid<u32>, path: &[Crucible]) -> String {
+    let mut tiles = map.map(|heat| char::from_digit(*heat, 10).unwrap_or('?'));
+
+    for &(pos, direction, _) in path {
+        if let Some(direction) = direction {
+            tiles[pos] = match direction {
+                Direction::Up => '^',
+                Direction::Right => '>',
+                Direction::Down => 'v',
+                Direction::Left => '<',
+            };
+        }
+    }
+
+    tiles
+        .rows()
+        .map(|row| row.iter().collect::<String>() + "\n")
+        .collect()
+}
+
+/// Positions reachable in one step without reversing, paired with the direction taken.
+fn neighbours(
+    map: &Grid<u32>,
+    pos: (usize, usize),
+    direction: Option<Direction>,
+) -> impl Iterator<Item = ((usize, usize), Direction)> + '_ {
+    Direction::ALL
+        .into_iter()
+        .filter(move |dir| direction.map(|d| d.opposite()) != Some(*dir))
+        .filter_map(move |dir| map.step(pos, dir).map(|p| (p, dir)))
+}
+
+fn parse_map(input: &str) -> Result<Grid<u32>, ParseError> {
+    Grid::parse(input, |c| c.to_digit(10))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    aoc_common::example_tests! {
+        Day17,
+        part1_example: part1(include_str!("example")) == 102,
+        part2_example: part2(include_str!("example")) == 94,
+    }
+}
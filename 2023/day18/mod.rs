@@ -0,0 +1,316 @@
+use std::{cmp::Ordering, collections::VecDeque};
+
+use aoc_common::{generate, number::gcd_all, parse_num, Answer, ParseError, Rng, Size, Solution};
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    /// The plain instructions, and the ones hidden in the colors.
+    type Parsed = (Vec<Direction>, Vec<Direction>);
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed, ParseError> {
+        parse_directions(input)
+    }
+
+    fn part1(&self, (directions, _): &Self::Parsed) -> Answer {
+        dug_squares(directions).into()
+    }
+
+    fn part2(&self, (_, directions): &Self::Parsed) -> Answer {
+        tracing::trace!(?directions);
+        tracing::debug!(
+            gcd = gcd_all(
+                &directions
+                    .iter()
+                    .map(|d| match d {
+                        Direction::Up(n) => *n,
+                        Direction::Down(n) => *n,
+                        Direction::Right(n) => *n,
+                        Direction::Left(n) => *n,
+                    })
+                    .collect::<Vec<_>>()
+            ),
+            "lengths"
+        );
+
+        dug_squares(directions).into()
+    }
+
+    fn generate(&self, rng: &mut Rng, size: Size) -> Option<String> {
+        let (side, plain_gap, color_gap) = size.pick((5, 3, 20), (12, 5, 100), (40, 8, 25_000));
+
+        let region = generate::region(rng, side, side, side * side / 2);
+        let corners = generate::outline(&region);
+
+        // Random widths for the columns and rows of the region stretch it
+        // without letting the trench touch itself
+        let mut gaps = |max| (0..side).map(|_| rng.int(2..=max)).collect::<Vec<_>>();
+        let (plain_xs, plain_ys) = (gaps(plain_gap), gaps(plain_gap));
+        let (color_xs, color_ys) = (gaps(color_gap), gaps(color_gap));
+
+        // Straight runs of the trench as (direction, from, to)
+        let mut runs: Vec<(char, _, _)> = Vec::new();
+
+        for (idx, &from) in corners.iter().enumerate() {
+            let to = corners[(idx + 1) % corners.len()];
+            let direction = match (to.0.cmp(&from.0), to.1.cmp(&from.1)) {
+                (Ordering::Greater, _) => 'R',
+                (Ordering::Less, _) => 'L',
+                (_, Ordering::Greater) => 'D',
+                _ => 'U',
+            };
+
+            match runs.last_mut() {
+                Some(run) if run.0 == direction => run.2 = to,
+                _ => runs.push((direction, from, to)),
+            }
+        }
+
+        let length =
+            |gaps: &[i64], a: usize, b: usize| gaps[a.min(b)..a.max(b)].iter().sum::<i64>();
+        let mut input = String::new();
+
+        for (direction, from, to) in runs {
+            let (plain, color) = match direction {
+                'R' | 'L' => (
+                    length(&plain_xs, from.0, to.0),
+                    length(&color_xs, from.0, to.0),
+                ),
+                _ => (
+                    length(&plain_ys, from.1, to.1),
+                    length(&color_ys, from.1, to.1),
+                ),
+            };
+            let color_direction = match direction {
+                'R' => 0,
+                'D' => 1,
+                'L' => 2,
+                _ => 3,
+            };
+
+            input += &format!("{direction} {plain} (#{color:05x}{color_direction})\n");
+        }
+
+        Some(input)
+    }
+}
+
+fn dug_squares(directions: &[Direction]) -> usize {
+    let outline = map_outline(directions);
+
+    tracing::trace!("outline:\n{}", format_outline(&outline));
+
+    let fill = fill_outline(&outline);
+
+    tracing::trace!("filled:\n{}", format_outline(&fill));
+
+    fill.iter().flatten().map(|b| if *b { 1 } else { 0 }).sum()
+}
+
+#[derive(Debug)]
+pub enum Direction {
+    Up(isize),
+    Down(isize),
+    Right(isize),
+    Left(isize),
+}
+
+fn fill_outline(outline: &VecDeque<VecDeque<bool>>) -> VecDeque<VecDeque<bool>> {
+    let mut outline_with_fill = outline.clone();
+
+    let mut last_below_above = 0;
+
+    for y in 0..outline.len() {
+        let mut inside = false;
+
+        for x in 0..(outline[0].len()) {
+            if outline[y][x] {
+                let above = y > 0 && outline[y - 1][x];
+                let below = y < outline.len() - 1 && outline[y + 1][x];
+
+                if above && below {
+                    inside = !inside;
+                    last_below_above = 0;
+                    continue;
+                }
+
+                if below && (last_below_above != -1) {
+                    inside = !inside;
+                    last_below_above = 1;
+                    continue;
+                }
+
+                if above && (last_below_above != 1) {
+                    inside = !inside;
+                    last_below_above = -1;
+                    continue;
+                }
+            }
+
+            if inside || outline[y][x] {
+                outline_with_fill[y][x] = true;
+            }
+        }
+    }
+
+    outline_with_fill
+}
+
+fn format_outline(outline: &VecDeque<VecDeque<bool>>) -> String {
+    let mut text = String::new();
+
+    for r in outline {
+        for p in r {
+            text.push(if *p { '#' } else { '.' });
+        }
+        text.push('\n');
+    }
+
+    text
+}
+
+fn map_outline(directions: &[Direction]) -> VecDeque<VecDeque<bool>> {
+    let mut map: VecDeque<VecDeque<bool>> = VecDeque::from([VecDeque::from([true])]);
+    let mut curr_x: isize = 0;
+    let mut curr_y: isize = 0;
+    let mut offset_x: isize = 0;
+    let mut offset_y: isize = 0;
+
+    for direction in directions {
+        tracing::trace!(?direction, "outline so far:\n{}", format_outline(&map));
+
+        match direction {
+            Direction::Up(len) => {
+                for i in ((curr_y - len)..=curr_y).rev() {
+                    let y_idx = i + offset_y;
+
+                    if y_idx >= 0 {
+                        map[y_idx as usize][(curr_x + offset_x) as usize] = true;
+                    } else {
+                        let new_row_len = map.front().unwrap().len();
+
+                        map.push_front(VecDeque::from_iter(std::iter::repeat_n(
+                            false,
+                            new_row_len,
+                        )));
+                        offset_y += 1;
+                        map.front_mut().unwrap()[(curr_x + offset_x) as usize] = true;
+                    }
+                }
+
+                curr_y -= len;
+            }
+            Direction::Down(len) => {
+                for i in curr_y..=(curr_y + len) {
+                    let y_idx = i + offset_y;
+
+                    if y_idx < map.len() as isize {
+                        map[y_idx as usize][(curr_x + offset_x) as usize] = true;
+                    } else {
+                        let new_row_len = map.front().unwrap().len();
+
+                        map.push_back(VecDeque::from_iter(std::iter::repeat_n(false, new_row_len)));
+                        map.back_mut().unwrap()[(curr_x + offset_x) as usize] = true;
+                    }
+                }
+
+                curr_y += len;
+            }
+            Direction::Right(len) => {
+                for i in curr_x..=(curr_x + len) {
+                    let x_idx = i + offset_x;
+
+                    if x_idx < map[(curr_y + offset_y) as usize].len() as isize {
+                        map[(curr_y + offset_y) as usize][x_idx as usize] = true;
+                    } else {
+                        for (idx, row) in map.iter_mut().enumerate() {
+                            row.push_back(idx == (curr_y + offset_y) as usize);
+                        }
+                    }
+                }
+
+                curr_x += len;
+            }
+            Direction::Left(len) => {
+                for i in ((curr_x - len)..=curr_x).rev() {
+                    let x_idx = i + offset_x;
+
+                    if x_idx >= 0 {
+                        map[(curr_y + offset_y) as usize][x_idx as usize] = true;
+                    } else {
+                        for (idx, row) in map.iter_mut().enumerate() {
+                            row.push_front(idx == (curr_y + offset_y) as usize);
+                        }
+                        offset_x += 1;
+                    }
+                }
+
+                curr_x -= len;
+            }
+        }
+    }
+
+    map
+}
+
+fn parse_directions(input: &str) -> Result<(Vec<Direction>, Vec<Direction>), ParseError> {
+    let mut directions = Vec::new();
+    let mut color_directions = Vec::new();
+
+    for line in input.lines() {
+        let line_split = line.split(' ').collect::<Vec<_>>();
+
+        let [direction, num, color] = line_split[..] else {
+            return Err(ParseError::new(
+                line,
+                "Expected '<direction> <num> (#<color>)'",
+            ));
+        };
+
+        let num = parse_num(num)?;
+
+        directions.push(match direction {
+            "U" => Direction::Up(num),
+            "D" => Direction::Down(num),
+            "L" => Direction::Left(num),
+            "R" => Direction::Right(num),
+            _ => return Err(ParseError::new(direction, "Unexpected direction")),
+        });
+
+        color_directions.push(parse_color_direction(color)?);
+    }
+
+    Ok((directions, color_directions))
+}
+
+fn parse_color_direction(text: &str) -> Result<Direction, ParseError> {
+    let Some(color) = text
+        .strip_prefix("(#")
+        .and_then(|color| color.strip_suffix(')'))
+        .filter(|color| color.len() == 6 && color.is_ascii())
+    else {
+        return Err(ParseError::new(text, "Expected '(#<color>)'"));
+    };
+
+    let num = isize::from_str_radix(&color[..5], 16)
+        .map_err(|_| ParseError::new(&color[..5], "Expected a hex number"))?;
+
+    match &color[5..] {
+        "3" => Ok(Direction::Up(num)),
+        "1" => Ok(Direction::Down(num)),
+        "2" => Ok(Direction::Left(num)),
+        "0" => Ok(Direction::Right(num)),
+        _ => Err(ParseError::new(&color[5..], "Unexpected direction")),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Part 2 fills the whole lagoon grid, which is far too large for the example
+    aoc_common::example_tests! {
+        Day18,
+        part1_example: part1(include_str!("example")) == 62,
+    }
+}
@@ -0,0 +1,443 @@
+use std::{
+    collections::{HashMap, HashSet, VecDeque},
+    str::Lines,
+};
+
+use aoc_common::{interval::HyperRect, parse_num, Answer, ParseError, Rng, Size, Solution};
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    type Parsed = (HashMap<String, Workflow>, Vec<Part>);
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed, ParseError> {
+        let mut lines = input.lines();
+
+        let workflow_map = parse_workflows(&mut lines)?;
+        let parts = parse_parts(&mut lines)?;
+
+        Ok((workflow_map, parts))
+    }
+
+    fn part1(&self, (workflow_map, parts): &Self::Parsed) -> Answer {
+        let sum: usize = parts
+            .iter()
+            .map(|part| {
+                let starting_workflow = workflow_map.get("in").expect("No 'in' workflow found");
+
+                if part.apply_workflow(starting_workflow, workflow_map) {
+                    part.xmas_sum()
+                } else {
+                    0
+                }
+            })
+            .sum();
+
+        sum.into()
+    }
+
+    fn part2(&self, (workflow_map, _): &Self::Parsed) -> Answer {
+        let start_workflow = workflow_map.get("in").expect("No 'in'workflow found");
+
+        let mut accepted = Vec::new();
+        collect_accepted(ALL_PARTS, start_workflow, workflow_map, &mut accepted);
+
+        let sum: usize = accepted.iter().map(HyperRect::volume).sum();
+
+        sum.into()
+    }
+
+    fn generate(&self, rng: &mut Rng, size: Size) -> Option<String> {
+        let (workflow_count, part_count) = size.pick((10, 20), (100, 100), (550, 200));
+
+        // The workflows form a tree rooted at 'in', like in the puzzle
+        let mut names = HashSet::from(["in".to_string()]);
+        let mut pending = VecDeque::from(["in".to_string()]);
+        let mut workflows = Vec::new();
+
+        while let Some(name) = pending.pop_front() {
+            let mut target = |rng: &mut Rng| {
+                // Always keep one workflow pending until there are enough
+                if names.len() < workflow_count && (pending.is_empty() || rng.chance(0.6)) {
+                    let name = loop {
+                        let name = (0..rng.int(2..=3))
+                            .map(|_| (b'a' + rng.index(26) as u8) as char)
+                            .collect::<String>();
+
+                        if names.insert(name.clone()) {
+                            break name;
+                        }
+                    };
+                    pending.push_back(name.clone());
+                    name
+                } else {
+                    rng.pick(&["A", "R"]).to_string()
+                }
+            };
+
+            let mut rules = (0..rng.int(1..=3))
+                .map(|_| {
+                    format!(
+                        "{}{}{}:{}",
+                        rng.pick(&['x', 'm', 'a', 's']),
+                        rng.pick(&['<', '>']),
+                        rng.int(1..=4000),
+                        target(rng)
+                    )
+                })
+                .collect::<Vec<_>>();
+            rules.push(target(rng));
+
+            workflows.push(format!("{name}{{{}}}", rules.join(",")));
+        }
+
+        rng.shuffle(&mut workflows);
+
+        let parts = (0..part_count)
+            .map(|_| {
+                let [x, m, a, s] = [(); 4].map(|_| rng.int(1..=4000));
+                format!("{{x={x},m={m},a={a},s={s}}}")
+            })
+            .collect::<Vec<_>>();
+
+        Some(format!(
+            "{}\n\n{}\n",
+            workflows.join("\n"),
+            parts.join("\n")
+        ))
+    }
+}
+
+#[derive(Debug)]
+pub struct Workflow {
+    rules: Vec<Rule>,
+}
+
+#[derive(Debug)]
+enum Rule {
+    Conditional {
+        lcond: PartValue,
+        op: Op,
+        rcond: usize,
+        result: RuleResult,
+    },
+    Unconditional(RuleResult),
+}
+
+impl Rule {
+    pub fn eval_for_part(&self, part: &Part) -> Option<&RuleResult> {
+        match self {
+            Rule::Conditional {
+                lcond,
+                op,
+                rcond,
+                result,
+            } => {
+                let lcond = part.value(*lcond);
+                let matches = match op {
+                    Op::Greater => lcond > *rcond,
+                    Op::Less => lcond < *rcond,
+                };
+
+                if matches {
+                    Some(result)
+                } else {
+                    None
+                }
+            }
+            Rule::Unconditional(res) => Some(res),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum PartValue {
+    X,
+    M,
+    A,
+    S,
+}
+
+impl PartValue {
+    fn axis(self) -> usize {
+        self as usize
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Op {
+    Greater,
+    Less,
+}
+
+#[derive(Debug)]
+enum RuleResult {
+    Accepted,
+    Rejected,
+    OtherRule(String),
+}
+
+#[derive(Debug)]
+pub struct Part {
+    x: usize,
+    m: usize,
+    a: usize,
+    s: usize,
+}
+
+impl Part {
+    pub fn apply_workflow(
+        &self,
+        workflow: &Workflow,
+        workflow_map: &HashMap<String, Workflow>,
+    ) -> bool {
+        for rule in workflow.rules.iter() {
+            if let Some(res) = rule.eval_for_part(self) {
+                match res {
+                    RuleResult::Accepted => {
+                        return true;
+                    }
+                    RuleResult::Rejected => {
+                        return false;
+                    }
+                    RuleResult::OtherRule(rule_name) => {
+                        let other_workflow = workflow_map
+                            .get(rule_name)
+                            .expect("Other rule doesn't exist");
+
+                        return self.apply_workflow(other_workflow, workflow_map);
+                    }
+                }
+            }
+        }
+
+        unreachable!()
+    }
+
+    pub fn value(&self, part_value: PartValue) -> usize {
+        match part_value {
+            PartValue::X => self.x,
+            PartValue::M => self.m,
+            PartValue::A => self.a,
+            PartValue::S => self.s,
+        }
+    }
+
+    pub fn xmas_sum(&self) -> usize {
+        self.x + self.m + self.a + self.s
+    }
+}
+
+/// Ratings of x, m, a and s, in the order of the `PartValue` axes.
+type XmasBox = HyperRect<usize, 4>;
+
+const ALL_PARTS: XmasBox = HyperRect {
+    sides: [1..4001, 1..4001, 1..4001, 1..4001],
+};
+
+/// Splits `parts` into the ones the condition matches and the ones it doesn't.
+fn split_matching(parts: &XmasBox, lcond: PartValue, op: Op, rcond: usize) -> (XmasBox, XmasBox) {
+    match op {
+        Op::Greater => {
+            let (not_matching, matching) = parts.split(lcond.axis(), rcond + 1);
+            (matching, not_matching)
+        }
+        Op::Less => parts.split(lcond.axis(), rcond),
+    }
+}
+
+/// Adds the boxes of `parts` the workflow accepts to `accepted`. They never
+/// overlap, as each rule only gets the parts the rules before it didn't match.
+fn collect_accepted(
+    parts: XmasBox,
+    workflow: &Workflow,
+    workflow_map: &HashMap<String, Workflow>,
+    accepted: &mut Vec<XmasBox>,
+) {
+    let mut remaining = parts;
+
+    for rule in workflow.rules.iter() {
+        let (matching, result) = match rule {
+            Rule::Conditional {
+                lcond,
+                op,
+                rcond,
+                result,
+            } => {
+                let (matching, not_matching) = split_matching(&remaining, *lcond, *op, *rcond);
+                remaining = not_matching;
+
+                (matching, result)
+            }
+            Rule::Unconditional(result) => (remaining.clone(), result),
+        };
+
+        if matching.is_empty() {
+            continue;
+        }
+
+        match result {
+            RuleResult::Accepted => accepted.push(matching),
+            RuleResult::Rejected => {}
+            RuleResult::OtherRule(workflow_name) => {
+                let next_workflow = workflow_map
+                    .get(workflow_name)
+                    .expect("No workflow with found for given name");
+                collect_accepted(matching, next_workflow, workflow_map, accepted);
+            }
+        }
+    }
+}
+
+fn parse_workflows(lines: &mut Lines) -> Result<HashMap<String, Workflow>, ParseError> {
+    let mut map = HashMap::new();
+
+    loop {
+        let Some(line) = lines.next() else {
+            return Err(ParseError::new(
+                "",
+                "Expected an empty line after the workflows",
+            ));
+        };
+
+        if line.is_empty() {
+            break;
+        }
+
+        let Some((rule_name, rule)) = line
+            .split_once('{')
+            .and_then(|(rule_name, rule)| Some((rule_name, rule.strip_suffix('}')?)))
+        else {
+            return Err(ParseError::new(line, "Expected '<name>{<rules>}'"));
+        };
+        let rule_parts = rule.split(',');
+
+        let mut rules = Vec::new();
+
+        for rule_part in rule_parts {
+            if let Some((cond_part, res)) = rule_part.split_once(':') {
+                let (lcond, op, rcond) = if let Some((lcond, rcond)) = cond_part.split_once('<') {
+                    (lcond, Op::Less, rcond)
+                } else if let Some((lcond, rcond)) = cond_part.split_once('>') {
+                    (lcond, Op::Greater, rcond)
+                } else {
+                    return Err(ParseError::new(cond_part, "Expected '<' or '>'"));
+                };
+
+                let result = match res {
+                    "A" => RuleResult::Accepted,
+                    "R" => RuleResult::Rejected,
+                    p => RuleResult::OtherRule(p.to_owned()),
+                };
+
+                let lcond = match lcond {
+                    "x" => PartValue::X,
+                    "m" => PartValue::M,
+                    "a" => PartValue::A,
+                    "s" => PartValue::S,
+                    _ => return Err(ParseError::new(lcond, "Unexpected part value")),
+                };
+
+                let rcond = parse_num(rcond)?;
+
+                rules.push(Rule::Conditional {
+                    lcond,
+                    op,
+                    rcond,
+                    result,
+                });
+            } else {
+                rules.push(Rule::Unconditional(match rule_part {
+                    "A" => RuleResult::Accepted,
+                    "R" => RuleResult::Rejected,
+                    p => RuleResult::OtherRule(p.to_owned()),
+                }))
+            }
+        }
+
+        map.insert(rule_name.to_owned(), Workflow { rules });
+    }
+
+    Ok(map)
+}
+
+fn parse_parts(lines: &mut Lines) -> Result<Vec<Part>, ParseError> {
+    lines
+        .map(|line| {
+            let values = line
+                .strip_prefix('{')
+                .and_then(|line| line.strip_suffix('}'))
+                .map(|line| line.split(',').collect::<Vec<_>>());
+
+            let Some([x, m, a, s]) = values.as_deref() else {
+                return Err(ParseError::new(
+                    line,
+                    "Expected '{x=<num>,m=<num>,a=<num>,s=<num>}'",
+                ));
+            };
+
+            let value = |text: &str, prefix: &str| match text.strip_prefix(prefix) {
+                Some(num) => parse_num(num),
+                None => Err(ParseError::new(text, format!("Expected '{prefix}<num>'"))),
+            };
+
+            Ok(Part {
+                x: value(x, "x=")?,
+                m: value(m, "m=")?,
+                a: value(a, "a=")?,
+                s: value(s, "s=")?,
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    aoc_common::example_tests! {
+        Day19,
+        part1_example: part1(include_str!("example")) == 19114,
+        part2_example: part2(include_str!("example")) == 167409079868000_i64,
+    }
+
+    #[test]
+    fn accepted_boxes_match_workflows() {
+        for seed in 0..50 {
+            let mut rng = Rng::new(seed);
+            let input = Day19.generate(&mut rng, Size::Small).unwrap();
+            let (workflow_map, _) = Day19.parse(&input).unwrap();
+            let start_workflow = &workflow_map["in"];
+
+            let mut accepted = Vec::new();
+            collect_accepted(ALL_PARTS, start_workflow, &workflow_map, &mut accepted);
+
+            for (i, a) in accepted.iter().enumerate() {
+                assert!(accepted[i + 1..]
+                    .iter()
+                    .all(|b| a.intersection(b).is_empty()));
+            }
+
+            // Random parts, and the parts right at the edges of the boxes
+            let mut points = (0..200)
+                .map(|_| [(); 4].map(|_| rng.int(1..=4000) as usize))
+                .collect::<Vec<_>>();
+            for rect in accepted.iter() {
+                points.push(rect.sides.clone().map(|side| side.start));
+                points.push(rect.sides.clone().map(|side| side.end - 1));
+                points.push(rect.sides.clone().map(|side| side.end.min(4000)));
+            }
+
+            for [x, m, a, s] in points {
+                let part = Part { x, m, a, s };
+                let in_box = accepted.iter().any(|rect| rect.contains(&[x, m, a, s]));
+
+                assert_eq!(
+                    part.apply_workflow(start_workflow, &workflow_map),
+                    in_box,
+                    "seed {seed}: {part:?}"
+                );
+            }
+        }
+    }
+}
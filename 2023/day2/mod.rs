@@ -0,0 +1,191 @@
+use aoc_common::{parse_num, Answer, ParseError, Rng, Size, Solution};
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    type Parsed = Vec<Game>;
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed, ParseError> {
+        let mut games = Vec::new();
+
+        for line in input.lines() {
+            let Some((prefix_part, game_part)) = line.split_once(": ") else {
+                return Err(ParseError::new(line, "Expected 'Game <id>: <rounds>'"));
+            };
+
+            let Some(game_id_str) = prefix_part.strip_prefix("Game ") else {
+                return Err(ParseError::new(prefix_part, "Expected 'Game <id>'"));
+            };
+
+            games.push(Game {
+                id: parse_num(game_id_str)?,
+                rounds: parse_game_part(game_part)?,
+            });
+        }
+
+        Ok(games)
+    }
+
+    fn part1(&self, games: &Self::Parsed) -> Answer {
+        let mut game_id_sum = 0;
+
+        for game in games {
+            let all_rounds_possible = game
+                .rounds
+                .iter()
+                .all(|game_round| game_round.is_possible());
+
+            if all_rounds_possible {
+                game_id_sum += game.id;
+            }
+        }
+
+        game_id_sum.into()
+    }
+
+    fn part2(&self, games: &Self::Parsed) -> Answer {
+        let mut sum_of_set_powers = 0;
+
+        for game in games {
+            let min_set_power = get_min_set(&game.rounds).get_set_power();
+
+            sum_of_set_powers += min_set_power;
+        }
+
+        sum_of_set_powers.into()
+    }
+
+    fn generate(&self, rng: &mut Rng, size: Size) -> Option<String> {
+        let mut input = String::new();
+
+        for id in 1..=size.pick(5, 20, 100) {
+            let rounds = (0..rng.int(1..=6))
+                .map(|_| {
+                    let mut colors = ["red", "green", "blue"];
+                    rng.shuffle(&mut colors);
+
+                    colors[..rng.int(1..=3) as usize]
+                        .iter()
+                        .map(|color| format!("{} {color}", rng.int(1..=20)))
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                })
+                .collect::<Vec<_>>();
+
+            input += &format!("Game {id}: {}\n", rounds.join("; "));
+        }
+
+        Some(input)
+    }
+}
+
+pub struct Game {
+    id: u64,
+    rounds: Vec<GameRound>,
+}
+
+/// The cubes shown in one round, `None` for colors that weren't shown.
+struct GameRound {
+    red_cubes: Option<u64>,
+    green_cubes: Option<u64>,
+    blue_cubes: Option<u64>,
+}
+
+impl GameRound {
+    pub fn is_possible(&self) -> bool {
+        const RED_MAX: u64 = 12;
+        const GREEN_MAX: u64 = 13;
+        const BLUE_MAX: u64 = 14;
+
+        self.red_cubes.unwrap_or(0) <= RED_MAX
+            && self.green_cubes.unwrap_or(0) <= GREEN_MAX
+            && self.blue_cubes.unwrap_or(0) <= BLUE_MAX
+    }
+
+    pub fn get_set_power(&self) -> u64 {
+        self.red_cubes.unwrap_or(1) * self.green_cubes.unwrap_or(1) * self.blue_cubes.unwrap_or(1)
+    }
+}
+
+impl TryFrom<&str> for GameRound {
+    type Error = ParseError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let cube_strs = value.split(", ");
+
+        let mut res = Self {
+            red_cubes: None,
+            green_cubes: None,
+            blue_cubes: None,
+        };
+
+        for cube_str in cube_strs {
+            let Some((num_str, color_str)) = cube_str.split_once(' ') else {
+                return Err(ParseError::new(cube_str, "Expected '<count> <color>'"));
+            };
+
+            let count = parse_num::<u64>(num_str)?;
+
+            match color_str {
+                "red" => res.red_cubes = Some(count),
+                "green" => res.green_cubes = Some(count),
+                "blue" => res.blue_cubes = Some(count),
+                _ => {
+                    return Err(ParseError::new(color_str, "Unknown color"));
+                }
+            }
+        }
+
+        Ok(res)
+    }
+}
+
+fn get_min_set(game_rounds: &[GameRound]) -> GameRound {
+    fn max(a: Option<u64>, b: Option<u64>) -> Option<u64> {
+        match (a, b) {
+            (None, None) => None,
+            (None, Some(b)) => Some(b),
+            (Some(a), None) => Some(a),
+            (Some(a), Some(b)) => Some(std::cmp::max(a, b)),
+        }
+    }
+
+    let (r_min, g_min, b_min) = game_rounds
+        .iter()
+        .fold((None, None, None), |acc, game_round| {
+            (
+                max(acc.0, game_round.red_cubes),
+                max(acc.1, game_round.green_cubes),
+                max(acc.2, game_round.blue_cubes),
+            )
+        });
+
+    GameRound {
+        red_cubes: r_min,
+        green_cubes: g_min,
+        blue_cubes: b_min,
+    }
+}
+
+fn parse_game_part(game_part: &str) -> Result<Vec<GameRound>, ParseError> {
+    let game_round_strs = game_part.split("; ");
+
+    let mut game_rounds = Vec::new();
+
+    for game_round_str in game_round_strs {
+        game_rounds.push(GameRound::try_from(game_round_str)?);
+    }
+
+    Ok(game_rounds)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    aoc_common::example_tests! {
+        Day2,
+        part1_example: part1(include_str!("example")) == 8,
+        part2_example: part2(include_str!("example")) == 2286,
+    }
+}
@@ -0,0 +1,363 @@
+use std::collections::{HashMap, HashSet, VecDeque};
+
+use aoc_common::{
+    cycle::brent,
+    graph::{Graph, NodeId},
+    number::lcm,
+    Answer, ParseError, Rng, Size, Solution,
+};
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    type Parsed = Modules;
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed, ParseError> {
+        parse_modules(input)
+    }
+
+    fn part1(&self, modules: &Self::Parsed) -> Answer {
+        let mut states = modules.states.clone();
+
+        let mut low_pulse_count = 0;
+        let mut high_pulse_count = 0;
+
+        for _ in 0..1000 {
+            let counts = modules.press_button(&mut states, &[]);
+            low_pulse_count += counts.low;
+            high_pulse_count += counts.high;
+        }
+
+        (low_pulse_count * high_pulse_count).into()
+    }
+
+    fn part2(&self, modules: &Self::Parsed) -> Answer {
+        presses_until_rx_low(modules).into()
+    }
+
+    fn generate(&self, rng: &mut Rng, size: Size) -> Option<String> {
+        let (counter_count, bits) = size.pick((2, 4), (3, 8), (4, 12));
+
+        let mut names = HashSet::from(["rx".to_string()]);
+        let mut name = |rng: &mut Rng| loop {
+            let name = (0..2)
+                .map(|_| (b'a' + rng.index(26) as u8) as char)
+                .collect::<String>();
+
+            if names.insert(name.clone()) {
+                break name;
+            }
+        };
+
+        let collector = name(rng);
+        let mut modules = vec![(format!("&{collector}"), vec!["rx".to_string()])];
+        let mut starts = Vec::new();
+
+        // Binary counters like in the puzzle: a chain of flip-flops that a
+        // conjunction resets once they count up to a random period, when it
+        // sends a high pulse on towards the collector
+        for _ in 0..counter_count {
+            let period = rng.int(1 << (bits - 1)..=(1 << bits) - 1) | 1;
+            let flip_flops = (0..bits).map(|_| name(rng)).collect::<Vec<_>>();
+            let (hub, inverter) = (name(rng), name(rng));
+
+            let mut hub_outputs = vec![inverter.clone(), flip_flops[0].clone()];
+
+            for (bit, flip_flop) in flip_flops.iter().enumerate() {
+                let mut outputs = flip_flops
+                    .get(bit + 1)
+                    .cloned()
+                    .into_iter()
+                    .collect::<Vec<_>>();
+
+                if period & (1 << bit) != 0 {
+                    outputs.push(hub.clone());
+                } else {
+                    hub_outputs.push(flip_flop.clone());
+                }
+
+                rng.shuffle(&mut outputs);
+                modules.push((format!("%{flip_flop}"), outputs));
+            }
+
+            rng.shuffle(&mut hub_outputs);
+            modules.push((format!("&{hub}"), hub_outputs));
+            modules.push((format!("&{inverter}"), vec![collector.clone()]));
+            starts.push(flip_flops[0].clone());
+        }
+
+        modules.push(("broadcaster".to_string(), starts));
+        rng.shuffle(&mut modules);
+
+        Some(
+            modules
+                .into_iter()
+                .map(|(module, outputs)| format!("{module} -> {}\n", outputs.join(", ")))
+                .collect(),
+        )
+    }
+}
+
+/// The modules as a graph of the wires between them, starting with the
+/// broadcaster.
+#[derive(Debug)]
+pub struct Modules {
+    wires: Graph<String>,
+    /// The state of each module by node id, `None` for the ones that only
+    /// receive pulses, like `rx`.
+    states: Vec<Option<ModuleLogic>>,
+}
+
+/// Module states that are equal when their flip-flops are. A conjunction
+/// remembers the last pulse of each input, which for a flip-flop is its state
+/// and for a conjunction is low before the first press and high after, so
+/// comparing all of it would never find a counter back at the start.
+#[derive(Debug, Clone)]
+struct FlipFlops(Vec<Option<ModuleLogic>>);
+
+impl PartialEq for FlipFlops {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.iter().zip(&other.0).all(|pair| match pair {
+            (Some(ModuleLogic::FlipFlop(a)), Some(ModuleLogic::FlipFlop(b))) => a == b,
+            _ => true,
+        })
+    }
+}
+
+impl Eq for FlipFlops {}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum ModuleLogic {
+    Broadcaster,
+    FlipFlop(bool),
+    Nand(HashMap<NodeId, bool>),
+}
+
+impl ModuleLogic {
+    pub fn handle_pulse(&mut self, pulse: bool, from_input: NodeId) -> Option<bool> {
+        match self {
+            ModuleLogic::Broadcaster => Some(pulse),
+            ModuleLogic::FlipFlop(state) => {
+                if pulse {
+                    None
+                } else {
+                    *state = !*state;
+                    Some(*state)
+                }
+            }
+            ModuleLogic::Nand(state) => {
+                let input = state
+                    .get_mut(&from_input)
+                    .expect("Recieved input from unknown source");
+                *input = pulse;
+
+                Some(!state.values().all(|v| *v))
+            }
+        }
+    }
+}
+
+/// The button presses until `rx` gets a low pulse. It gets them from a single
+/// conjunction, whose inputs are each driven by a separate counter that sends a
+/// high pulse once every period and then starts over. `rx` gets a low pulse
+/// once all of them line up, after the least common multiple of the periods.
+///
+/// This assumes every counter's flip-flops are all off again after exactly its
+/// period, and panics if one only gets into a cycle after some presses, as the
+/// least common multiple would then be wrong.
+fn presses_until_rx_low(modules: &Modules) -> u64 {
+    tracing::trace!(
+        "modules:\n{}",
+        modules.wires.to_dot(|name| name.clone(), |_| None)
+    );
+
+    let sources = modules.wires.reversed();
+    let rx = modules
+        .wires
+        .id(&"rx".to_string())
+        .expect("No module sends pulses to rx");
+    let collector = sources
+        .neighbours(rx)
+        .next()
+        .expect("No module sends pulses to rx");
+    let Some(ModuleLogic::Nand(counters)) = &modules.states[collector] else {
+        panic!("rx isn't fed by a conjunction");
+    };
+
+    counters
+        .keys()
+        .map(|&counter| {
+            // Simulate just the modules that send pulses to the counter, so
+            // the others don't add to its period
+            let upstream = sources
+                .bfs(counter)
+                .iter()
+                .map(Option::is_some)
+                .collect::<Vec<_>>();
+
+            let cycle = brent(FlipFlops(modules.states.clone()), |states| {
+                let mut states = states.clone();
+                modules.press_button(&mut states.0, &upstream);
+                states
+            });
+
+            // Only a counter that starts over from all off fires on every
+            // multiple of its period
+            assert_eq!(
+                cycle.start,
+                0,
+                "Counter {} doesn't return to all off",
+                modules.wires.node(counter)
+            );
+
+            cycle.period as u64
+        })
+        .fold(1, lcm)
+}
+
+/// Presses the button until `rx` gets a low pulse.
+#[cfg(test)]
+fn presses_until_rx_low_by_pressing(modules: &Modules) -> u64 {
+    let mut states = modules.states.clone();
+
+    let mut i = 0;
+
+    loop {
+        i += 1;
+
+        let rx_pulses = modules.press_button(&mut states, &[]).low_to_rx;
+
+        if i % 1000 == 0 {
+            tracing::debug!(button_presses = i, rx_pulses, "still pressing");
+        }
+
+        if rx_pulses == 1 {
+            break i;
+        }
+    }
+}
+
+/// `parse_modules` adds the broadcaster first, even if it has no outputs.
+const BROADCASTER: NodeId = 0;
+
+/// Pulses sent after one button press.
+struct PulseCounts {
+    low: usize,
+    high: usize,
+    low_to_rx: usize,
+}
+
+impl Modules {
+    /// Presses the button once, updating `states`. Only the modules marked in
+    /// `active` handle pulses, or all of them if it's empty.
+    fn press_button(&self, states: &mut [Option<ModuleLogic>], active: &[bool]) -> PulseCounts {
+        let rx = self.wires.id(&"rx".to_string());
+
+        // (target, pulse, source), the button isn't a module so it has no id
+        let mut state_deque = VecDeque::from([(BROADCASTER, false, NodeId::MAX)]);
+
+        let mut counts = PulseCounts {
+            low: 0,
+            high: 0,
+            low_to_rx: 0,
+        };
+
+        while let Some((target, pulse, source)) = state_deque.pop_front() {
+            if pulse {
+                counts.high += 1;
+            } else {
+                counts.low += 1;
+
+                if Some(target) == rx {
+                    counts.low_to_rx += 1;
+                }
+            }
+
+            if !active.is_empty() && !active[target] {
+                continue;
+            }
+
+            if let Some(logic) = &mut states[target] {
+                if let Some(next_pulse) = logic.handle_pulse(pulse, source) {
+                    for output in self.wires.neighbours(target) {
+                        state_deque.push_back((output, next_pulse, target));
+                    }
+                }
+            }
+        }
+
+        counts
+    }
+}
+
+fn parse_modules(input: &str) -> Result<Modules, ParseError> {
+    let mut wires = Graph::directed();
+    let mut states = Vec::new();
+
+    wires.add_node("broadcaster".to_string());
+
+    for line in input.lines() {
+        let Some((name_part, output_part)) = line.split_once(" -> ") else {
+            return Err(ParseError::new(line, "Expected '<module> -> <outputs>'"));
+        };
+
+        let (logic, name) = if name_part == "broadcaster" {
+            (ModuleLogic::Broadcaster, name_part)
+        } else if let Some(name) = name_part.strip_prefix('%') {
+            (ModuleLogic::FlipFlop(false), name)
+        } else if let Some(name) = name_part.strip_prefix('&') {
+            (ModuleLogic::Nand(HashMap::new()), name)
+        } else {
+            return Err(ParseError::new(name_part, "Unexpected module"));
+        };
+
+        let id = wires.add_node(name.to_owned());
+        for output in output_part.split(", ") {
+            wires.add_edge(name.to_owned(), output.to_owned(), ());
+        }
+
+        states.resize(wires.len(), None);
+        states[id] = Some(logic);
+    }
+
+    states.resize(wires.len(), None);
+
+    // Find all NAND inputs
+    let sources = wires.reversed();
+    for (id, state) in states.iter_mut().enumerate() {
+        if let Some(ModuleLogic::Nand(inputs)) = state {
+            inputs.extend(sources.neighbours(id).map(|source| (source, false)));
+        }
+    }
+
+    Ok(Modules { wires, states })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::differential::assert_equivalent;
+
+    // The puzzle has no example for part 2
+    aoc_common::example_tests! {
+        Day20,
+        part1_example: part1(include_str!("example")) == 32000000,
+        part1_example2: part1(include_str!("example2")) == 11687500,
+    }
+
+    #[test]
+    fn counter_periods_match_pressing() {
+        let modules = |rng: &mut Rng| {
+            let input = Day20.generate(rng, Size::Small).unwrap();
+            Day20.parse(&input).unwrap()
+        };
+
+        assert_equivalent(
+            20,
+            modules,
+            |_| Vec::new(),
+            presses_until_rx_low_by_pressing,
+            presses_until_rx_low,
+        );
+    }
+}
@@ -0,0 +1,352 @@
+use std::collections::VecDeque;
+
+use aoc_common::{Answer, ParseError, Rng, Size, Solution};
+
+pub struct Day21;
+
+/// The start position and which tiles are rocks.
+pub type Garden = ((usize, usize), Vec<Vec<bool>>);
+
+impl Solution for Day21 {
+    type Parsed = Garden;
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed, ParseError> {
+        parse_map(input)
+    }
+
+    fn part1(&self, (start, map): &Self::Parsed) -> Answer {
+        let count = count_garden_plots_after_steps(64, *start, map);
+
+        count.into()
+    }
+
+    fn part2(&self, (start, map): &Self::Parsed) -> Answer {
+        let count = count_infinite_garden_plots_after_steps(26501365, *start, map);
+
+        count.into()
+    }
+
+    fn generate(&self, rng: &mut Rng, size: Size) -> Option<String> {
+        let side = size.pick(11, 65, 131);
+        let middle = side / 2;
+        let mut input = String::new();
+
+        // A single 'S' in the middle, with its row, its column and the edges
+        // free of rocks like in the puzzle
+        for y in 0..side {
+            for x in 0..side {
+                let free = x == middle
+                    || y == middle
+                    || x == 0
+                    || y == 0
+                    || x == side - 1
+                    || y == side - 1;
+
+                input.push(if (x, y) == (middle, middle) {
+                    'S'
+                } else if !free && rng.chance(0.15) {
+                    '#'
+                } else {
+                    '.'
+                });
+            }
+            input.push('\n');
+        }
+
+        Some(input)
+    }
+}
+
+fn count_garden_plots_after_steps(steps: usize, start: (usize, usize), map: &[Vec<bool>]) -> usize {
+    reachable_plots(start, map, 1, &[steps])[0]
+}
+
+fn find_allowed_neighbours(
+    x: usize,
+    y: usize,
+    max_x: usize,
+    max_y: usize,
+    is_rock: impl Fn(usize, usize) -> bool,
+) -> Vec<(usize, usize)> {
+    let mut allowed = Vec::with_capacity(4);
+
+    // Up
+    if y > 0 && !is_rock(x, y - 1) {
+        allowed.push((x, y - 1));
+    }
+
+    // Down
+    if y < max_y && !is_rock(x, y + 1) {
+        allowed.push((x, y + 1));
+    }
+
+    // Left
+    if x > 0 && !is_rock(x - 1, y) {
+        allowed.push((x - 1, y));
+    }
+
+    // Right
+    if x < max_x && !is_rock(x + 1, y) {
+        allowed.push((x + 1, y));
+    }
+
+    allowed
+}
+
+fn count_infinite_garden_plots_after_steps(
+    steps: usize,
+    start: (usize, usize),
+    map: &[Vec<bool>],
+) -> usize {
+    let side = map.len();
+    let repeats = steps / side;
+    let remainder = steps % side;
+
+    if repeats < 4 {
+        return reachable_plots(start, map, 2 * repeats + 3, &[steps])[0];
+    }
+
+    // Once the steps reach a few maps away, every further map adds the same
+    // number of maps around the edge, so the plots grow quadratically. That
+    // relies on the row and column of the start being free of rocks, like in
+    // the real inputs but not the example
+    let counts = reachable_plots(
+        start,
+        map,
+        11,
+        &[
+            remainder + 2 * side,
+            remainder + 3 * side,
+            remainder + 4 * side,
+        ],
+    )
+    .into_iter()
+    .map(|count| count as i64)
+    .collect::<Vec<_>>();
+
+    let x = (repeats - 2) as i64;
+    let first_diff = counts[1] - counts[0];
+    let second_diff = counts[2] - 2 * counts[1] + counts[0];
+
+    (counts[0] + x * first_diff + x * (x - 1) / 2 * second_diff) as usize
+}
+
+/// Counts the plots reachable in exactly each of `steps` steps, on `tiles` by
+/// `tiles` copies of the map with the start in the middle one.
+fn reachable_plots(
+    start: (usize, usize),
+    map: &[Vec<bool>],
+    tiles: usize,
+    steps: &[usize],
+) -> Vec<usize> {
+    let (width, height) = (map[0].len(), map.len());
+    let (tiled_width, tiled_height) = (width * tiles, height * tiles);
+    let max_steps = steps.iter().copied().max().unwrap_or(0);
+
+    let start = (
+        start.0 + width * (tiles / 2),
+        start.1 + height * (tiles / 2),
+    );
+    let mut distances = vec![usize::MAX; tiled_width * tiled_height];
+    distances[start.1 * tiled_width + start.0] = 0;
+
+    let mut queue = VecDeque::from([start]);
+
+    while let Some((x, y)) = queue.pop_front() {
+        let distance = distances[y * tiled_width + x];
+        if distance == max_steps {
+            continue;
+        }
+
+        let neighbours =
+            find_allowed_neighbours(x, y, tiled_width - 1, tiled_height - 1, |x, y| {
+                map[y % height][x % width]
+            });
+
+        for (x, y) in neighbours {
+            if distances[y * tiled_width + x] == usize::MAX {
+                distances[y * tiled_width + x] = distance + 1;
+                queue.push_back((x, y));
+            }
+        }
+    }
+
+    // A plot reached in fewer steps can be reached again by stepping back and
+    // forth, if it takes an even number of steps more
+    steps
+        .iter()
+        .map(|&steps| {
+            distances
+                .iter()
+                .filter(|&&distance| distance <= steps && distance % 2 == steps % 2)
+                .count()
+        })
+        .collect()
+}
+
+fn parse_map(input: &str) -> Result<Garden, ParseError> {
+    let mut start = (0, 0);
+
+    let map = input
+        .lines()
+        .enumerate()
+        .map(|(y, line)| {
+            line.char_indices()
+                .enumerate()
+                .map(|(x, (idx, c))| match c {
+                    'S' => {
+                        start = (x, y);
+                        Ok(false)
+                    }
+                    '.' => Ok(false),
+                    '#' => Ok(true),
+                    _ => Err(ParseError::new(
+                        &line[idx..idx + c.len_utf8()],
+                        "Unexpected character",
+                    )),
+                })
+                .collect()
+        })
+        .collect::<Result<_, _>>()?;
+
+    Ok((start, map))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use super::*;
+    use aoc_common::differential::assert_equivalent;
+
+    // The solver always takes 64 steps, the puzzle text only gives the count after 6
+    aoc_common::example_tests! {
+        Day21,
+        part1_example: part1(include_str!("example")) == 42,
+    }
+
+    fn garden_and_steps(rng: &mut Rng, max_steps: usize) -> (Garden, usize) {
+        let input = Day21.generate(rng, Size::Small).unwrap();
+
+        (parse_map(&input).unwrap(), rng.index(max_steps + 1))
+    }
+
+    /// Clears one rock or takes fewer steps.
+    fn shrink(((start, map), steps): &(Garden, usize)) -> Vec<(Garden, usize)> {
+        let mut smaller = vec![];
+
+        for fewer in [steps / 2, steps.saturating_sub(1)] {
+            if fewer < *steps {
+                smaller.push(((*start, map.clone()), fewer));
+            }
+        }
+
+        for (y, row) in map.iter().enumerate() {
+            for x in (0..row.len()).filter(|&x| row[x]) {
+                let mut map = map.clone();
+                map[y][x] = false;
+                smaller.push(((*start, map), *steps));
+            }
+        }
+
+        smaller
+    }
+
+    #[test]
+    fn distances_match_sets() {
+        assert_equivalent(
+            50,
+            |rng| garden_and_steps(rng, 30),
+            shrink,
+            |((start, map), steps)| count_garden_plots_after_steps_by_sets(*steps, *start, map),
+            |((start, map), steps)| count_garden_plots_after_steps(*steps, *start, map),
+        );
+    }
+
+    #[test]
+    fn extrapolation_matches_sets() {
+        // Up to 7 maps away, so most cases are extrapolated
+        assert_equivalent(
+            30,
+            |rng| garden_and_steps(rng, 7 * 11),
+            shrink,
+            |((start, map), steps)| {
+                count_infinite_garden_plots_after_steps_by_sets(*steps, *start, map)
+            },
+            |((start, map), steps)| count_infinite_garden_plots_after_steps(*steps, *start, map),
+        );
+    }
+
+    /// Counts the plots reachable in exactly `steps` steps by keeping the set of
+    /// them after every step.
+    fn count_garden_plots_after_steps_by_sets(
+        steps: usize,
+        start: (usize, usize),
+        map: &[Vec<bool>],
+    ) -> usize {
+        let max_x = map.first().map(|l| l.len() - 1).unwrap_or(0);
+        let max_y = map.len() - 1;
+
+        let mut current_position_set = HashSet::new();
+        current_position_set.insert(start);
+
+        for _ in 0..steps {
+            let current_positions = std::mem::take(&mut current_position_set);
+
+            for pos in current_positions {
+                let neighbours =
+                    find_allowed_neighbours(pos.0, pos.1, max_x, max_y, |x, y| map[y][x]);
+
+                for neighbour in neighbours {
+                    current_position_set.insert(neighbour);
+                }
+            }
+        }
+
+        current_position_set.len()
+    }
+
+    /// Counts the plots reachable in exactly `steps` steps on the infinite map by
+    /// keeping the set of them after every step.
+    fn count_infinite_garden_plots_after_steps_by_sets(
+        steps: usize,
+        start: (usize, usize),
+        map: &[Vec<bool>],
+    ) -> usize {
+        let max_x = map.first().map(|l| l.len() - 1).unwrap_or(0);
+        let max_y = map.len() - 1;
+
+        let mut current_position_set = HashSet::new();
+        current_position_set.insert((start.0 as isize, start.1 as isize));
+
+        for _ in 0..steps {
+            let current_positions = std::mem::take(&mut current_position_set);
+
+            for pos in current_positions {
+                for (offset_x, offset_y) in [(0, 1), (1, 0), (0, -1), (-1, 0)] {
+                    let x = pos.0 + offset_x;
+                    let y = pos.1 + offset_y;
+
+                    let idx_x = coord_to_idx(x, max_x);
+                    let idx_y = coord_to_idx(y, max_y);
+
+                    if !map[idx_y][idx_x] {
+                        current_position_set.insert((x, y));
+                    }
+                }
+            }
+        }
+
+        current_position_set.len()
+    }
+
+    fn coord_to_idx(coord: isize, max: usize) -> usize {
+        let idx = coord % (max as isize + 1);
+
+        if idx < 0 {
+            (idx + max as isize + 1) as usize
+        } else {
+            idx as usize
+        }
+    }
+}
@@ -1,37 +1,70 @@
-use std::{
-    collections::{HashMap, HashSet},
-    env,
-    fs::File,
-    io::{BufRead, BufReader},
-};
-
-fn main() {
-    let Some(filepath) = env::args().nth(1) else {
-        println!("Usage: part2 <filepath>");
-        return;
-    };
+use std::collections::{HashMap, HashSet};
 
-    let file = match File::open(filepath) {
-        Ok(file) => file,
-        Err(err) => {
-            println!("Error opening input file: {err}");
-            return;
-        }
-    };
+use aoc_common::{parse_num, Answer, ParseError, Rng, Size, Solution};
+
+pub struct Day22;
+
+impl Solution for Day22 {
+    type Parsed = Vec<Brick>;
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed, ParseError> {
+        parse_bricks(input)
+    }
+
+    fn part1(&self, bricks: &Self::Parsed) -> Answer {
+        let stacked_bricks = stack_bricks(bricks.to_vec());
+
+        let count = count_disintegratable_bricks(&stacked_bricks);
 
-    let reader = BufReader::new(file);
+        count.into()
+    }
+
+    fn part2(&self, bricks: &Self::Parsed) -> Answer {
+        let stacked_bricks = stack_bricks(bricks.to_vec());
 
-    let bricks = parse_bricks(reader);
+        let count = count_falling_bricks(&stacked_bricks);
 
-    let stacked_bricks = stack_bricks(bricks);
+        count.into()
+    }
 
-    let count = count_falling_bricks(&stacked_bricks);
+    fn generate(&self, rng: &mut Rng, size: Size) -> Option<String> {
+        let (brick_count, side) = size.pick((20, 3), (300, 6), (1200, 10));
 
-    println!("Count {count}");
+        let mut occupied = HashSet::new();
+        let mut input = String::new();
+
+        for _ in 0..brick_count {
+            let len = rng.int(0..=3) as usize;
+            let axis = rng.index(3);
+            let (dx, dy, dz) = [(len, 0, 0), (0, len, 0), (0, 0, len)][axis];
+
+            // Only bricks along x and y have to fit into the footprint
+            let (dx, dy) = (dx.min(side - 1), dy.min(side - 1));
+            let x = rng.index(side - dx);
+            let y = rng.index(side - dy);
+            let mut z = rng.int(1..=brick_count as i64 / 4 + 1) as usize;
+
+            let cells = |z: usize| {
+                (x..=x + dx).flat_map(move |x| {
+                    (y..=y + dy).flat_map(move |y| (z..=z + dz).map(move |z| (x, y, z)))
+                })
+            };
+
+            // Snapshots never have bricks overlapping
+            while cells(z).any(|cell| occupied.contains(&cell)) {
+                z += 1;
+            }
+            occupied.extend(cells(z));
+
+            input += &format!("{x},{y},{z}~{},{},{}\n", x + dx, y + dy, z + dz);
+        }
+
+        Some(input)
+    }
 }
 
-#[derive(Debug)]
-struct Brick {
+#[derive(Debug, Clone)]
+pub struct Brick {
     start: (usize, usize, usize),
     end: (usize, usize, usize),
 }
@@ -85,7 +118,8 @@ impl Area {
     }
 }
 
-fn count_falling_bricks(stacked_bricks: &[Brick]) -> usize {
+/// Maps each brick index to the indices of the bricks it rests on.
+fn find_supporters(stacked_bricks: &[Brick]) -> HashMap<usize, Vec<usize>> {
     // (bottom z of block) -> Vec<(block idx, area occupied by that block)>
     let mut z_area_map: HashMap<usize, Vec<(usize, Area)>> = HashMap::new();
 
@@ -123,6 +157,26 @@ fn count_falling_bricks(stacked_bricks: &[Brick]) -> usize {
         }
     }
 
+    supporters_map
+}
+
+fn count_disintegratable_bricks(stacked_bricks: &[Brick]) -> usize {
+    let supporters_map = find_supporters(stacked_bricks);
+
+    let mut disintegratable_blocks: HashSet<usize> = HashSet::from_iter(0..stacked_bricks.len());
+
+    for supported_by_idxs in supporters_map.values() {
+        if supported_by_idxs.len() == 1 {
+            disintegratable_blocks.remove(&supported_by_idxs[0]);
+        }
+    }
+
+    disintegratable_blocks.len()
+}
+
+fn count_falling_bricks(stacked_bricks: &[Brick]) -> usize {
+    let supporters_map = find_supporters(stacked_bricks);
+
     // (block idx) -> Vec<is supporting block idx>
     let mut supporters_map_rev: HashMap<usize, Vec<usize>> = HashMap::new();
 
@@ -171,8 +225,7 @@ fn stack_bricks(mut bricks: Vec<Brick>) -> Vec<Brick> {
     let mut z_map = HashMap::with_capacity(bricks.len());
 
     // Sort by z asc
-    bricks
-        .sort_by(|a, b| std::cmp::min(a.start.2, a.end.2).cmp(&std::cmp::min(b.start.2, b.end.2)));
+    bricks.sort_by_key(|a| std::cmp::min(a.start.2, a.end.2));
 
     for brick in bricks.iter_mut() {
         let max_exisiting_z = brick
@@ -197,27 +250,42 @@ fn stack_bricks(mut bricks: Vec<Brick>) -> Vec<Brick> {
     bricks
 }
 
-fn parse_bricks(reader: BufReader<File>) -> Vec<Brick> {
-    reader
+fn parse_bricks(input: &str) -> Result<Vec<Brick>, ParseError> {
+    input
         .lines()
         .map(|line| {
-            let line = line.expect("Failed to read line");
-
-            let (start, end) = line.split_once('~').expect("Line split failed");
-
-            let start = start
-                .split(',')
-                .map(|n| n.parse().expect("Num parse failed"))
-                .collect::<Vec<_>>();
-            let end = end
-                .split(',')
-                .map(|n| n.parse().expect("Num parse failed"))
-                .collect::<Vec<_>>();
-
-            Brick {
-                start: (start[0], start[1], start[2]),
-                end: (end[0], end[1], end[2]),
-            }
+            let Some((start, end)) = line.split_once('~') else {
+                return Err(ParseError::new(line, "Expected '<x>,<y>,<z>~<x>,<y>,<z>'"));
+            };
+
+            Ok(Brick {
+                start: parse_position(start)?,
+                end: parse_position(end)?,
+            })
         })
         .collect()
 }
+
+fn parse_position(text: &str) -> Result<(usize, usize, usize), ParseError> {
+    let nums = text
+        .split(',')
+        .map(parse_num)
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let [x, y, z] = nums[..] else {
+        return Err(ParseError::new(text, "Expected '<x>,<y>,<z>'"));
+    };
+
+    Ok((x, y, z))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    aoc_common::example_tests! {
+        Day22,
+        part1_example: part1(include_str!("example")) == 5,
+        part2_example: part2(include_str!("example")) == 7,
+    }
+}
@@ -0,0 +1,489 @@
+use aoc_common::{
+    graph::{Graph, NodeId},
+    search, Answer, Direction, Grid, ParseError, Rng, Size, Solution,
+};
+
+pub struct Day23;
+
+impl Solution for Day23 {
+    type Parsed = Grid<Tile>;
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed, ParseError> {
+        parse_map(input)
+    }
+
+    fn part1(&self, map: &Self::Parsed) -> Answer {
+        let start = (1, 0);
+        let end = (map.width() - 2, map.height() - 1);
+
+        let trails = map_to_adj_graph(start, end, map);
+
+        let steps = longest_path(&trails, start, end).expect("No path found");
+
+        steps.into()
+    }
+
+    fn part2(&self, map: &Self::Parsed) -> Answer {
+        let start = (1, 0);
+        let end = (map.width() - 2, map.height() - 1);
+
+        let trails = map_to_undirected_adj_graph(start, end, map);
+
+        let steps = longest_path_ignoring_slopes(&trails, start, end).expect("No path found");
+
+        steps.into()
+    }
+
+    fn generate(&self, rng: &mut Rng, size: Size) -> Option<String> {
+        let (junctions, max_gap) = size.pick((3, 8), (4, 12), (6, 24));
+
+        Some(
+            generate_trails(rng, junctions, max_gap)
+                .rows()
+                .map(|row| row.iter().collect::<String>() + "\n")
+                .collect(),
+        )
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum Tile {
+    Path,
+    Slope(Direction),
+    Block,
+}
+
+/// The longest path from `start` to `end`, which must be acyclic, as the
+/// cheapest one when every step costs -1.
+fn longest_path(
+    trails: &Graph<(usize, usize)>,
+    start: (usize, usize),
+    end: (usize, usize),
+) -> Option<usize> {
+    let costs = search::costs_from(trails.id(&start)?, |tile| {
+        trails.neighbours(*tile).map(|next| (next, -1_isize))
+    });
+
+    tracing::debug!(
+        expanded = costs.stats.expanded,
+        frontier_peak = costs.stats.frontier_peak,
+        "searched"
+    );
+
+    costs.cost(&trails.id(&end)?).map(|cost| (-cost) as usize)
+}
+
+fn map_to_adj_graph(
+    start: (usize, usize),
+    end: (usize, usize),
+    map: &Grid<Tile>,
+) -> Graph<(usize, usize)> {
+    let mut trails = Graph::directed();
+    trails.add_node(start);
+
+    let mut tiles_to_examine = vec![(start, Direction::Down)];
+
+    while let Some((tile, dir)) = tiles_to_examine.pop() {
+        let neighbours = get_neighbours_for_tile(tile, dir, map);
+
+        for neighbour in neighbours {
+            // The direction is the one of the step, so the neighbour was
+            // already examined coming from this tile
+            if !trails.contains_edge(&tile, &neighbour.0) {
+                trails.add_edge(tile, neighbour.0, ());
+                tiles_to_examine.push(neighbour);
+            }
+        }
+    }
+
+    trails.add_node(end);
+
+    trails
+}
+
+fn get_neighbours_for_tile(
+    pos: (usize, usize),
+    dir: Direction,
+    map: &Grid<Tile>,
+) -> Vec<((usize, usize), Direction)> {
+    match map[pos] {
+        Tile::Path => {}
+        Tile::Slope(slope) => {
+            let next = map.step(pos, slope).expect("Slope leads off the map");
+            return vec![(next, slope)];
+        }
+        Tile::Block => unreachable!(),
+    }
+
+    Direction::ALL
+        .into_iter()
+        // Never walk back to the tile we came from
+        .filter(|next_dir| *next_dir != dir.opposite())
+        .filter_map(|next_dir| map.step(pos, next_dir).map(|next| (next, next_dir)))
+        // Slopes can't be climbed against their direction
+        .filter(|(next, next_dir)| match map[*next] {
+            Tile::Path => true,
+            Tile::Slope(slope) => slope != next_dir.opposite(),
+            Tile::Block => false,
+        })
+        .collect()
+}
+
+fn longest_path_ignoring_slopes(
+    tiles: &Graph<(usize, usize)>,
+    start: (usize, usize),
+    end: (usize, usize),
+) -> Option<usize> {
+    let junctions = junction_graph(tiles, start, end);
+
+    tracing::trace!(
+        "junctions:\n{}",
+        junctions.to_dot(|(x, y)| format!("{x},{y}"), |len| Some(len.to_string()))
+    );
+
+    let start = junctions.id(&start)?;
+    let end = junctions.id(&end)?;
+
+    longest_trail(start, end, &junctions, &mut vec![false; junctions.len()])
+}
+
+/// Collapses the corridors between junctions into trails, as a graph of the
+/// junctions (plus the start, the end and dead ends) with the trails leaving
+/// each of them, weighted by their length.
+fn junction_graph(
+    tiles: &Graph<(usize, usize)>,
+    start: (usize, usize),
+    end: (usize, usize),
+) -> Graph<(usize, usize), usize> {
+    let is_junction = |tile: NodeId| {
+        *tiles.node(tile) == start || *tiles.node(tile) == end || tiles.edges(tile).len() != 2
+    };
+
+    let mut junctions = Graph::directed();
+
+    for junction in (0..tiles.len()).filter(|tile| is_junction(*tile)) {
+        junctions.add_node(*tiles.node(junction));
+
+        for first in tiles.neighbours(junction) {
+            let (mut prev, mut tile, mut len) = (junction, first, 1);
+
+            let reached_junction = loop {
+                if is_junction(tile) {
+                    break true;
+                }
+
+                let Some(next) = tiles.neighbours(tile).find(|next| *next != prev) else {
+                    break false;
+                };
+                (prev, tile, len) = (tile, next, len + 1);
+            };
+
+            if reached_junction && tile != junction {
+                junctions.add_edge(*tiles.node(junction), *tiles.node(tile), len);
+            }
+        }
+    }
+
+    junctions
+}
+
+fn longest_trail(
+    junction: NodeId,
+    end: NodeId,
+    junctions: &Graph<(usize, usize), usize>,
+    visited: &mut [bool],
+) -> Option<usize> {
+    if junction == end {
+        return Some(0);
+    }
+
+    visited[junction] = true;
+
+    let mut longest = None;
+
+    for &(next, len) in junctions.edges(junction) {
+        if !visited[next] {
+            if let Some(rest) = longest_trail(next, end, junctions, visited) {
+                longest = longest.max(Some(rest + len));
+            }
+        }
+    }
+
+    visited[junction] = false;
+
+    longest
+}
+
+/// Tries every path tile by tile. Takes ~30min on the real input :(
+#[cfg(test)]
+fn longest_path_ignoring_slopes_brute_force(
+    tiles: &Graph<(usize, usize)>,
+    start: (usize, usize),
+    end: (usize, usize),
+) -> Option<usize> {
+    let mut dist = vec![0; tiles.len()];
+    let mut visited = vec![false; tiles.len()];
+
+    lp(tiles.id(&start)?, 0, &mut dist, &mut visited, tiles);
+
+    let end_cost = dist[tiles.id(&end)?];
+
+    if end_cost != 0 {
+        Some(end_cost)
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+fn lp(
+    node: NodeId,
+    cost: usize,
+    dist: &mut [usize],
+    visited: &mut [bool],
+    tiles: &Graph<(usize, usize)>,
+) {
+    if visited[node] {
+        return;
+    }
+
+    visited[node] = true;
+
+    if dist[node] < cost {
+        dist[node] = cost;
+    }
+
+    for edge in tiles.neighbours(node) {
+        lp(edge, cost + 1, dist, visited, tiles);
+    }
+
+    visited[node] = false;
+}
+
+fn map_to_undirected_adj_graph(
+    start: (usize, usize),
+    end: (usize, usize),
+    map: &Grid<Tile>,
+) -> Graph<(usize, usize)> {
+    let mut tiles = Graph::undirected();
+    let mut visited = Grid::new(map.width(), map.height(), false);
+
+    tiles.add_node(start);
+
+    let mut tiles_to_examine = vec![start];
+
+    while let Some(tile) = tiles_to_examine.pop() {
+        if visited[tile] {
+            continue;
+        }
+        visited[tile] = true;
+
+        // Edges to examined tiles were added when examining those
+        for neighbour in get_undirected_neighbours_for_tile(tile, map) {
+            if !visited[neighbour] {
+                tiles.add_edge(tile, neighbour, ());
+                tiles_to_examine.push(neighbour);
+            }
+        }
+    }
+
+    tiles.add_node(end);
+
+    tiles
+}
+
+fn get_undirected_neighbours_for_tile(
+    pos: (usize, usize),
+    map: &Grid<Tile>,
+) -> Vec<(usize, usize)> {
+    map.neighbours4(pos)
+        .filter(|next| !matches!(map[*next], Tile::Block))
+        .collect()
+}
+
+fn parse_map(input: &str) -> Result<Grid<Tile>, ParseError> {
+    Grid::parse(input, |c| match c {
+        '.' => Some(Tile::Path),
+        '#' => Some(Tile::Block),
+        '^' => Some(Tile::Slope(Direction::Up)),
+        'v' => Some(Tile::Slope(Direction::Down)),
+        '<' => Some(Tile::Slope(Direction::Left)),
+        '>' => Some(Tile::Slope(Direction::Right)),
+        _ => None,
+    })
+}
+
+/// A lattice of junctions with trails going right and down between them, some
+/// taking a detour. Slopes around every junction keep the trails one way, and
+/// the trails never touch, so the map is a DAG from the top left to the bottom
+/// right like in the puzzle.
+fn generate_trails(rng: &mut Rng, junctions: usize, max_gap: i64) -> Grid<char> {
+    let coords = |rng: &mut Rng, first| {
+        let mut coords = vec![first];
+        for _ in 1..junctions {
+            coords.push(coords[coords.len() - 1] + rng.int(6..=max_gap) as usize);
+        }
+        coords
+    };
+    let first_x = rng.int(3..=5) as usize;
+    let xs = coords(rng, first_x);
+    let first_y = rng.int(2..=4) as usize;
+    let ys = coords(rng, first_y);
+
+    let (last_x, last_y) = (xs[junctions - 1], ys[junctions - 1]);
+    let width = last_x + rng.int(4..=6) as usize;
+    let height = last_y + rng.int(2..=4) as usize;
+
+    let mut tiles = Grid::new(width, height, '#');
+
+    let dig = |tiles: &mut Grid<char>, corners: &[(usize, usize)]| {
+        for pair in corners.windows(2) {
+            let ((x1, y1), (x2, y2)) = (pair[0], pair[1]);
+
+            for x in x1.min(x2)..=x1.max(x2) {
+                for y in y1.min(y2)..=y1.max(y2) {
+                    tiles[(x, y)] = '.';
+                }
+            }
+        }
+    };
+
+    dig(&mut tiles, &[(1, 0), (1, ys[0]), (xs[0], ys[0])]);
+    tiles[(xs[0] - 1, ys[0])] = '>';
+
+    dig(
+        &mut tiles,
+        &[
+            (last_x, last_y),
+            (width - 2, last_y),
+            (width - 2, height - 1),
+        ],
+    );
+    tiles[(last_x + 1, last_y)] = '>';
+
+    // Each cell between the trails takes at most one detour
+    let mut used = Grid::new(junctions - 1, junctions - 1, false);
+    let mut detour = |rng: &mut Rng, cells: [Option<(usize, usize)>; 2]| {
+        let free = cells
+            .into_iter()
+            .enumerate()
+            .filter_map(|(side, cell)| cell.filter(|cell| !used[*cell]).map(|cell| (side, cell)))
+            .collect::<Vec<_>>();
+
+        if free.is_empty() || rng.chance(0.3) {
+            return None;
+        }
+
+        let (side, cell) = *rng.pick(&free);
+        used[cell] = true;
+
+        Some((side, cell))
+    };
+
+    for j in 0..junctions {
+        for i in 0..junctions {
+            let (x, y) = (xs[i], ys[j]);
+
+            if i + 1 < junctions {
+                let next_x = xs[i + 1];
+                let above = j.checked_sub(1).map(|j| (i, j));
+                let below = (j + 1 < junctions).then_some((i, j));
+
+                let row = match detour(rng, [above, below]) {
+                    Some((0, _)) => Some(y - rng.int(1..=(y - ys[j - 1]) as i64 - 2) as usize),
+                    Some(_) => Some(y + rng.int(1..=(ys[j + 1] - y) as i64 - 2) as usize),
+                    None => None,
+                };
+
+                match row {
+                    Some(row) => dig(
+                        &mut tiles,
+                        &[
+                            (x, y),
+                            (x + 2, y),
+                            (x + 2, row),
+                            (next_x - 2, row),
+                            (next_x - 2, y),
+                            (next_x, y),
+                        ],
+                    ),
+                    None => dig(&mut tiles, &[(x, y), (next_x, y)]),
+                }
+                tiles[(x + 1, y)] = '>';
+                tiles[(next_x - 1, y)] = '>';
+            }
+
+            if j + 1 < junctions {
+                let next_y = ys[j + 1];
+                let left = i.checked_sub(1).map(|i| (i, j));
+                let right = (i + 1 < junctions).then_some((i, j));
+
+                let column = match detour(rng, [left, right]) {
+                    Some((0, _)) => Some(x - rng.int(1..=(x - xs[i - 1]) as i64 - 2) as usize),
+                    Some(_) => Some(x + rng.int(1..=(xs[i + 1] - x) as i64 - 2) as usize),
+                    None => None,
+                };
+
+                match column {
+                    Some(column) => dig(
+                        &mut tiles,
+                        &[
+                            (x, y),
+                            (x, y + 2),
+                            (column, y + 2),
+                            (column, next_y - 2),
+                            (x, next_y - 2),
+                            (x, next_y),
+                        ],
+                    ),
+                    None => dig(&mut tiles, &[(x, y), (x, next_y)]),
+                }
+                tiles[(x, y + 1)] = 'v';
+                tiles[(x, next_y - 1)] = 'v';
+            }
+        }
+    }
+
+    tiles
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::differential::{assert_equivalent, shrink_cells};
+
+    aoc_common::example_tests! {
+        Day23,
+        part1_example: part1(include_str!("example")) == 94,
+        part2_example: part2(include_str!("example")) == 154,
+    }
+
+    #[test]
+    fn junctions_match_brute_force() {
+        let map = |rng: &mut Rng| parse_map(&Day23.generate(rng, Size::Medium).unwrap()).unwrap();
+        // Blocking any tile but the start and the end keeps the map valid
+        let shrink = |map: &Grid<Tile>| {
+            let (start, end) = ((1, 0), (map.width() - 2, map.height() - 1));
+
+            shrink_cells(map, |tile| {
+                (!matches!(tile, Tile::Block)).then_some(Tile::Block)
+            })
+            .into_iter()
+            .filter(|shrunk| !matches!(shrunk[start], Tile::Block))
+            .filter(|shrunk| !matches!(shrunk[end], Tile::Block))
+            .collect()
+        };
+        let longest = |map: &Grid<Tile>, f: fn(&_, _, _) -> Option<usize>| {
+            let (start, end) = ((1, 0), (map.width() - 2, map.height() - 1));
+            f(&map_to_undirected_adj_graph(start, end, map), start, end)
+        };
+
+        assert_equivalent(
+            20,
+            map,
+            shrink,
+            |map| longest(map, longest_path_ignoring_slopes_brute_force),
+            |map| longest(map, longest_path_ignoring_slopes),
+        );
+    }
+}
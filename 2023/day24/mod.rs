@@ -0,0 +1,231 @@
+use std::collections::HashSet;
+
+use aoc_common::{number::Integer, parse_num, Answer, ParseError, Rng, Size, Solution};
+
+pub struct Day24;
+
+impl Solution for Day24 {
+    type Parsed = Vec<Hailstone>;
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed, ParseError> {
+        parse_hailstones(input)
+    }
+
+    fn part1(&self, hailstones: &Self::Parsed) -> Answer {
+        let count = count_in_area(hailstones, 200000000000000, 400000000000000);
+
+        count.into()
+    }
+
+    fn part2(&self, _parsed: &Self::Parsed) -> Answer {
+        unreachable!("Day 24 part 2 isn't solved yet")
+    }
+
+    fn parts(&self) -> u32 {
+        1
+    }
+
+    fn generate(&self, rng: &mut Rng, size: Size) -> Option<String> {
+        // Like in the puzzle, every hailstone is on course to be hit by one
+        // rock thrown at some point in time
+        let rock_pos = [(); 3].map(|_| rng.int(250_000_000_000_000..=350_000_000_000_000));
+        let rock_vel = [(); 3].map(|_| rng.int(-300..=300));
+
+        let mut times = HashSet::new();
+        let mut input = String::new();
+
+        for _ in 0..size.pick(5, 50, 300) {
+            let time = loop {
+                let time = rng.int(100_000_000_000..=500_000_000_000);
+                if times.insert(time) {
+                    break time;
+                }
+            };
+
+            let vel = [(); 3].map(|_| rng.int(-600..=600));
+            let pos = [0, 1, 2].map(|axis| rock_pos[axis] + time * (rock_vel[axis] - vel[axis]));
+
+            input += &format!(
+                "{}, {}, {} @ {}, {}, {}\n",
+                pos[0], pos[1], pos[2], vel[0], vel[1], vel[2]
+            );
+        }
+
+        Some(input)
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Hailstone {
+    pos: (i128, i128, i128),
+    vel: (i128, i128, i128),
+}
+
+/// A point with the exact coordinates `(x / denom, y / denom)`.
+#[derive(Debug)]
+struct CollisionPoint {
+    x: i128,
+    y: i128,
+    /// Always positive.
+    denom: i128,
+}
+
+impl CollisionPoint {
+    /// Whether both coordinates lie within `min..=max`, or `None` if the
+    /// bounds overflow when scaled by `denom`.
+    fn is_within(&self, min: i128, max: i128) -> Option<bool> {
+        let (min, max) = (min.checked_mul(self.denom)?, max.checked_mul(self.denom)?);
+
+        Some((min..=max).contains(&self.x) && (min..=max).contains(&self.y))
+    }
+}
+
+/// The number of pairs whose paths cross in the future within `min..=max` on
+/// both the x and y axis.
+fn count_in_area(hailstones: &[Hailstone], min: i128, max: i128) -> usize {
+    get_collisions_of_pairs(hailstones)
+        .iter()
+        .filter(|point| {
+            point.is_within(min, max).unwrap_or_else(|| {
+                tracing::debug!("Skipping {point:?}, the bounds overflow at its scale");
+                false
+            })
+        })
+        .count()
+}
+
+fn get_collisions_of_pairs(hailstones: &[Hailstone]) -> Vec<CollisionPoint> {
+    let mut collisions = Vec::new();
+
+    for (offset, a) in hailstones.iter().enumerate() {
+        for b in hailstones[(offset + 1)..].iter() {
+            match get_xy_collision_point_in_future(a, b) {
+                Some(Some(collision_point)) => collisions.push(collision_point),
+                Some(None) => {}
+                None => tracing::debug!("Skipping {a:?} and {b:?}, their collision overflows"),
+            }
+        }
+    }
+
+    collisions
+}
+
+/// `a * b - c * d`, or `None` if it overflows.
+fn checked_cross<T: Integer>(a: T, b: T, c: T, d: T) -> Option<T> {
+    a.checked_mul(b)?.checked_sub(c.checked_mul(d)?)
+}
+
+/// Where the paths of both hailstones cross in the future, ignoring the z
+/// axis. The outer `None` means the numbers are too big to work it out.
+fn get_xy_collision_point_in_future(
+    a: &Hailstone,
+    b: &Hailstone,
+) -> Option<Option<CollisionPoint>> {
+    let x1 = a.pos.0;
+    let y1 = a.pos.1;
+    let x2 = x1.checked_add(a.vel.0)?;
+    let y2 = y1.checked_add(a.vel.1)?;
+
+    let x3 = b.pos.0;
+    let y3 = b.pos.1;
+    let x4 = x3.checked_add(b.vel.0)?;
+    let y4 = y3.checked_add(b.vel.1)?;
+
+    let (dx12, dy12) = (x1.checked_sub(x2)?, y1.checked_sub(y2)?);
+    let (dx34, dy34) = (x3.checked_sub(x4)?, y3.checked_sub(y4)?);
+
+    let denom = checked_cross(dx12, dy34, dy12, dx34)?;
+
+    if denom == 0 {
+        return Some(None);
+    }
+
+    let det12 = checked_cross(x1, y2, y1, x2)?;
+    let det34 = checked_cross(x3, y4, y3, x4)?;
+    let px_num = checked_cross(det12, dx34, dx12, det34)?;
+    let py_num = checked_cross(det12, dy34, dy12, det34)?;
+
+    let collision_point = CollisionPoint {
+        x: px_num.checked_mul(denom.signum())?,
+        y: py_num.checked_mul(denom.signum())?,
+        denom: denom.checked_abs()?,
+    };
+
+    // They only cross in the future if both hailstones fly towards the collision point.
+
+    let flies_towards = |hailstone: &Hailstone| {
+        let dx = collision_point
+            .x
+            .checked_sub(hailstone.pos.0.checked_mul(collision_point.denom)?)?;
+        let dy = collision_point
+            .y
+            .checked_sub(hailstone.pos.1.checked_mul(collision_point.denom)?)?;
+
+        Some(dx.signum() == hailstone.vel.0.signum() && dy.signum() == hailstone.vel.1.signum())
+    };
+
+    Some((flies_towards(a)? && flies_towards(b)?).then_some(collision_point))
+}
+
+fn parse_hailstones(input: &str) -> Result<Vec<Hailstone>, ParseError> {
+    input
+        .lines()
+        .map(|line| {
+            let Some((pos, vel)) = line.split_once(" @ ") else {
+                return Err(ParseError::new(line, "Expected '<position> @ <velocity>'"));
+            };
+
+            Ok(Hailstone {
+                pos: parse_triple(pos)?,
+                vel: parse_triple(vel)?,
+            })
+        })
+        .collect()
+}
+
+fn parse_triple<T: std::str::FromStr + Copy>(text: &str) -> Result<(T, T, T), ParseError> {
+    let nums = text
+        .split(',')
+        .map(|num| parse_num(num.trim()))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let [x, y, z] = nums[..] else {
+        return Err(ParseError::new(text, "Expected '<x>, <y>, <z>'"));
+    };
+
+    Ok((x, y, z))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    aoc_common::example_tests! {
+        Day24,
+        part1_overflowing: part1(concat!(
+            "100000000000000000000, 0, 0 @ -10000000000000000000, 10000000000000000000, 0\n",
+            "0, 0, 0 @ 10000000000000000000, 10000000000000000000, 0\n",
+        )) == 0usize,
+    }
+
+    #[test]
+    fn example_in_test_area() {
+        let hailstones = Day24.parse(include_str!("example")).unwrap();
+
+        assert_eq!(count_in_area(&hailstones, 7, 27), 2);
+    }
+
+    #[test]
+    fn overflowing_pairs_are_skipped() {
+        let a = Hailstone {
+            pos: (10i128.pow(20), 0, 0),
+            vel: (-(10i128.pow(19)), 10i128.pow(19), 0),
+        };
+        let b = Hailstone {
+            pos: (0, 0, 0),
+            vel: (10i128.pow(19), 10i128.pow(19), 0),
+        };
+
+        assert!(get_xy_collision_point_in_future(&a, &b).is_none());
+    }
+}
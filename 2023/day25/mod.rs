@@ -0,0 +1,155 @@
+use std::collections::{BTreeMap, BTreeSet, HashSet};
+
+use aoc_common::{diagnostics, graph::Graph, Answer, ParseError, Rng, Size, Solution};
+
+pub struct Day25;
+
+impl Solution for Day25 {
+    type Parsed = Graph<usize>;
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed, ParseError> {
+        parse_edges(input)
+    }
+
+    fn part1(&self, wires: &Self::Parsed) -> Answer {
+        let cut = wires
+            .min_cut(|_| 1)
+            .expect("Need at least two components to cut");
+        tracing::debug!(wires = cut.weight, "cut");
+
+        let a_size = cut.side.len();
+        let b_size = wires.len() - a_size;
+
+        diagnostics::record("group_a_size", a_size);
+        diagnostics::record("group_b_size", b_size);
+
+        (a_size * b_size).into()
+    }
+
+    fn part2(&self, _parsed: &Self::Parsed) -> Answer {
+        unreachable!("Day 25 only has one part")
+    }
+
+    fn parts(&self) -> u32 {
+        1
+    }
+
+    fn generate(&self, rng: &mut Rng, size: Size) -> Option<String> {
+        let group_sizes = size.pick(8..=12, 50..=100, 650..=800);
+
+        let mut names = HashSet::new();
+        let mut name = |rng: &mut Rng| loop {
+            let name = (0..3)
+                .map(|_| (b'a' + rng.index(26) as u8) as char)
+                .collect::<String>();
+
+            if names.insert(name.clone()) {
+                break name;
+            }
+        };
+
+        let mut edges = Vec::new();
+        let mut groups = Vec::new();
+
+        // Two groups where every component has at least four wires, so the
+        // three wires between the groups are the only cut of that size
+        for _ in 0..2 {
+            let group = (0..rng.int(group_sizes.clone()))
+                .map(|_| name(rng))
+                .collect::<Vec<_>>();
+            let mut neighbours = vec![BTreeSet::new(); group.len()];
+
+            for a in 1..group.len() {
+                connect(&mut neighbours, a, rng.index(a));
+            }
+            for _ in 0..group.len() {
+                connect(
+                    &mut neighbours,
+                    rng.index(group.len()),
+                    rng.index(group.len()),
+                );
+            }
+            for a in 0..group.len() {
+                while neighbours[a].len() < 4 {
+                    connect(&mut neighbours, a, rng.index(group.len()));
+                }
+            }
+
+            for (a, others) in neighbours.iter().enumerate() {
+                for &b in others.iter().filter(|b| **b > a) {
+                    edges.push((group[a].clone(), group[b].clone()));
+                }
+            }
+            groups.push(group);
+        }
+
+        let mut cut = Vec::new();
+        while cut.len() < 3 {
+            let wire = (rng.pick(&groups[0]).clone(), rng.pick(&groups[1]).clone());
+            if !cut.contains(&wire) {
+                cut.push(wire);
+            }
+        }
+        edges.extend(cut);
+
+        // Every wire is listed once, on the line of either of its components
+        let mut lines = BTreeMap::<String, Vec<String>>::new();
+        for (a, b) in edges {
+            let (a, b) = if rng.chance(0.5) { (a, b) } else { (b, a) };
+            lines.entry(a).or_default().push(b);
+        }
+
+        let mut lines = lines
+            .into_iter()
+            .map(|(a, others)| format!("{a}: {}\n", others.join(" ")))
+            .collect::<Vec<_>>();
+        rng.shuffle(&mut lines);
+
+        Some(lines.concat())
+    }
+}
+
+fn parse_edges(input: &str) -> Result<Graph<usize>, ParseError> {
+    let mut wires = Graph::undirected();
+
+    for line in input.lines() {
+        let Some((lpart, rpart)) = line.split_once(": ") else {
+            return Err(ParseError::new(
+                line,
+                "Expected '<component>: <components>'",
+            ));
+        };
+        let right_parts = rpart.split_ascii_whitespace();
+
+        let lnum = parse_component(lpart)?;
+
+        for rp in right_parts {
+            let rnum = parse_component(rp)?;
+
+            wires.add_edge(lnum, rnum, ());
+        }
+    }
+
+    Ok(wires)
+}
+
+fn parse_component(text: &str) -> Result<usize, ParseError> {
+    usize::from_str_radix(text, 36).map_err(|_| ParseError::new(text, "Expected a component name"))
+}
+
+fn connect(neighbours: &mut [BTreeSet<usize>], a: usize, b: usize) {
+    if a != b {
+        neighbours[a].insert(b);
+        neighbours[b].insert(a);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    aoc_common::example_tests! {
+        Day25,
+        part1_example: part1(include_str!("example")) == 54,
+    }
+}
@@ -0,0 +1,364 @@
+use std::collections::{hash_map::Entry, HashMap, HashSet};
+
+use aoc_common::{Answer, ParseError, Rng, Size, Solution};
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    /// The lines of the engine schematic, which must all have the same length.
+    type Parsed = Vec<String>;
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed, ParseError> {
+        let width = input.lines().next().map(str::len).unwrap_or(0);
+
+        input
+            .lines()
+            .map(|line| {
+                if let Some((idx, c)) = line.char_indices().find(|(_, c)| !c.is_ascii()) {
+                    Err(ParseError::new(
+                        &line[idx..idx + c.len_utf8()],
+                        "Unexpected character",
+                    ))
+                } else if line.len() != width {
+                    Err(ParseError::new(
+                        line,
+                        "Row length differs from the first row",
+                    ))
+                } else {
+                    Ok(line.to_string())
+                }
+            })
+            .collect()
+    }
+
+    fn part1(&self, schematic: &Self::Parsed) -> Answer {
+        let mut sum: u64 = 0;
+
+        let mut prev_prev_line = None;
+        let mut prev_line = None;
+
+        for (idx, line) in schematic.iter().map(String::as_str).enumerate() {
+            if idx == 0 {
+                prev_line = Some(line);
+            } else {
+                let valid_numbers_in_line =
+                    get_part_numbers_from_line(prev_prev_line, prev_line.unwrap(), Some(line));
+                sum += valid_numbers_in_line.into_iter().sum::<u64>();
+
+                prev_prev_line = prev_line.take();
+                prev_line = Some(line);
+            }
+        }
+
+        let valid_numbers_in_last_line =
+            get_part_numbers_from_line(prev_prev_line, prev_line.unwrap(), None);
+        sum += valid_numbers_in_last_line.into_iter().sum::<u64>();
+
+        sum.into()
+    }
+
+    fn part2(&self, schematic: &Self::Parsed) -> Answer {
+        let mut gear_numbers = Vec::new();
+
+        let mut prev_prev_line = None;
+        let mut prev_line = None;
+
+        let mut line_idx = 0;
+
+        for (idx, line) in schematic.iter().map(String::as_str).enumerate() {
+            if idx == 0 {
+                prev_line = Some(line);
+            } else {
+                line_idx = idx - 1;
+
+                gear_numbers.append(&mut get_gear_numbers_from_line(
+                    line_idx,
+                    prev_prev_line,
+                    prev_line.unwrap(),
+                    Some(line),
+                ));
+
+                prev_prev_line = prev_line.take();
+                prev_line = Some(line);
+            }
+        }
+
+        gear_numbers.append(&mut get_gear_numbers_from_line(
+            line_idx + 1,
+            prev_prev_line,
+            prev_line.unwrap(),
+            None,
+        ));
+
+        let mut gear_map = HashMap::new();
+
+        for gear_number in gear_numbers {
+            for gear in gear_number.gears {
+                match gear_map.entry(gear) {
+                    Entry::Vacant(e) => {
+                        let mut set = HashSet::new();
+                        set.insert(gear_number.num);
+
+                        e.insert(set);
+                    }
+                    Entry::Occupied(mut e) => {
+                        e.get_mut().insert(gear_number.num);
+                    }
+                }
+            }
+        }
+
+        let mut sum = 0;
+
+        for number_set in gear_map.into_values() {
+            if number_set.len() == 2 {
+                sum += number_set.into_iter().map(|n| n.num).product::<u64>();
+            }
+        }
+
+        sum.into()
+    }
+
+    fn generate(&self, rng: &mut Rng, size: Size) -> Option<String> {
+        let side = size.pick(10, 40, 140);
+        let mut input = String::new();
+
+        for _ in 0..side {
+            let mut line = String::new();
+
+            while line.len() < side {
+                let num_len = rng.int(1..=3) as usize;
+
+                if rng.chance(0.12) && line.len() + num_len < side {
+                    // Followed by a '.' so numbers don't run into each other
+                    line += &rng
+                        .int(10i64.pow(num_len as u32 - 1)..=10i64.pow(num_len as u32) - 1)
+                        .to_string();
+                    line.push('.');
+                } else if rng.chance(0.06) {
+                    line.push(*rng.pick(&['*', '#', '+', '$', '/', '=', '%', '@', '&', '-']));
+                } else {
+                    line.push('.');
+                }
+            }
+
+            input += &line;
+            input.push('\n');
+        }
+
+        Some(input)
+    }
+}
+
+fn get_part_numbers_from_line(
+    prev_line: Option<&str>,
+    line: &str,
+    next_line: Option<&str>,
+) -> Vec<u64> {
+    let mut numbers = Vec::new();
+
+    let mut num_relevant = false;
+    let mut num_buf = Vec::new();
+
+    for (i, c) in line.chars().enumerate() {
+        match c {
+            c if c.is_ascii_digit() => {
+                if !num_relevant {
+                    if num_buf.is_empty() && i > 0 {
+                        // Check above/below char before number
+                        num_relevant = check_if_pos_relevant(i - 1, prev_line, next_line);
+                        // Check immediately before number
+                        num_relevant = num_relevant || is_symbol(line.as_bytes()[i - 1]);
+                    }
+
+                    // Check above/below char in number
+                    num_relevant = num_relevant || check_if_pos_relevant(i, prev_line, next_line);
+                }
+
+                num_buf.push(c as u8);
+            }
+            _ => {
+                if !num_buf.is_empty() {
+                    if !num_relevant && i < line.len() {
+                        // Check above/below char after number
+                        num_relevant = check_if_pos_relevant(i, prev_line, next_line);
+                        // Check immediately after number
+                        num_relevant = num_relevant || is_symbol(line.as_bytes()[i]);
+                    }
+
+                    if num_relevant {
+                        let num = String::from_utf8(std::mem::take(&mut num_buf))
+                            .unwrap()
+                            .parse::<u64>()
+                            .unwrap();
+
+                        numbers.push(num);
+                    } else {
+                        num_buf.clear();
+                    }
+
+                    num_relevant = false;
+                }
+            }
+        }
+    }
+
+    if !num_buf.is_empty() && num_relevant {
+        let num = String::from_utf8(num_buf).unwrap().parse::<u64>().unwrap();
+        numbers.push(num);
+    }
+
+    numbers
+}
+
+fn check_if_pos_relevant(pos: usize, prev_line: Option<&str>, next_line: Option<&str>) -> bool {
+    match prev_line.map(|prev_line| prev_line.as_bytes()[pos]) {
+        Some(c) if is_symbol(c) => {
+            return true;
+        }
+        _ => {}
+    }
+
+    match next_line.map(|next_line| next_line.as_bytes()[pos]) {
+        Some(c) if is_symbol(c) => {
+            return true;
+        }
+        _ => {}
+    }
+
+    false
+}
+
+fn is_symbol(c: u8) -> bool {
+    !c.is_ascii_digit() && c != b'.'
+}
+
+#[derive(Debug)]
+struct GearNumber {
+    num: NumberPos,
+    gears: Vec<GearPos>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+struct NumberPos {
+    num: u64,
+    x: u64,
+    y: u64,
+}
+
+#[derive(Debug, PartialEq, Eq, Hash)]
+struct GearPos {
+    x: u64,
+    y: u64,
+}
+
+fn get_gear_numbers_from_line(
+    line_idx: usize,
+    prev_line: Option<&str>,
+    line: &str,
+    next_line: Option<&str>,
+) -> Vec<GearNumber> {
+    let mut numbers = Vec::new();
+
+    let mut num_buf = Vec::new();
+    let mut gears = Vec::new();
+
+    for (i, c) in line.chars().enumerate() {
+        match c {
+            c if c.is_ascii_digit() => {
+                if num_buf.is_empty() && i > 0 {
+                    // Check above/below char before number
+                    if line_idx > 0 {
+                        add_gear_from_pos_if_present(&mut gears, line_idx - 1, i - 1, prev_line);
+                    }
+                    add_gear_from_pos_if_present(&mut gears, line_idx + 1, i - 1, next_line);
+                    // Check immediately before number
+                    add_gear_from_pos_if_present(&mut gears, line_idx, i - 1, Some(line));
+                }
+
+                // Check above/below char in number
+                if line_idx > 0 {
+                    add_gear_from_pos_if_present(&mut gears, line_idx - 1, i, prev_line);
+                }
+                add_gear_from_pos_if_present(&mut gears, line_idx + 1, i, next_line);
+
+                num_buf.push(c as u8);
+            }
+            _ => {
+                if !num_buf.is_empty() {
+                    if i < line.len() {
+                        // Check above/below char after number
+                        if line_idx > 0 {
+                            add_gear_from_pos_if_present(&mut gears, line_idx - 1, i, prev_line);
+                        }
+                        add_gear_from_pos_if_present(&mut gears, line_idx + 1, i, next_line);
+                        // Check immediately after number
+                        add_gear_from_pos_if_present(&mut gears, line_idx, i, Some(line));
+                    }
+
+                    if !gears.is_empty() {
+                        let num = String::from_utf8(std::mem::take(&mut num_buf))
+                            .unwrap()
+                            .parse::<u64>()
+                            .unwrap();
+
+                        numbers.push(GearNumber {
+                            num: NumberPos {
+                                num,
+                                x: i as u64,
+                                y: line_idx as u64,
+                            },
+                            gears: std::mem::take(&mut gears),
+                        });
+                    } else {
+                        num_buf.clear();
+                    }
+                }
+            }
+        }
+    }
+
+    if !num_buf.is_empty() && !gears.is_empty() {
+        let num = String::from_utf8(num_buf).unwrap().parse::<u64>().unwrap();
+        numbers.push(GearNumber {
+            num: NumberPos {
+                num,
+                x: (line.len() - 1) as u64,
+                y: line_idx as u64,
+            },
+            gears,
+        });
+    }
+
+    numbers
+}
+
+fn add_gear_from_pos_if_present(
+    gears: &mut Vec<GearPos>,
+    line_idx: usize,
+    pos: usize,
+    line: Option<&str>,
+) {
+    match line.map(|line| line.as_bytes()[pos]) {
+        Some(c) if is_gear(c) => gears.push(GearPos {
+            x: pos as u64,
+            y: line_idx as u64,
+        }),
+        _ => {}
+    }
+}
+
+fn is_gear(c: u8) -> bool {
+    c == b'*'
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    aoc_common::example_tests! {
+        Day3,
+        part1_example: part1(include_str!("example")) == 4361,
+        part2_example: part2(include_str!("example")) == 467835,
+    }
+}
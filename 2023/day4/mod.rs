@@ -0,0 +1,172 @@
+use std::collections::{HashSet, VecDeque};
+
+use aoc_common::{parse_num, Answer, ParseError, Rng, Size, Solution};
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    type Parsed = Vec<Card>;
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed, ParseError> {
+        input.lines().map(parse_card_from_line).collect()
+    }
+
+    fn part1(&self, cards: &Self::Parsed) -> Answer {
+        let mut sum = 0;
+
+        for card in cards {
+            sum += card.calc_win_points();
+        }
+
+        sum.into()
+    }
+
+    fn part2(&self, cards: &Self::Parsed) -> Answer {
+        let mut card_count = 0;
+
+        let mut copy_deque = VecDeque::new();
+
+        for card in cards {
+            let current_card_copies = copy_deque.pop_front().unwrap_or(0);
+            // Copies + the card itself.
+            let current_card_count = current_card_copies + 1;
+
+            card_count += current_card_count;
+
+            let wins = card.number_of_wins();
+
+            for i in 0..wins {
+                match copy_deque.get_mut(i) {
+                    Some(e) => {
+                        *e += current_card_count;
+                    }
+                    None => {
+                        copy_deque.push_back(current_card_count);
+                    }
+                }
+            }
+        }
+
+        card_count.into()
+    }
+
+    fn generate(&self, rng: &mut Rng, size: Size) -> Option<String> {
+        let (cards, winning_len, your_len) = size.pick((10, 5, 8), (50, 8, 16), (200, 10, 25));
+
+        let mut input = String::new();
+        // Copies won so far for the cards ahead, to keep part 2 from overflowing
+        let mut copies = vec![0u64; cards];
+        let mut total = 0;
+
+        for num in 0..cards {
+            let count = copies[num] + 1;
+            total += count;
+
+            let mut wins = if rng.chance(0.5) {
+                0
+            } else {
+                rng.int(1..=winning_len as i64) as usize
+            };
+            if total > 1_000_000 {
+                wins = 0;
+            }
+            for copy in copies.iter_mut().skip(num + 1).take(wins) {
+                *copy += count;
+            }
+
+            let mut nums = (1..=99).collect::<Vec<u64>>();
+            rng.shuffle(&mut nums);
+
+            let (winning, rest) = nums.split_at(winning_len);
+            let mut yours = [&winning[..wins], &rest[..your_len - wins]].concat();
+            rng.shuffle(&mut yours);
+
+            let format = |nums: &[u64]| {
+                nums.iter()
+                    .map(|n| format!("{n:>2}"))
+                    .collect::<Vec<_>>()
+                    .join(" ")
+            };
+
+            input += &format!(
+                "Card {:>3}: {} | {}\n",
+                num + 1,
+                format(winning),
+                format(&yours)
+            );
+        }
+
+        Some(input)
+    }
+}
+
+#[derive(Debug)]
+pub struct Card {
+    _num: u64,
+    winning_nums: HashSet<u64>,
+    your_nums: HashSet<u64>,
+}
+
+impl Card {
+    pub fn number_of_wins(&self) -> usize {
+        self.winning_nums.intersection(&self.your_nums).count()
+    }
+
+    pub fn calc_win_points(&self) -> u64 {
+        let number_of_wins = self.number_of_wins();
+
+        if number_of_wins == 0 {
+            0
+        } else {
+            1 << (number_of_wins - 1)
+        }
+    }
+}
+
+fn parse_card_from_line(line: &str) -> Result<Card, ParseError> {
+    let Some((card_part, nums_part)) = line.split_once(": ") else {
+        return Err(ParseError::new(line, "Expected 'Card <num>: <numbers>'"));
+    };
+    let Some((_, num_str)) = card_part.split_once(' ') else {
+        return Err(ParseError::new(card_part, "Expected 'Card <num>'"));
+    };
+    let num = parse_num(num_str.trim())?;
+
+    let Some((lnums, rnums)) = nums_part.split_once(" | ") else {
+        return Err(ParseError::new(
+            nums_part,
+            "Expected '<numbers> | <numbers>'",
+        ));
+    };
+
+    Ok(Card {
+        _num: num,
+        winning_nums: parse_set_from_line(lnums)?,
+        your_nums: parse_set_from_line(rnums)?,
+    })
+}
+
+fn parse_set_from_line(line: &str) -> Result<HashSet<u64>, ParseError> {
+    let num_strs = line.split(' ');
+
+    let mut set = HashSet::new();
+
+    for num_str in num_strs {
+        if !num_str.is_empty() {
+            let num = parse_num(num_str)?;
+            set.insert(num);
+        }
+    }
+
+    Ok(set)
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    aoc_common::example_tests! {
+        Day4,
+        part1_example: part1(include_str!("example")) == 13,
+        part2_example: part2(include_str!("example")) == 30,
+    }
+}
@@ -0,0 +1,339 @@
+use std::{collections::HashMap, ops::Range, str::Lines};
+
+use aoc_common::{interval::IntervalSet, parse_num, Answer, ParseError, Rng, Size, Solution};
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    type Parsed = (Vec<u64>, CategoryMap);
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed, ParseError> {
+        let mut lines_iter = input.lines();
+
+        let seeds = parse_seeds(&mut lines_iter)?;
+
+        let mut category_map = CategoryMap::new();
+
+        for _ in 0..7 {
+            let (src, dst, ranges) = parse_category_map(&mut lines_iter)?;
+            category_map.insert((src, dst), MapRanges::new(ranges));
+        }
+
+        Ok((seeds, category_map))
+    }
+
+    fn part1(&self, (seeds, category_map): &Self::Parsed) -> Answer {
+        let nearest_location = seeds
+            .iter()
+            .map(|seed| map_seed_to_location(*seed, category_map))
+            .min()
+            .expect("No seeds");
+
+        nearest_location.into()
+    }
+
+    fn part2(&self, (seeds, category_map): &Self::Parsed) -> Answer {
+        let seed_ranges = seeds
+            .chunks_exact(2)
+            .map(|pair| pair[0]..(pair[0] + pair[1]))
+            .collect();
+
+        map_seed_ranges_to_location(seed_ranges, category_map).into()
+    }
+
+    fn generate(&self, rng: &mut Rng, size: Size) -> Option<String> {
+        let (limit, seed_pairs, ranges) =
+            size.pick((1_000, 2, 3), (1_000_000, 5, 10), (4_000_000_000, 10, 40));
+
+        let mut seeds = Vec::new();
+        for _ in 0..seed_pairs {
+            let start = rng.int(0..=limit);
+            seeds.push(start.to_string());
+            seeds.push(rng.int(1..=limit / 10).to_string());
+        }
+
+        let mut input = format!("seeds: {}\n", seeds.join(" "));
+
+        for (src, dst) in MAPPING {
+            input += &format!("\n{}-to-{} map:\n", src.name(), dst.name());
+
+            // Source ranges never overlap, so cut them from sorted points
+            let mut points = (0..ranges * 2)
+                .map(|_| rng.int(0..=limit))
+                .collect::<Vec<_>>();
+            points.sort_unstable();
+
+            let mut lines = points
+                .chunks_exact(2)
+                .filter(|pair| pair[1] > pair[0])
+                .map(|pair| {
+                    let len = pair[1] - pair[0];
+                    format!("{} {} {len}", rng.int(0..=limit - len), pair[0])
+                })
+                .collect::<Vec<_>>();
+            rng.shuffle(&mut lines);
+
+            for line in lines {
+                input += &line;
+                input.push('\n');
+            }
+        }
+
+        Some(input)
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Hash)]
+pub enum Category {
+    Seed,
+    Soil,
+    Fertilizer,
+    Water,
+    Light,
+    Temperature,
+    Humidity,
+    Location,
+}
+
+impl TryFrom<&str> for Category {
+    type Error = &'static str;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value {
+            "seed" => Ok(Category::Seed),
+            "soil" => Ok(Category::Soil),
+            "fertilizer" => Ok(Category::Fertilizer),
+            "water" => Ok(Category::Water),
+            "light" => Ok(Category::Light),
+            "temperature" => Ok(Category::Temperature),
+            "humidity" => Ok(Category::Humidity),
+            "location" => Ok(Category::Location),
+            _ => Err("Unknown category"),
+        }
+    }
+}
+
+impl Category {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Category::Seed => "seed",
+            Category::Soil => "soil",
+            Category::Fertilizer => "fertilizer",
+            Category::Water => "water",
+            Category::Light => "light",
+            Category::Temperature => "temperature",
+            Category::Humidity => "humidity",
+            Category::Location => "location",
+        }
+    }
+}
+
+const MAPPING: [(Category, Category); 7] = [
+    (Category::Seed, Category::Soil),
+    (Category::Soil, Category::Fertilizer),
+    (Category::Fertilizer, Category::Water),
+    (Category::Water, Category::Light),
+    (Category::Light, Category::Temperature),
+    (Category::Temperature, Category::Humidity),
+    (Category::Humidity, Category::Location),
+];
+
+// (src, dst) -> ranges
+pub type CategoryMap = HashMap<(Category, Category), MapRanges>;
+
+#[derive(Debug)]
+pub struct MapRanges(Vec<MapRange>);
+
+impl MapRanges {
+    pub fn new(mut ranges: Vec<MapRange>) -> Self {
+        ranges.sort_by_key(|a| a.src_range.start);
+        Self(ranges)
+    }
+
+    pub fn map_num(&self, num: u64) -> u64 {
+        for range in self.0.iter() {
+            if range.src_range.contains(&num) {
+                return range.offset(num);
+            }
+        }
+
+        num
+    }
+
+    /// Maps every value in `ranges`, cutting them where the source ranges
+    /// start and end.
+    pub fn map_ranges(&self, ranges: &IntervalSet<u64>) -> IntervalSet<u64> {
+        let mut mapped = IntervalSet::new();
+        let mut unmapped = ranges.clone();
+
+        for mr in self.0.iter() {
+            let src = IntervalSet::from(mr.src_range.clone());
+
+            for range in ranges.intersection(&src).ranges() {
+                mapped.insert(mr.offset(range.start)..mr.offset(range.end));
+            }
+
+            unmapped = unmapped.difference(&src);
+        }
+
+        mapped.union(&unmapped)
+    }
+}
+
+#[derive(Debug)]
+pub struct MapRange {
+    src_range: Range<u64>,
+    dst_offset: i64,
+}
+
+impl MapRange {
+    pub fn new(src: u64, dst: u64, len: u64) -> Self {
+        Self {
+            src_range: src..(src + len),
+            dst_offset: dst as i64 - src as i64,
+        }
+    }
+
+    fn offset(&self, num: u64) -> u64 {
+        (num as i64 + self.dst_offset) as u64
+    }
+}
+
+/// Parses the seed numbers. Part 2 reads them as `<start> <len>` pairs, so an
+/// odd count is rejected here.
+fn parse_seeds(lines_iter: &mut Lines) -> Result<Vec<u64>, ParseError> {
+    let Some(seeds_str) = lines_iter.next() else {
+        return Err(ParseError::new("", "Expected a seeds line"));
+    };
+
+    let Some((_, seeds_part)) = seeds_str.split_once(": ") else {
+        return Err(ParseError::new(seeds_str, "Expected 'seeds: <numbers>'"));
+    };
+
+    let mut seeds = Vec::new();
+
+    for seed_str in seeds_part.split_ascii_whitespace() {
+        seeds.push(parse_num(seed_str)?);
+    }
+
+    if seeds.len() % 2 != 0 {
+        let last = seeds_part
+            .split_ascii_whitespace()
+            .last()
+            .unwrap_or_default();
+
+        return Err(ParseError::new(last, "Seed is missing its range length"));
+    }
+
+    // Consume empty line
+    if let Some(line) = lines_iter.next().filter(|line| !line.is_empty()) {
+        return Err(ParseError::new(line, "Expected an empty line"));
+    }
+
+    Ok(seeds)
+}
+
+fn parse_category_map(
+    lines_iter: &mut Lines,
+) -> Result<(Category, Category, Vec<MapRange>), ParseError> {
+    let Some(header_line) = lines_iter.next() else {
+        return Err(ParseError::new("", "Expected a map header"));
+    };
+    let Some((src, dst)) = header_line
+        .strip_suffix(" map:")
+        .and_then(|mapping| mapping.split_once("-to-"))
+    else {
+        return Err(ParseError::new(
+            header_line,
+            "Expected '<src>-to-<dst> map:'",
+        ));
+    };
+    let src_category = Category::try_from(src).map_err(|err| ParseError::new(src, err))?;
+    let dst_category = Category::try_from(dst).map_err(|err| ParseError::new(dst, err))?;
+
+    let mut ranges = Vec::new();
+
+    for line in lines_iter.by_ref() {
+        if line.is_empty() {
+            break;
+        }
+
+        let nums = line
+            .split(' ')
+            .map(parse_num)
+            .collect::<Result<Vec<u64>, _>>()?;
+
+        let [dst, src, len] = nums[..] else {
+            return Err(ParseError::new(line, "Expected '<dst> <src> <len>'"));
+        };
+
+        ranges.push(MapRange::new(src, dst, len))
+    }
+
+    Ok((src_category, dst_category, ranges))
+}
+
+fn map_seed_to_location(seed: u64, category_map: &CategoryMap) -> u64 {
+    let mut num = seed;
+
+    for m in MAPPING {
+        num = category_map
+            .get(&m)
+            .expect("Mapping should exist")
+            .map_num(num);
+    }
+
+    num
+}
+
+fn map_seed_ranges_to_location(seed_ranges: IntervalSet<u64>, category_map: &CategoryMap) -> u64 {
+    let mut ranges = seed_ranges;
+
+    for m in MAPPING {
+        ranges = category_map
+            .get(&m)
+            .expect("Mapping should exist")
+            .map_ranges(&ranges);
+    }
+
+    ranges.min().expect("No seeds")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::differential::assert_equivalent;
+
+    aoc_common::example_tests! {
+        Day5,
+        part1_example: part1(include_str!("example")) == 35,
+        part2_example: part2(include_str!("example")) == 46,
+    }
+
+    #[test]
+    fn mapping_ranges_matches_mapping_seeds() {
+        assert_equivalent(
+            100,
+            |rng| {
+                Day5.parse(&Day5.generate(rng, Size::Small).unwrap())
+                    .unwrap()
+            },
+            |_| Vec::new(),
+            |(seeds, category_map)| {
+                seeds
+                    .chunks_exact(2)
+                    .flat_map(|pair| pair[0]..(pair[0] + pair[1]))
+                    .map(|seed| map_seed_to_location(seed, category_map))
+                    .min()
+            },
+            |(seeds, category_map)| {
+                let seed_ranges = seeds
+                    .chunks_exact(2)
+                    .map(|pair| pair[0]..(pair[0] + pair[1]))
+                    .collect();
+
+                Some(map_seed_ranges_to_location(seed_ranges, category_map))
+            },
+        );
+    }
+}
@@ -0,0 +1,211 @@
+use std::str::Lines;
+
+use aoc_common::{number::Integer, parse_num, Answer, ParseError, Rng, Size, Solution};
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    type Parsed = Races;
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed, ParseError> {
+        let mut lines_iter = input.lines();
+
+        let races = parse_races(&mut lines_iter)?;
+        let kerned = Race::from_kerned(&races)?;
+
+        Ok((races, kerned))
+    }
+
+    fn part1(&self, (races, _): &Self::Parsed) -> Answer {
+        let num_ways_to_win: u64 = races.iter().map(|race| race.num_ways_to_win()).product();
+
+        num_ways_to_win.into()
+    }
+
+    fn part2(&self, (_, kerned): &Self::Parsed) -> Answer {
+        let num_ways_to_win = kerned.num_ways_to_win();
+
+        num_ways_to_win.into()
+    }
+
+    fn generate(&self, rng: &mut Rng, size: Size) -> Option<String> {
+        let (race_count, max_time) = size.pick((3, 30), (4, 60), (4, 99));
+
+        // Every record has to be beatable, also for the single race of part 2
+        let races = loop {
+            let races = (0..race_count)
+                .map(|_| {
+                    let time_ms = rng.int(7..=max_time) as u64;
+                    let hold = rng.int(1..=time_ms as i64 / 2 - 1) as u64;
+
+                    Race {
+                        time_ms,
+                        record_mm: hold * (time_ms - hold),
+                    }
+                })
+                .collect::<Vec<_>>();
+
+            let beatable = Race::from_kerned(&races).is_ok_and(|kerned| {
+                let half = kerned.time_ms / 2;
+                kerned.record_mm < half * (kerned.time_ms - half)
+            });
+
+            if beatable {
+                break races;
+            }
+        };
+
+        let mut time_line = "Time:    ".to_string();
+        let mut distance_line = "Distance:".to_string();
+
+        for race in &races {
+            let width = race.record_mm.to_string().len() + 2;
+
+            time_line += &format!("{:>width$}", race.time_ms);
+            distance_line += &format!("{:>width$}", race.record_mm);
+        }
+
+        Some(format!("{time_line}\n{distance_line}\n"))
+    }
+}
+
+/// The races, and the single race you get when ignoring the spaces between
+/// the numbers.
+pub type Races = (Vec<Race>, Race);
+
+pub struct Race {
+    time_ms: u64,
+    record_mm: u64,
+}
+
+impl Race {
+    /// The single race you get when ignoring the spaces between the numbers,
+    /// or an error if those numbers don't fit.
+    pub fn from_kerned(races: &[Race]) -> Result<Race, ParseError> {
+        let concat = |num: fn(&Race) -> u64| {
+            races
+                .iter()
+                .map(num)
+                .try_fold(0u64, |acc, num| {
+                    let shift = 10u64.checked_pow(num.to_string().len() as u32)?;
+
+                    Integer::checked_add(Integer::checked_mul(acc, shift)?, num)
+                })
+                .ok_or_else(|| ParseError::new("", "Numbers without spaces are too big"))
+        };
+
+        Ok(Race {
+            time_ms: concat(|race| race.time_ms)?,
+            record_mm: concat(|race| race.record_mm)?,
+        })
+    }
+
+    /// Holding the button for `hold` ms goes `hold * (time - hold)` mm, so the
+    /// winning holds lie strictly between the roots of
+    /// `hold² - time * hold + record`, symmetric around `time / 2`.
+    ///
+    /// The distances go up to `time² / 4`, so they are worked out in `u128`,
+    /// where they always fit.
+    pub fn num_ways_to_win(&self) -> u64 {
+        let time = self.time_ms;
+        let (wide_time, wide_record) = (u128::from(time), u128::from(self.record_mm));
+        let beats_record = |hold: u64| u128::from(hold) * u128::from(time - hold) > wide_record;
+
+        let Some(discriminant) = Integer::checked_sub(wide_time * wide_time, 4 * wide_record)
+        else {
+            return 0;
+        };
+
+        // Rounding the root down puts this at most one below the first
+        // winning hold. The root is at most `time`, so it fits back in `u64`.
+        let mut first = (time - Integer::isqrt(discriminant) as u64) / 2;
+        while first <= time / 2 && !beats_record(first) {
+            first += 1;
+        }
+
+        if first > time / 2 {
+            0
+        } else {
+            time - 2 * first + 1
+        }
+    }
+}
+
+fn parse_races(lines_iter: &mut Lines) -> Result<Vec<Race>, ParseError> {
+    let Some(time_line) = lines_iter.next() else {
+        return Err(ParseError::new("", "Expected a time line"));
+    };
+    let time_strs = time_line.split_ascii_whitespace();
+    let Some(record_distance_line) = lines_iter.next() else {
+        return Err(ParseError::new("", "Expected a distance line"));
+    };
+    let mut record_distance_strs = record_distance_line.split_ascii_whitespace();
+
+    record_distance_strs.next(); // Skip 1
+
+    time_strs
+        .into_iter()
+        .skip(1)
+        .map(|time_str| {
+            let Some(record_distance_str) = record_distance_strs.next() else {
+                return Err(ParseError::new(
+                    record_distance_line,
+                    "Got different length for distance",
+                ));
+            };
+
+            let time_ms = parse_num(time_str)?;
+            let record_mm = parse_num(record_distance_str)?;
+
+            Ok(Race { time_ms, record_mm })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    aoc_common::example_tests! {
+        Day6,
+        part1_example: part1(include_str!("example")) == 288,
+        part2_example: part2(include_str!("example")) == 71503,
+    }
+
+    #[test]
+    fn ways_to_win_match_counting() {
+        for time_ms in 0..60 {
+            for record_mm in 0..=time_ms * time_ms / 4 + 1 {
+                let counted = (0..=time_ms)
+                    .filter(|hold| hold * (time_ms - hold) > record_mm)
+                    .count() as u64;
+                let race = Race { time_ms, record_mm };
+
+                assert_eq!(
+                    race.num_ways_to_win(),
+                    counted,
+                    "{time_ms} ms, {record_mm} mm"
+                );
+            }
+        }
+
+        let unbeatable = Race {
+            time_ms: 1000,
+            record_mm: u64::MAX,
+        };
+        assert_eq!(unbeatable.num_ways_to_win(), 0);
+
+        let longest = Race {
+            time_ms: u64::MAX,
+            record_mm: 0,
+        };
+        assert_eq!(longest.num_ways_to_win(), u64::MAX - 1);
+    }
+
+    #[test]
+    fn rejects_kerned_numbers_that_dont_fit() {
+        let input = "Time:      9999999999 9999999999\nDistance:  1 1\n";
+
+        assert!(Day6.parse(input).is_err());
+    }
+}
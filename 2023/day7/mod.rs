@@ -1,48 +1,58 @@
-use std::{
-    env,
-    fs::File,
-    io::{BufRead, BufReader},
-};
-
-fn main() {
-    let Some(filepath) = env::args().nth(1) else {
-        println!("Usage: part2 <filepath>");
-        return;
-    };
-
-    let file = match File::open(filepath) {
-        Ok(file) => file,
-        Err(err) => {
-            println!("Error opening input file: {err}");
-            return;
+use aoc_common::{parse_num, Answer, ParseError, Rng, Size, Solution};
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    type Parsed = Vec<InputHand>;
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed, ParseError> {
+        let mut hands = Vec::new();
+
+        for line in input.lines() {
+            let Some((hand_part, bid_part)) = line.split_once(' ') else {
+                return Err(ParseError::new(line, "Expected '<hand> <bid>'"));
+            };
+
+            let cards = hand_part
+                .char_indices()
+                .map(|(idx, c)| {
+                    CardType::try_from(c).map_err(|_| {
+                        ParseError::new(&hand_part[idx..idx + c.len_utf8()], "Invalid card type")
+                    })
+                })
+                .collect::<Result<_, _>>()?;
+            let bid = parse_num(bid_part)?;
+
+            hands.push(InputHand { cards, bid })
         }
-    };
 
-    let reader = BufReader::new(file);
+        Ok(hands)
+    }
 
-    let input_hands = parse_input(reader);
+    fn part1(&self, input_hands: &Self::Parsed) -> Answer {
+        total_winnings(input_hands.iter().cloned()).into()
+    }
 
-    let mut hands_with_strength: Vec<_> = input_hands
-        .into_iter()
-        .map(HandWithStrength::from)
-        .collect();
+    fn part2(&self, input_hands: &Self::Parsed) -> Answer {
+        total_winnings(input_hands.iter().map(InputHand::with_jokers)).into()
+    }
 
-    hands_with_strength.sort();
+    fn generate(&self, rng: &mut Rng, size: Size) -> Option<String> {
+        let cards = b"23456789TJQKA";
+        let mut input = String::new();
 
-    let sum: u64 = hands_with_strength
-        .into_iter()
-        .enumerate()
-        .map(|(idx, hand)| {
-            let rank = (idx + 1) as u64;
-            hand.bid * rank
-        })
-        .sum();
+        for _ in 0..size.pick(10, 100, 1000) {
+            let hand = (0..5).map(|_| *rng.pick(cards) as char).collect::<String>();
+
+            input += &format!("{hand} {}\n", rng.int(1..=1000));
+        }
 
-    println!("Sum: {sum}");
+        Some(input)
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
-enum CardType {
+pub enum CardType {
     Joker = 0,
 
     Two = 1,
@@ -54,10 +64,10 @@ enum CardType {
     Eight = 7,
     Nine = 8,
     Ten = 9,
-
-    Queen = 10,
-    King = 11,
-    Ace = 12,
+    Jack = 10,
+    Queen = 11,
+    King = 12,
+    Ace = 13,
 }
 
 impl TryFrom<char> for CardType {
@@ -74,7 +84,7 @@ impl TryFrom<char> for CardType {
             '8' => Ok(CardType::Eight),
             '9' => Ok(CardType::Nine),
             'T' => Ok(CardType::Ten),
-            'J' => Ok(CardType::Joker),
+            'J' => Ok(CardType::Jack),
             'Q' => Ok(CardType::Queen),
             'K' => Ok(CardType::King),
             'A' => Ok(CardType::Ace),
@@ -83,13 +93,14 @@ impl TryFrom<char> for CardType {
     }
 }
 
-struct InputHand {
+#[derive(Clone)]
+pub struct InputHand {
     cards: Vec<CardType>,
     bid: u64,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
-enum HandStrength {
+pub enum HandStrength {
     FiveOfKind = 6,
     FourOfKind = 5,
     FullHouse = 4,
@@ -100,8 +111,25 @@ enum HandStrength {
 }
 
 impl InputHand {
+    /// The same hand with every Jack played as a Joker.
+    pub fn with_jokers(&self) -> InputHand {
+        let cards = self
+            .cards
+            .iter()
+            .map(|card| match card {
+                CardType::Jack => CardType::Joker,
+                other => *other,
+            })
+            .collect();
+
+        InputHand {
+            cards,
+            bid: self.bid,
+        }
+    }
+
     pub fn strength(&self) -> HandStrength {
-        let mut type_counts: [u8; 13] = [0; 13];
+        let mut type_counts: [u8; 14] = [0; 14];
         self.cards
             .iter()
             .for_each(|c_type| type_counts[*c_type as usize] += 1);
@@ -197,22 +225,28 @@ impl From<InputHand> for HandWithStrength {
     }
 }
 
-fn parse_input(reader: BufReader<File>) -> Vec<InputHand> {
-    let mut hands = Vec::new();
+fn total_winnings(input_hands: impl Iterator<Item = InputHand>) -> u64 {
+    let mut hands_with_strength: Vec<_> = input_hands.map(HandWithStrength::from).collect();
 
-    for line in reader.lines() {
-        let line = line.expect("Error reading line");
+    hands_with_strength.sort();
 
-        let (hand_part, bid_part) = line.split_once(' ').expect("Line split failed");
+    hands_with_strength
+        .into_iter()
+        .enumerate()
+        .map(|(idx, hand)| {
+            let rank = (idx + 1) as u64;
+            hand.bid * rank
+        })
+        .sum()
+}
 
-        let cards = hand_part
-            .chars()
-            .map(|c| CardType::try_from(c).expect("Invalid card type"))
-            .collect();
-        let bid = bid_part.parse().expect("Bid parse failed");
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-        hands.push(InputHand { cards, bid })
+    aoc_common::example_tests! {
+        Day7,
+        part1_example: part1(include_str!("example")) == 6440,
+        part2_example: part2(include_str!("example")) == 5905,
     }
-
-    hands
 }
@@ -0,0 +1,261 @@
+use std::{
+    collections::{HashMap, HashSet},
+    str::Lines,
+};
+
+use aoc_common::{number::lcm, Answer, ParseError, Rng, Size, Solution};
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    type Parsed = (Vec<Direction>, JumpMap);
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed, ParseError> {
+        let mut lines = input.lines();
+
+        let directions = parse_directions(&mut lines)?;
+        // Consume empty line
+        if let Some(line) = lines.next().filter(|line| !line.is_empty()) {
+            return Err(ParseError::new(line, "Expected an empty line"));
+        }
+
+        let jump_map = parse_map(&mut lines)?;
+
+        Ok((directions, jump_map))
+    }
+
+    fn part1(&self, (directions, jump_map): &Self::Parsed) -> Answer {
+        let start = JumpPosition::try_from("AAA").unwrap();
+        let end = JumpPosition::try_from("ZZZ").unwrap();
+
+        let steps = jump_map.count_steps_until(start, directions, |pos| pos == end);
+
+        steps.into()
+    }
+
+    fn part2(&self, (directions, jump_map): &Self::Parsed) -> Answer {
+        let starts = jump_map.get_starts();
+
+        let step_count_per_start: Vec<u64> = starts
+            .into_iter()
+            .map(|start| {
+                jump_map.count_steps_until(start, directions, |pos| {
+                    matches!(pos.pos_type, JumpPositionType::End)
+                })
+            })
+            .collect();
+
+        let steps = step_count_per_start
+            .into_iter()
+            .reduce(lcm)
+            .expect("Got no starts");
+
+        steps.into()
+    }
+
+    fn generate(&self, rng: &mut Rng, size: Size) -> Option<String> {
+        let (ghosts, depths, direction_len) =
+            size.pick((2, 5..=15, 5), (4, 20..=60, 50), (6, 40..=100, 263));
+
+        let directions = (0..direction_len)
+            .map(|_| *rng.pick(&['L', 'R']))
+            .collect::<String>();
+
+        let mut names = HashSet::from(["AAA".to_string(), "ZZZ".to_string()]);
+        let mut name = |rng: &mut Rng, last: Option<char>| loop {
+            let letter = |rng: &mut Rng| (b'A' + rng.index(26) as u8) as char;
+            let last = last.unwrap_or_else(|| (b'B' + rng.index(24) as u8) as char);
+            let name = format!("{}{}{last}", letter(rng), letter(rng));
+
+            if names.insert(name.clone()) {
+                break name;
+            }
+        };
+
+        let mut lines = Vec::new();
+
+        // Every ghost walks its own loop of layers. Both directions lead to
+        // the next layer, so it ends up on its '..Z' node after the same number
+        // of steps each time around, which is what part 2 relies on.
+        for ghost in 0..ghosts {
+            let (start, end) = if ghost == 0 {
+                ("AAA".to_string(), "ZZZ".to_string())
+            } else {
+                (name(rng, Some('A')), name(rng, Some('Z')))
+            };
+
+            let mut layers = vec![vec![start]];
+            for _ in 1..rng.int(depths.clone()) {
+                let width = rng.int(1..=2);
+                layers.push((0..width).map(|_| name(rng, None)).collect());
+            }
+            layers.push(vec![end]);
+
+            for (depth, layer) in layers.iter().enumerate() {
+                let next = layers.get(depth + 1).unwrap_or(&layers[1]);
+
+                for node in layer {
+                    lines.push(format!("{node} = ({}, {})", rng.pick(next), rng.pick(next)));
+                }
+            }
+        }
+
+        rng.shuffle(&mut lines);
+
+        Some(format!("{directions}\n\n{}\n", lines.join("\n")))
+    }
+}
+
+pub enum Direction {
+    Left = 0,
+    Right = 1,
+}
+
+impl TryFrom<char> for Direction {
+    type Error = ();
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            'L' => Ok(Direction::Left),
+            'R' => Ok(Direction::Right),
+            _ => Err(()),
+        }
+    }
+}
+
+fn parse_directions(lines: &mut Lines) -> Result<Vec<Direction>, ParseError> {
+    let Some(line) = lines.next() else {
+        return Err(ParseError::new("", "Expected a directions line"));
+    };
+
+    line.char_indices()
+        .map(|(idx, c)| {
+            Direction::try_from(c).map_err(|_| {
+                ParseError::new(&line[idx..idx + c.len_utf8()], "Unexpected direction char")
+            })
+        })
+        .collect()
+}
+
+#[derive(Clone, Copy, Hash, PartialEq, Eq)]
+pub struct JumpPosition {
+    num: u64,
+    pos_type: JumpPositionType,
+}
+
+#[derive(Clone, Copy, Hash, PartialEq, Eq)]
+enum JumpPositionType {
+    Start,
+    End,
+    Middle,
+}
+
+impl TryFrom<&str> for JumpPosition {
+    type Error = ();
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        if value.len() != 3 {
+            return Err(());
+        }
+
+        let num_val = u64::from_str_radix(value, 36).map_err(|_| ())?;
+
+        let pos_type = match value.chars().nth(2).unwrap() {
+            'A' => JumpPositionType::Start,
+            'Z' => JumpPositionType::End,
+            _ => JumpPositionType::Middle,
+        };
+
+        Ok(Self {
+            num: num_val,
+            pos_type,
+        })
+    }
+}
+
+pub struct JumpMap {
+    map: HashMap<JumpPosition, (JumpPosition, JumpPosition)>,
+}
+
+impl JumpMap {
+    pub fn get_starts(&self) -> Vec<JumpPosition> {
+        self.map
+            .keys()
+            .filter(|jp| matches!(jp.pos_type, JumpPositionType::Start))
+            .copied()
+            .collect()
+    }
+
+    pub fn count_steps_until(
+        &self,
+        start: JumpPosition,
+        directions: &[Direction],
+        is_end: impl Fn(JumpPosition) -> bool,
+    ) -> u64 {
+        let mut steps = 0;
+
+        let mut current_pos = start;
+
+        let mut direction_iter = directions.iter().cycle();
+
+        while !is_end(current_pos) {
+            steps += 1;
+
+            let direction = direction_iter.next().unwrap();
+
+            let target = self.map.get(&current_pos).expect("Invalid jump pos");
+
+            match direction {
+                Direction::Left => {
+                    current_pos = target.0;
+                }
+                Direction::Right => {
+                    current_pos = target.1;
+                }
+            }
+        }
+
+        steps
+    }
+}
+
+fn parse_map(lines: &mut Lines) -> Result<JumpMap, ParseError> {
+    let mut map = HashMap::new();
+
+    for line in lines.by_ref() {
+        let Some((pos_part, (l_jump, r_jump))) =
+            line.split_once(" = ").and_then(|(pos_part, jump_part)| {
+                let jumps = jump_part
+                    .strip_prefix('(')?
+                    .strip_suffix(')')?
+                    .split_once(", ")?;
+
+                Some((pos_part, jumps))
+            })
+        else {
+            return Err(ParseError::new(line, "Expected 'AAA = (BBB, CCC)'"));
+        };
+
+        map.insert(
+            parse_jump_position(pos_part)?,
+            (parse_jump_position(l_jump)?, parse_jump_position(r_jump)?),
+        );
+    }
+
+    Ok(JumpMap { map })
+}
+
+fn parse_jump_position(text: &str) -> Result<JumpPosition, ParseError> {
+    JumpPosition::try_from(text).map_err(|_| ParseError::new(text, "Invalid jump position"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    aoc_common::example_tests! {
+        Day8,
+        part1_example: part1(include_str!("example")) == 6,
+        part2_example: part2(include_str!("example2")) == 6,
+    }
+}
@@ -0,0 +1,132 @@
+use aoc_common::{parse_num, Answer, ParseError, Rng, Size, Solution};
+
+pub struct Day9;
+
+impl Solution for Day9 {
+    type Parsed = Vec<InputSequence>;
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed, ParseError> {
+        parse_input_sequences(input)
+    }
+
+    fn part1(&self, input_sequences: &Self::Parsed) -> Answer {
+        let sum: i64 = input_sequences.iter().map(|is| is.find_next()).sum();
+
+        sum.into()
+    }
+
+    fn part2(&self, input_sequences: &Self::Parsed) -> Answer {
+        let sum: i64 = input_sequences.iter().map(|is| is.find_prev()).sum();
+
+        sum.into()
+    }
+
+    fn generate(&self, rng: &mut Rng, size: Size) -> Option<String> {
+        let (lines, len, max_degree) = size.pick((5, 8, 3), (50, 15, 5), (200, 21, 6));
+        let mut input = String::new();
+
+        for _ in 0..lines {
+            // Values of a polynomial, so the differences reach all zeroes
+            let coefficients = (0..=rng.int(0..=max_degree))
+                .map(|_| rng.int(-5..=5))
+                .collect::<Vec<_>>();
+            let offset = rng.int(-5..=5);
+
+            let values = (0..len)
+                .map(|x| {
+                    coefficients
+                        .iter()
+                        .fold(0, |acc, c| acc * (x + offset) + c)
+                        .to_string()
+                })
+                .collect::<Vec<_>>();
+
+            input += &values.join(" ");
+            input.push('\n');
+        }
+
+        Some(input)
+    }
+}
+
+pub struct InputSequence(Vec<i64>);
+
+impl InputSequence {
+    pub fn find_next(&self) -> i64 {
+        let last_next = self
+            .difference_sequences()
+            .into_iter()
+            .rev()
+            .fold(0, |acc, seq| seq.last().unwrap() + acc);
+
+        self.0.last().unwrap() + last_next
+    }
+
+    pub fn find_prev(&self) -> i64 {
+        let last_prev = self
+            .difference_sequences()
+            .into_iter()
+            .rev()
+            .fold(0, |acc, seq| seq.first().unwrap() - acc);
+
+        self.0.first().unwrap() - last_prev
+    }
+
+    fn difference_sequences(&self) -> Vec<Vec<i64>> {
+        let mut difference_sequences = Vec::new();
+
+        loop {
+            let seq = difference_sequences.last().unwrap_or(&self.0);
+
+            let diff: Vec<i64> = seq
+                .iter()
+                .enumerate()
+                .skip(1)
+                .map(|(idx, val)| {
+                    let prev = seq[idx - 1];
+
+                    let diff = (val - prev).abs();
+
+                    if *val < prev {
+                        -diff
+                    } else {
+                        diff
+                    }
+                })
+                .collect();
+
+            if diff.iter().all(|v| *v == 0) {
+                break;
+            }
+
+            difference_sequences.push(diff);
+        }
+
+        difference_sequences
+    }
+}
+
+fn parse_input_sequences(input: &str) -> Result<Vec<InputSequence>, ParseError> {
+    let mut sequences = Vec::new();
+
+    for line in input.lines() {
+        sequences.push(InputSequence(
+            line.split_ascii_whitespace()
+                .map(parse_num)
+                .collect::<Result<_, _>>()?,
+        ));
+    }
+
+    Ok(sequences)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    aoc_common::example_tests! {
+        Day9,
+        part1_example: part1(include_str!("example")) == 114,
+        part2_example: part2(include_str!("example")) == 2,
+    }
+}
@@ -0,0 +1,61 @@
+pub mod day1;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day13;
+pub mod day14;
+pub mod day15;
+pub mod day16;
+pub mod day17;
+pub mod day18;
+pub mod day19;
+pub mod day2;
+pub mod day20;
+pub mod day21;
+pub mod day22;
+pub mod day23;
+pub mod day24;
+pub mod day25;
+pub mod day3;
+pub mod day4;
+pub mod day5;
+pub mod day6;
+pub mod day7;
+pub mod day8;
+pub mod day9;
+
+use aoc_common::Solver;
+
+/// Returns the solution for the given day of 2023, if there is one.
+pub fn solution(day: u32) -> Option<&'static dyn Solver> {
+    let solution: &'static dyn Solver = match day {
+        1 => &day1::Day1,
+        2 => &day2::Day2,
+        3 => &day3::Day3,
+        4 => &day4::Day4,
+        5 => &day5::Day5,
+        6 => &day6::Day6,
+        7 => &day7::Day7,
+        8 => &day8::Day8,
+        9 => &day9::Day9,
+        10 => &day10::Day10,
+        11 => &day11::Day11,
+        12 => &day12::Day12,
+        13 => &day13::Day13,
+        14 => &day14::Day14,
+        15 => &day15::Day15,
+        16 => &day16::Day16,
+        17 => &day17::Day17,
+        18 => &day18::Day18,
+        19 => &day19::Day19,
+        20 => &day20::Day20,
+        21 => &day21::Day21,
+        22 => &day22::Day22,
+        23 => &day23::Day23,
+        24 => &day24::Day24,
+        25 => &day25::Day25,
+        _ => return None,
+    };
+
+    Some(solution)
+}
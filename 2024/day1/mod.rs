@@ -0,0 +1,92 @@
+use std::collections::HashMap;
+
+use aoc_common::{parse_num, Answer, ParseError, Rng, Size, Solution};
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    type Parsed = (Vec<isize>, Vec<isize>);
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed, ParseError> {
+        let mut l_nums = Vec::new();
+        let mut r_nums = Vec::new();
+
+        for line in input.lines() {
+            let Some((l_num, r_num)) = line.split_once("   ") else {
+                return Err(ParseError::new(line, "Expected two numbers"));
+            };
+
+            l_nums.push(parse_num(l_num)?);
+            r_nums.push(parse_num(r_num)?);
+        }
+
+        Ok((l_nums, r_nums))
+    }
+
+    fn part1(&self, (l_nums, r_nums): &Self::Parsed) -> Answer {
+        let mut l_nums = l_nums.clone();
+        let mut r_nums = r_nums.clone();
+
+        l_nums.sort();
+        r_nums.sort();
+
+        let sum: usize = l_nums
+            .into_iter()
+            .zip(r_nums)
+            .map(|(l, r)| l.abs_diff(r))
+            .sum();
+
+        sum.into()
+    }
+
+    fn part2(&self, (l_nums, r_nums): &Self::Parsed) -> Answer {
+        let mut r_map = HashMap::new();
+
+        for r_num in r_nums.iter().copied() {
+            r_map
+                .entry(r_num)
+                .and_modify(|num| *num += 1)
+                .or_insert(1_isize);
+        }
+
+        let sum: isize = l_nums
+            .iter()
+            .map(|l_num| l_num * r_map.get(l_num).unwrap_or(&0))
+            .sum();
+
+        sum.into()
+    }
+
+    fn generate(&self, rng: &mut Rng, size: Size) -> Option<String> {
+        let lines = size.pick(10, 100, 1000);
+        let left = (0..lines)
+            .map(|_| rng.int(10_000..=99_999))
+            .collect::<Vec<_>>();
+
+        let mut input = String::new();
+
+        for l_num in &left {
+            // Some numbers appear in both lists, for the similarity score
+            let r_num = if rng.chance(0.3) {
+                *rng.pick(&left)
+            } else {
+                rng.int(10_000..=99_999)
+            };
+
+            input += &format!("{l_num}   {r_num}\n");
+        }
+
+        Some(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    aoc_common::example_tests! {
+        Day1,
+        part1_example: part1(include_str!("example")) == 11,
+        part2_example: part2(include_str!("example")) == 31,
+    }
+}
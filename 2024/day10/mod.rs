@@ -0,0 +1,114 @@
+use std::collections::HashSet;
+
+use aoc_common::{Answer, Grid, ParseError, Rng, Size, Solution};
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Parsed = Grid<u32>;
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed, ParseError> {
+        Grid::parse(input, |c| c.to_digit(10))
+    }
+
+    fn part1(&self, map: &Self::Parsed) -> Answer {
+        let mut sum = 0;
+
+        for (pos, elem) in map.iter() {
+            if *elem == 0 {
+                sum += count_trailheads(pos, &mut HashSet::new(), map).len();
+            }
+        }
+
+        sum.into()
+    }
+
+    fn part2(&self, map: &Self::Parsed) -> Answer {
+        let mut sum = 0;
+
+        for (pos, elem) in map.iter() {
+            if *elem == 0 {
+                sum += count_trails(pos, map);
+            }
+        }
+
+        sum.into()
+    }
+
+    fn generate(&self, rng: &mut Rng, size: Size) -> Option<String> {
+        let side = size.pick(8, 25, 50);
+        let mut map = Grid::new(side, side, 0);
+
+        for pos in map.positions().collect::<Vec<_>>() {
+            map[pos] = rng.int(0..=9) as u32;
+        }
+
+        // Walk uphill trails over the random heights, so there are some to find
+        for _ in 0..size.pick(5, 30, 150) {
+            let mut pos = (rng.index(side), rng.index(side));
+            map[pos] = 0;
+
+            for height in 1..=9 {
+                let neighbours = map.neighbours4(pos).collect::<Vec<_>>();
+                pos = *rng.pick(&neighbours);
+                map[pos] = height;
+            }
+        }
+
+        Some(
+            map.rows()
+                .map(|row| {
+                    row.iter()
+                        .map(|height| height.to_string())
+                        .collect::<String>()
+                        + "\n"
+                })
+                .collect(),
+        )
+    }
+}
+
+fn count_trailheads<'a>(
+    pos: (usize, usize),
+    acc: &'a mut HashSet<(usize, usize)>,
+    map: &Grid<u32>,
+) -> &'a mut HashSet<(usize, usize)> {
+    let num_at_pos = map[pos];
+
+    if num_at_pos == 9 {
+        acc.insert(pos);
+        return acc;
+    }
+
+    for neighbour in map.neighbours4(pos) {
+        if map[neighbour] == num_at_pos + 1 {
+            count_trailheads(neighbour, acc, map);
+        }
+    }
+
+    acc
+}
+
+fn count_trails(pos: (usize, usize), map: &Grid<u32>) -> usize {
+    let num_at_pos = map[pos];
+
+    if num_at_pos == 9 {
+        return 1;
+    }
+
+    map.neighbours4(pos)
+        .filter(|neighbour| map[*neighbour] == num_at_pos + 1)
+        .map(|neighbour| count_trails(neighbour, map))
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    aoc_common::example_tests! {
+        Day10,
+        part1_example: part1(include_str!("example")) == 36,
+        part2_example: part2(include_str!("example")) == 81,
+    }
+}
@@ -0,0 +1,104 @@
+use std::collections::HashMap;
+
+use aoc_common::{parse_num, Answer, ParseError, Rng, Size, Solution};
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Parsed = Vec<isize>;
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed, ParseError> {
+        input.split_whitespace().map(parse_num).collect()
+    }
+
+    fn part1(&self, stones: &Self::Parsed) -> Answer {
+        count_stones(stones, 25).into()
+    }
+
+    fn part2(&self, stones: &Self::Parsed) -> Answer {
+        count_stones(stones, 75).into()
+    }
+
+    fn generate(&self, rng: &mut Rng, size: Size) -> Option<String> {
+        let stones = (0..size.pick(2, 5, 8))
+            .map(|_| rng.int(0..=9_999_999).to_string())
+            .collect::<Vec<_>>();
+
+        Some(stones.join(" ") + "\n")
+    }
+}
+
+fn count_stones(stones: &[isize], blinks: usize) -> usize {
+    let mut sum = 0;
+
+    let mut cache = HashMap::new();
+
+    for &stone in stones {
+        let val = count_blink(stone, 0, blinks, &mut cache);
+
+        cache.insert((stone, 0), val + 1);
+
+        sum += val + 1;
+    }
+
+    sum
+}
+
+/// How many stones are added by `stone` and its descendants until `blinks` is reached.
+fn count_blink(
+    stone: isize,
+    depth: usize,
+    blinks: usize,
+    cache: &mut HashMap<(isize, usize), usize>,
+) -> usize {
+    if depth == blinks {
+        return 0;
+    }
+
+    if let Some(cached_value) = cache.get(&(stone, depth)) {
+        return *cached_value;
+    }
+
+    if stone == 0 {
+        return count_blink(1, depth + 1, blinks, cache);
+    }
+
+    let str = stone.to_string();
+
+    if str.len().is_multiple_of(2) {
+        let n1 = str[0..(str.len() / 2)]
+            .parse::<isize>()
+            .expect("Failed to parse num");
+
+        let n2 = str[(str.len() / 2)..]
+            .parse::<isize>()
+            .expect("Failed to parse num");
+
+        let b1 = count_blink(n1, depth + 1, blinks, cache);
+        cache.insert((n1, depth + 1), b1);
+
+        let b2 = count_blink(n2, depth + 1, blinks, cache);
+        cache.insert((n2, depth + 1), b2);
+
+        cache.insert((stone, depth), b1 + b2 + 1);
+
+        return b1 + b2 + 1;
+    }
+
+    let val = count_blink(stone * 2024, depth + 1, blinks, cache);
+
+    cache.insert((stone * 2024, depth + 1), val);
+
+    val
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    aoc_common::example_tests! {
+        Day11,
+        part1_example: part1(include_str!("example")) == 55312,
+        part2_example: part2(include_str!("example")) == 65601038650482_i64,
+    }
+}
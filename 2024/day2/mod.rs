@@ -0,0 +1,115 @@
+use aoc_common::{parse_num, Answer, ParseError, Rng, Size, Solution};
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    type Parsed = Vec<Vec<isize>>;
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed, ParseError> {
+        let mut reports = Vec::new();
+
+        for line in input.lines() {
+            let report = line
+                .split(" ")
+                .map(parse_num)
+                .collect::<Result<Vec<_>, _>>()?;
+
+            reports.push(report);
+        }
+
+        Ok(reports)
+    }
+
+    fn part1(&self, reports: &Self::Parsed) -> Answer {
+        let safe_report_count = reports
+            .iter()
+            .filter(|report| is_report_safe(report))
+            .count();
+
+        safe_report_count.into()
+    }
+
+    fn part2(&self, reports: &Self::Parsed) -> Answer {
+        let mut safe_report_count = 0;
+
+        'outer: for report in reports {
+            if is_report_safe(report) {
+                safe_report_count += 1;
+                continue 'outer;
+            }
+
+            for idx in 0..report.len() {
+                let modified_report = report
+                    .iter()
+                    .enumerate()
+                    .filter_map(|(i, val)| if idx == i { None } else { Some(*val) })
+                    .collect::<Vec<_>>();
+
+                if is_report_safe(&modified_report) {
+                    safe_report_count += 1;
+                    continue 'outer;
+                }
+            }
+        }
+
+        safe_report_count.into()
+    }
+
+    fn generate(&self, rng: &mut Rng, size: Size) -> Option<String> {
+        let mut input = String::new();
+
+        for _ in 0..size.pick(10, 100, 1000) {
+            let direction = if rng.chance(0.5) { 1 } else { -1 };
+            let mut level = rng.int(1..=99);
+            let mut levels = vec![level];
+
+            // Mostly safe steps, with the odd one that isn't
+            for _ in 1..rng.int(5..=8) {
+                level += if rng.chance(0.9) {
+                    direction * rng.int(1..=3)
+                } else {
+                    rng.int(-5..=5)
+                };
+                levels.push(level.max(1));
+            }
+
+            input += &levels
+                .iter()
+                .map(|level| level.to_string())
+                .collect::<Vec<_>>()
+                .join(" ");
+            input.push('\n');
+        }
+
+        Some(input)
+    }
+}
+
+fn is_report_safe(report: &[isize]) -> bool {
+    let diffs = report.windows(2).map(|win| win[0] - win[1]);
+
+    let mut sign = None;
+    let mut safe = true;
+
+    for diff in diffs {
+        if diff == 0 || diff.abs() > 3 || (sign.is_some() && sign != Some(diff.signum())) {
+            safe = false;
+            break;
+        }
+
+        sign = Some(diff.signum());
+    }
+
+    safe
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    aoc_common::example_tests! {
+        Day2,
+        part1_example: part1(include_str!("example")) == 2,
+        part2_example: part2(include_str!("example")) == 4,
+    }
+}
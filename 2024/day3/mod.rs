@@ -0,0 +1,114 @@
+use regex::Regex;
+
+use aoc_common::{parse_num, Answer, ParseError, Rng, Size, Solution};
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    type Parsed = Vec<Instruction>;
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed, ParseError> {
+        let regex = Regex::new("(?:mul\\(([0-9]{1,3}),([0-9]{1,3})\\))|(?:do\\(\\)|don't\\(\\))")
+            .expect("Valid regex");
+
+        let mut instructions = Vec::new();
+
+        for captures in regex.captures_iter(input) {
+            let match_str = captures.get(0).expect("Capture expected").as_str();
+
+            let instruction = match match_str {
+                "do()" => Instruction::Do,
+                "don't()" => Instruction::Dont,
+                _ => {
+                    let num1 = parse_num(captures.get(1).expect("Capture expected").as_str())?;
+                    let num2 = parse_num(captures.get(2).expect("Capture expected").as_str())?;
+
+                    Instruction::Mul(num1, num2)
+                }
+            };
+
+            instructions.push(instruction);
+        }
+
+        Ok(instructions)
+    }
+
+    fn part1(&self, instructions: &Self::Parsed) -> Answer {
+        let res = instructions
+            .iter()
+            .map(|instruction| match instruction {
+                Instruction::Mul(num1, num2) => num1 * num2,
+                Instruction::Do | Instruction::Dont => 0,
+            })
+            .sum::<isize>();
+
+        res.into()
+    }
+
+    fn part2(&self, instructions: &Self::Parsed) -> Answer {
+        let mut enabled = true;
+        let mut sum = 0;
+
+        for instruction in instructions {
+            match instruction {
+                Instruction::Do => {
+                    enabled = true;
+                }
+                Instruction::Dont => {
+                    enabled = false;
+                }
+                Instruction::Mul(num1, num2) if enabled => {
+                    sum += num1 * num2;
+                }
+                _ => {}
+            }
+        }
+
+        sum.into()
+    }
+
+    fn generate(&self, rng: &mut Rng, size: Size) -> Option<String> {
+        let (lines, line_len) = size.pick((1, 200), (3, 1000), (6, 3000));
+        let junk =
+            b"()[]{}<>,'!@#$%^&*-+/?:;~ select where from why who how what when mul do don't";
+
+        let mut input = String::new();
+
+        for _ in 0..lines {
+            let mut line = String::new();
+
+            while line.len() < line_len {
+                match rng.index(12) {
+                    0 => line += &format!("mul({},{})", rng.int(1..=999), rng.int(1..=999)),
+                    1 => line += *rng.pick(&["do()", "don't()"]),
+                    // Almost instructions, which don't count
+                    2 => line += &format!("mul({},{}]", rng.int(1..=999), rng.int(1..=999)),
+                    3 => line += &format!("mul ({},{})", rng.int(1..=999), rng.int(1..=999)),
+                    _ => line.push(*rng.pick(junk) as char),
+                }
+            }
+
+            input += &line;
+            input.push('\n');
+        }
+
+        Some(input)
+    }
+}
+
+pub enum Instruction {
+    Mul(isize, isize),
+    Do,
+    Dont,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    aoc_common::example_tests! {
+        Day3,
+        part1_example: part1(include_str!("example")) == 161,
+        part2_example: part2(include_str!("example2")) == 48,
+    }
+}
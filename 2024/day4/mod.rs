@@ -0,0 +1,103 @@
+use aoc_common::{direction::OFFSETS_8, Answer, Grid, ParseError, Rng, Size, Solution};
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    type Parsed = Grid<char>;
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed, ParseError> {
+        Grid::parse(input, Some)
+    }
+
+    fn part1(&self, grid: &Self::Parsed) -> Answer {
+        let sum: usize = grid.positions().map(|pos| count_xmas_at(grid, pos)).sum();
+
+        sum.into()
+    }
+
+    fn part2(&self, grid: &Self::Parsed) -> Answer {
+        let mut sum = 0;
+
+        for pos in grid.positions() {
+            sum += if is_x_mas_at(grid, pos) { 1 } else { 0 };
+        }
+
+        sum.into()
+    }
+
+    fn generate(&self, rng: &mut Rng, size: Size) -> Option<String> {
+        let side = size.pick(10, 40, 140);
+        let mut input = String::new();
+
+        for _ in 0..side {
+            for _ in 0..side {
+                input.push(*rng.pick(&['X', 'M', 'A', 'S']));
+            }
+            input.push('\n');
+        }
+
+        Some(input)
+    }
+}
+
+fn count_xmas_at(grid: &Grid<char>, pos: (usize, usize)) -> usize {
+    if grid[pos] != 'X' {
+        return 0;
+    }
+
+    OFFSETS_8
+        .into_iter()
+        .filter(|(dx, dy)| {
+            "MAS"
+                .chars()
+                .zip(1..)
+                .all(|(c, dist)| grid.get_offset(pos, (dx * dist, dy * dist)) == Some(&c))
+        })
+        .count()
+}
+
+fn is_x_mas_at(grid: &Grid<char>, pos: (usize, usize)) -> bool {
+    let mut pos_sum = 0;
+
+    if grid[pos] != 'A' {
+        return false;
+    }
+
+    let top_left = grid.get_offset(pos, (-1, -1));
+    let top_right = grid.get_offset(pos, (1, -1));
+    let bottom_left = grid.get_offset(pos, (-1, 1));
+    let bottom_right = grid.get_offset(pos, (1, 1));
+
+    // top-left -> bottom-right
+    if top_left == Some(&'M') && bottom_right == Some(&'S') {
+        pos_sum += 1;
+    }
+
+    // bottom-right -> top-left
+    if top_left == Some(&'S') && bottom_right == Some(&'M') {
+        pos_sum += 1;
+    }
+
+    // top-right -> bottom-left
+    if top_right == Some(&'M') && bottom_left == Some(&'S') {
+        pos_sum += 1;
+    }
+
+    // bottom-left -> top-right
+    if top_right == Some(&'S') && bottom_left == Some(&'M') {
+        pos_sum += 1;
+    }
+
+    pos_sum == 2
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    aoc_common::example_tests! {
+        Day4,
+        part1_example: part1(include_str!("example")) == 18,
+        part2_example: part2(include_str!("example")) == 9,
+    }
+}
@@ -0,0 +1,160 @@
+use std::iter;
+
+use aoc_common::{graph::Graph, parse_num, Answer, ParseError, Rng, Size, Solution};
+
+pub struct Day5;
+
+/// The ordering rules as edges from the page that comes before to the one
+/// after, and the pages of every update.
+pub type PrintQueue = (Graph<isize>, Vec<Vec<isize>>);
+
+impl Solution for Day5 {
+    type Parsed = PrintQueue;
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed, ParseError> {
+        let mut rules = Graph::directed();
+        let mut pages = Vec::new();
+        let mut reading_rules = true;
+
+        for line in input.lines() {
+            if line.is_empty() {
+                reading_rules = false;
+                continue;
+            }
+
+            if reading_rules {
+                let Some((num1, num2)) = line.split_once("|") else {
+                    return Err(ParseError::new(line, "Expected '<num>|<num>'"));
+                };
+
+                rules.add_edge(parse_num(num1)?, parse_num(num2)?, ());
+            } else {
+                let page = line
+                    .split(",")
+                    .map(parse_num)
+                    .collect::<Result<Vec<_>, _>>()?;
+
+                pages.push(page);
+            }
+        }
+
+        Ok((rules, pages))
+    }
+
+    fn part1(&self, (rules, pages): &Self::Parsed) -> Answer {
+        let sum: isize = pages
+            .iter()
+            .filter(|page| is_page_safe(page, rules))
+            .map(|page| page[page.len() / 2])
+            .sum();
+
+        sum.into()
+    }
+
+    fn part2(&self, (rules, pages): &Self::Parsed) -> Answer {
+        let sum: isize = pages
+            .iter()
+            .filter(|page| !is_page_safe(page, rules))
+            .filter_map(|page| {
+                let reordered = reorder(page, rules);
+
+                if reordered.is_none() {
+                    tracing::debug!(?page, "Skipping an update whose rules have a cycle");
+                }
+
+                reordered.map(|reordered| reordered[page.len() / 2])
+            })
+            .sum();
+
+        sum.into()
+    }
+
+    fn generate(&self, rng: &mut Rng, size: Size) -> Option<String> {
+        // The rules order every pair of pages, so each update has one right order
+        let mut order = (11..=99).collect::<Vec<isize>>();
+        rng.shuffle(&mut order);
+        order.truncate(size.pick(9, 25, 49));
+
+        let mut rules = Vec::new();
+        for (idx, before) in order.iter().enumerate() {
+            for after in &order[idx + 1..] {
+                rules.push(format!("{before}|{after}\n"));
+            }
+        }
+        rng.shuffle(&mut rules);
+
+        let mut input = rules.concat();
+        input.push('\n');
+
+        for _ in 0..size.pick(6, 50, 200) {
+            let mut indices = (0..order.len()).collect::<Vec<_>>();
+            rng.shuffle(&mut indices);
+            // Odd lengths, so there is a middle page
+            indices.truncate(rng.index(order.len().min(23) / 2) * 2 + 3);
+
+            // Some updates are already in the right order
+            if rng.chance(0.5) {
+                indices.sort_unstable();
+            }
+
+            input += &indices
+                .iter()
+                .map(|&idx| order[idx].to_string())
+                .collect::<Vec<_>>()
+                .join(",");
+            input.push('\n');
+        }
+
+        Some(input)
+    }
+}
+
+fn is_page_safe(page: &[isize], rules: &Graph<isize>) -> bool {
+    page.iter()
+        .enumerate()
+        .all(|(idx, l)| page[idx + 1..].iter().all(|r| !rules.contains_edge(r, l)))
+}
+
+/// The pages in the order the rules between them give, keeping any that are
+/// repeated. The rules for all pages have cycles, so only the ones for this
+/// update are sorted. `None` if even those have a cycle.
+fn reorder(page: &[isize], rules: &Graph<isize>) -> Option<Vec<isize>> {
+    let mut update_rules = Graph::directed();
+
+    for &before in page {
+        update_rules.add_node(before);
+
+        for &after in page {
+            if rules.contains_edge(&before, &after) {
+                update_rules.add_edge(before, after, ());
+            }
+        }
+    }
+
+    let order = update_rules.topological_sort()?;
+
+    let reordered = order
+        .into_iter()
+        .flat_map(|id| {
+            let num = *update_rules.node(id);
+            let count = page.iter().filter(|&&other| other == num).count();
+
+            iter::repeat_n(num, count)
+        })
+        .collect();
+
+    Some(reordered)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    aoc_common::example_tests! {
+        Day5,
+        part1_example: part1(include_str!("example")) == 143,
+        part2_example: part2(include_str!("example")) == 123,
+        part2_repeated_pages: part2("1|2\n2|3\n\n3,2,2\n") == 2,
+        part2_cyclic_rules: part2("1|2\n2|3\n3|1\n5|6\n6|7\n\n3,2,1\n7,6,5\n") == 6,
+    }
+}
@@ -0,0 +1,222 @@
+use aoc_common::{cycle::brent, Answer, Direction, Grid, ParseError, Rng, Size, Solution};
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    type Parsed = Grid<char>;
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed, ParseError> {
+        Grid::parse(input, |c| matches!(c, '.' | '#' | '^').then_some(c))
+    }
+
+    fn part1(&self, map: &Self::Parsed) -> Answer {
+        let guard_starting_pos = map.position(|c| *c == '^').expect("No guard found");
+
+        let steps = get_possible_positions(guard_starting_pos, map.clone()).len();
+
+        steps.into()
+    }
+
+    fn part2(&self, map: &Self::Parsed) -> Answer {
+        let guard_starting_pos = map.position(|c| *c == '^').expect("No guard found");
+
+        count_loops(guard_starting_pos, map).into()
+    }
+
+    fn generate(&self, rng: &mut Rng, size: Size) -> Option<String> {
+        let side = size.pick(10, 50, 130);
+
+        // Retry until the guard takes a fair walk off the map, not a loop
+        loop {
+            let mut map = Grid::new(side, side, '.');
+
+            for pos in map.positions().collect::<Vec<_>>() {
+                if rng.chance(0.02) {
+                    map[pos] = '#';
+                }
+            }
+
+            let start = (rng.index(side), rng.index(side));
+            map[start] = '^';
+
+            if !check_map_for_loop(start, &map)
+                && get_possible_positions(start, map.clone()).len() >= side
+            {
+                return Some(
+                    map.rows()
+                        .map(|row| row.iter().collect::<String>() + "\n")
+                        .collect(),
+                );
+            }
+        }
+    }
+}
+
+/// Counts the obstacles that make the guard walk in a loop. The guard walks
+/// the route once, and every position on it is tried as an obstacle by
+/// continuing from just before it.
+fn count_loops(guard_starting_pos: (usize, usize), map: &Grid<char>) -> usize {
+    let mut map = map.clone();
+    let mut tried = Grid::new(map.width(), map.height(), false);
+    tried[guard_starting_pos] = true;
+
+    // The last obstacle for which the guard walked through each position in
+    // each direction
+    let mut seen = Grid::new(map.width(), map.height(), [usize::MAX; 4]);
+    let mut obstacles = 0;
+    let mut loops = 0;
+
+    let mut guard_pos = guard_starting_pos;
+    let mut guard_dir = Direction::Up;
+
+    while let (Some(next_pos), _) = step(&mut guard_dir, guard_pos, &map) {
+        if !tried[next_pos] {
+            tried[next_pos] = true;
+            map[next_pos] = '#';
+
+            if walks_in_loop(guard_pos, guard_dir, &map, &mut seen, obstacles) {
+                loops += 1;
+            }
+
+            map[next_pos] = '.';
+            obstacles += 1;
+        }
+
+        guard_pos = next_pos;
+    }
+
+    loops
+}
+
+fn walks_in_loop(
+    mut pos: (usize, usize),
+    mut dir: Direction,
+    map: &Grid<char>,
+    seen: &mut Grid<[usize; 4]>,
+    obstacle: usize,
+) -> bool {
+    loop {
+        if seen[pos][dir as usize] == obstacle {
+            break true;
+        }
+        seen[pos][dir as usize] = obstacle;
+
+        match step(&mut dir, pos, map).0 {
+            Some(next_pos) => pos = next_pos,
+            None => break false,
+        }
+    }
+}
+
+/// Counts the obstacles that make the guard walk in a loop by walking the
+/// whole route again on a copy of the map for each of them.
+#[cfg(test)]
+fn count_loops_by_cloning(guard_starting_pos: (usize, usize), map: &Grid<char>) -> usize {
+    let possible_positions = get_possible_positions(guard_starting_pos, map.clone());
+
+    let mut loops = 0;
+
+    for obstacle_pos in possible_positions {
+        let mut current_map = map.clone();
+        current_map[obstacle_pos] = '#';
+
+        let is_loop = check_map_for_loop(guard_starting_pos, &current_map);
+
+        if is_loop {
+            loops += 1;
+        }
+    }
+
+    loops
+}
+
+/// Whether the guard walks in a loop instead of leaving the map.
+fn check_map_for_loop(guard_starting_pos: (usize, usize), map: &Grid<char>) -> bool {
+    // Leaving the map ends in `None`, which steps to itself. The guard moves
+    // on every step, so any other cycle is longer than that.
+    let cycle = brent(Some((guard_starting_pos, Direction::Up)), |guard| {
+        guard.and_then(|(pos, mut dir)| step(&mut dir, pos, map).0.map(|next| (next, dir)))
+    });
+
+    cycle.period > 1
+}
+
+fn get_possible_positions(
+    guard_starting_pos: (usize, usize),
+    mut map: Grid<char>,
+) -> Vec<(usize, usize)> {
+    let mut guard_pos = Some(guard_starting_pos);
+    let mut guard_dir = Direction::Up;
+
+    let mut possible_positions = Vec::new();
+
+    while let Some(pos) = guard_pos {
+        if map[pos] != 'X' {
+            possible_positions.push(pos);
+            map[pos] = 'X';
+        }
+
+        guard_pos = step(&mut guard_dir, pos, &map).0;
+    }
+
+    possible_positions
+}
+
+fn step(
+    dir: &mut Direction,
+    pos: (usize, usize),
+    map: &Grid<char>,
+) -> (Option<(usize, usize)>, bool) {
+    let mut had_to_turn = false;
+
+    loop {
+        let new_pos = map.step(pos, *dir);
+
+        if new_pos.map(|p| map[p]) == Some('#') {
+            *dir = dir.turn_right();
+            had_to_turn = true;
+        } else {
+            break (new_pos, had_to_turn);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::differential::{assert_equivalent, shrink_cells};
+
+    aoc_common::example_tests! {
+        Day6,
+        part1_example: part1(include_str!("example")) == 41,
+        part2_example: part2(include_str!("example")) == 6,
+    }
+
+    #[test]
+    fn walking_once_matches_cloning() {
+        let map = |rng: &mut Rng| {
+            let input = Day6.generate(rng, Size::Medium).unwrap();
+            Day6.parse(&input).unwrap()
+        };
+        // Removing an obstacle can send the guard into a loop without any new
+        // one, which the puzzle rules out
+        let shrink = |map: &Grid<char>| {
+            let start = map.position(|c| *c == '^').unwrap();
+
+            shrink_cells(map, |c| (*c == '#').then_some('.'))
+                .into_iter()
+                .filter(|map| !check_map_for_loop(start, map))
+                .collect()
+        };
+        let loops =
+            |map: &Grid<char>, f: fn(_, &_) -> usize| f(map.position(|c| *c == '^').unwrap(), map);
+
+        assert_equivalent(
+            30,
+            map,
+            shrink,
+            |map| loops(map, count_loops_by_cloning),
+            |map| loops(map, count_loops),
+        );
+    }
+}
@@ -0,0 +1,136 @@
+use aoc_common::{parse_num, Answer, ParseError, Rng, Size, Solution};
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    type Parsed = Vec<(isize, Vec<isize>)>;
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed, ParseError> {
+        let mut equations = Vec::new();
+
+        for line in input.lines() {
+            let Some((result, operands)) = line.split_once(": ") else {
+                return Err(ParseError::new(line, "Expected '<result>: <operands>'"));
+            };
+
+            let operands = operands
+                .split(" ")
+                .map(parse_num)
+                .collect::<Result<Vec<_>, _>>()?;
+
+            equations.push((parse_num(result)?, operands));
+        }
+
+        Ok(equations)
+    }
+
+    fn part1(&self, equations: &Self::Parsed) -> Answer {
+        calibration_result(equations, false).into()
+    }
+
+    fn part2(&self, equations: &Self::Parsed) -> Answer {
+        calibration_result(equations, true).into()
+    }
+
+    fn generate(&self, rng: &mut Rng, size: Size) -> Option<String> {
+        let mut input = String::new();
+
+        for _ in 0..size.pick(10, 100, 850) {
+            let mut operands = vec![rng.int(1..=999)];
+            let mut result = operands[0];
+
+            // At most 17 digits in total, so even concatenating them all fits
+            let mut digits = operands[0].to_string().len();
+
+            while operands.len() < 12 {
+                let max = if rng.chance(0.5) { 9 } else { 999 };
+                let operand = rng.int(1..=max);
+                let operand_digits = operand.to_string().len();
+
+                if digits + operand_digits > 17 {
+                    break;
+                }
+
+                result = match rng.index(3) {
+                    0 => result + operand,
+                    1 => result * operand,
+                    _ => format!("{result}{operand}").parse().ok()?,
+                };
+                digits += operand_digits;
+                operands.push(operand);
+            }
+
+            // Some equations can't be made true
+            if rng.chance(0.4) {
+                result += rng.int(1..=9);
+            }
+
+            let operands = operands
+                .iter()
+                .map(|operand| operand.to_string())
+                .collect::<Vec<_>>()
+                .join(" ");
+
+            input += &format!("{result}: {operands}\n");
+        }
+
+        Some(input)
+    }
+}
+
+fn calibration_result(equations: &[(isize, Vec<isize>)], allow_concat: bool) -> isize {
+    let mut sum = 0;
+
+    for &(result, ref operands) in equations {
+        let success = test_equation(result, operands[0], &operands[1..], allow_concat);
+
+        if success {
+            sum += result;
+        }
+    }
+
+    sum
+}
+
+/// Whether some combination of `*`, `+` and, if allowed, `||` yields `result`.
+fn test_equation(result: isize, acc: isize, operands: &[isize], allow_concat: bool) -> bool {
+    if operands.is_empty() {
+        return result == acc;
+    }
+
+    // *
+    let mut new_acc = acc * operands[0];
+
+    if test_equation(result, new_acc, &operands[1..], allow_concat) {
+        return true;
+    }
+
+    // +
+    new_acc = acc + operands[0];
+
+    if test_equation(result, new_acc, &operands[1..], allow_concat) {
+        return true;
+    }
+
+    if !allow_concat {
+        return false;
+    }
+
+    // ||
+    new_acc = format!("{}{}", acc, operands[0])
+        .parse()
+        .expect("Failed to parse num");
+
+    test_equation(result, new_acc, &operands[1..], allow_concat)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    aoc_common::example_tests! {
+        Day7,
+        part1_example: part1(include_str!("example")) == 3749,
+        part2_example: part2(include_str!("example")) == 11387,
+    }
+}
@@ -0,0 +1,207 @@
+use std::collections::{HashMap, HashSet};
+
+use aoc_common::{Answer, ParseError, Rng, Size, Solution};
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    type Parsed = AntennaMap;
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed, ParseError> {
+        let mut antennas: HashMap<char, Vec<(isize, isize)>> = HashMap::new();
+
+        let mut x_max = 0;
+        let mut y_max = 0;
+
+        for (y, line) in input.lines().enumerate() {
+            y_max = y as isize;
+
+            for (x, (idx, c)) in line.char_indices().enumerate() {
+                x_max = x as isize;
+
+                if c.is_ascii_alphanumeric() {
+                    antennas
+                        .entry(c)
+                        .and_modify(|v| v.push((x as isize, y as isize)))
+                        .or_insert(vec![(x as isize, y as isize)]);
+                } else if c != '.' {
+                    return Err(ParseError::new(
+                        &line[idx..idx + c.len_utf8()],
+                        "Unexpected character",
+                    ));
+                }
+            }
+        }
+
+        Ok(AntennaMap {
+            antennas,
+            x_max,
+            y_max,
+        })
+    }
+
+    fn part1(&self, map: &Self::Parsed) -> Answer {
+        let &AntennaMap {
+            ref antennas,
+            x_max,
+            y_max,
+        } = map;
+
+        let mut antinode_positions = HashSet::new();
+
+        for positions in antennas.values() {
+            let combinations = get_antenna_combinations(positions);
+
+            for (a, b) in combinations {
+                let delta_x = b.0 - a.0;
+                let delta_y = b.1 - a.1;
+
+                antinode_positions.insert((a.0 - delta_x, a.1 - delta_y));
+                antinode_positions.insert((b.0 + delta_x, b.1 + delta_y));
+            }
+        }
+
+        tracing::trace!(
+            "antinodes:\n{}",
+            format_antinodes(&antinode_positions, x_max, y_max)
+        );
+
+        let unique_antinodes = antinode_positions
+            .iter()
+            .filter(|pos| pos.0 >= 0 && pos.0 <= x_max && pos.1 >= 0 && pos.1 <= y_max)
+            .count();
+
+        unique_antinodes.into()
+    }
+
+    fn part2(&self, map: &Self::Parsed) -> Answer {
+        let &AntennaMap {
+            ref antennas,
+            x_max,
+            y_max,
+        } = map;
+
+        let mut antinode_positions = HashSet::new();
+
+        for positions in antennas.values() {
+            let combinations = get_antenna_combinations(positions);
+
+            for (a, b) in combinations {
+                let delta_x = b.0 - a.0;
+                let delta_y = b.1 - a.1;
+
+                antinode_positions.insert((a.0, a.1));
+
+                let mut new_x = a.0;
+                let mut new_y = a.1;
+                loop {
+                    new_x -= delta_x;
+                    new_y -= delta_y;
+
+                    // if in bounds
+                    if new_x >= 0 && new_x <= x_max && new_y >= 0 && new_y <= y_max {
+                        antinode_positions.insert((new_x, new_y));
+                    } else {
+                        break;
+                    }
+                }
+
+                let mut new_x = a.0;
+                let mut new_y = a.1;
+                loop {
+                    new_x += delta_x;
+                    new_y += delta_y;
+
+                    // if in bounds
+                    if new_x >= 0 && new_x <= x_max && new_y >= 0 && new_y <= y_max {
+                        antinode_positions.insert((new_x, new_y));
+                    } else {
+                        break;
+                    }
+                }
+            }
+        }
+
+        tracing::trace!(
+            "antinodes:\n{}",
+            format_antinodes(&antinode_positions, x_max, y_max)
+        );
+
+        antinode_positions.len().into()
+    }
+
+    fn generate(&self, rng: &mut Rng, size: Size) -> Option<String> {
+        let side = size.pick(12, 30, 50);
+        let frequencies = ('0'..='9')
+            .chain('a'..='z')
+            .chain('A'..='Z')
+            .collect::<Vec<_>>();
+
+        let mut map = vec![vec!['.'; side]; side];
+
+        for _ in 0..size.pick(3, 15, 50) {
+            let frequency = *rng.pick(&frequencies);
+
+            for _ in 0..rng.int(2..=4) {
+                map[rng.index(side)][rng.index(side)] = frequency;
+            }
+        }
+
+        Some(
+            map.iter()
+                .map(|row| row.iter().collect::<String>() + "\n")
+                .collect(),
+        )
+    }
+}
+
+pub struct AntennaMap {
+    antennas: HashMap<char, Vec<(isize, isize)>>,
+    x_max: isize,
+    y_max: isize,
+}
+
+fn format_antinodes(
+    antinode_positions: &HashSet<(isize, isize)>,
+    x_max: isize,
+    y_max: isize,
+) -> String {
+    let mut map = String::new();
+
+    for y in 0..=y_max {
+        for x in 0..=x_max {
+            map.push(if antinode_positions.contains(&(x, y)) {
+                '#'
+            } else {
+                '.'
+            });
+        }
+
+        map.push('\n');
+    }
+
+    map
+}
+
+fn get_antenna_combinations(positions: &[(isize, isize)]) -> Vec<((isize, isize), (isize, isize))> {
+    let mut combinations = Vec::new();
+
+    for (idx, a) in positions.iter().enumerate() {
+        for b in &positions[(idx + 1)..] {
+            combinations.push((*a, *b));
+        }
+    }
+
+    combinations
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    aoc_common::example_tests! {
+        Day8,
+        part1_example: part1(include_str!("example")) == 14,
+        part2_example: part2(include_str!("example")) == 34,
+    }
+}
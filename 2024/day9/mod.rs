@@ -0,0 +1,218 @@
+use aoc_common::{Answer, ParseError, Rng, Size, Solution};
+
+pub struct Day9;
+
+impl Solution for Day9 {
+    type Parsed = Vec<FilesystemLocation>;
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed, ParseError> {
+        let mut disk = Vec::new();
+
+        let mut file_index: usize = 0;
+
+        let input = input.trim_end();
+
+        for (idx, (byte_idx, c)) in input.char_indices().enumerate() {
+            let Some(len) = c.to_digit(10) else {
+                return Err(ParseError::new(
+                    &input[byte_idx..byte_idx + c.len_utf8()],
+                    "Expected a digit",
+                ));
+            };
+            let len = len as usize;
+
+            if idx % 2 == 0 {
+                // is file
+                disk.push(FilesystemLocation::File {
+                    idx: file_index,
+                    len,
+                });
+
+                file_index += 1;
+            } else {
+                // free space
+                disk.push(FilesystemLocation::Free { len });
+            }
+        }
+
+        Ok(disk)
+    }
+
+    fn part1(&self, disk: &Self::Parsed) -> Answer {
+        let mut disk = expand_blocks(disk);
+
+        let mut l_idx = 0_usize;
+        let mut r_idx = disk.len() - 1;
+
+        loop {
+            if r_idx <= l_idx {
+                break;
+            }
+
+            if disk[l_idx] < 0 {
+                // free space
+                // move something here
+
+                loop {
+                    if disk[r_idx] >= 0 {
+                        disk[l_idx] = disk[r_idx];
+                        disk[r_idx] = -1;
+
+                        r_idx -= 1;
+                        break;
+                    }
+
+                    r_idx -= 1;
+                }
+            }
+
+            l_idx += 1;
+        }
+
+        let checksum: usize = disk
+            .iter()
+            .enumerate()
+            .map(|(idx, file_idx)| {
+                if *file_idx < 0 {
+                    0
+                } else {
+                    idx * *file_idx as usize
+                }
+            })
+            .sum();
+
+        checksum.into()
+    }
+
+    fn part2(&self, disk: &Self::Parsed) -> Answer {
+        let mut disk = disk.to_vec();
+
+        let mut move_pos = 0;
+
+        for r_idx in (0..disk.len()).rev() {
+            match disk[r_idx] {
+                FilesystemLocation::File {
+                    idx: file_idx,
+                    len: file_len,
+                } => {
+                    // find next appropriate file
+
+                    let mut free_len = 0;
+
+                    for (l_idx, loc) in disk.iter().enumerate().take(r_idx) {
+                        match *loc {
+                            FilesystemLocation::File { .. } => {}
+                            FilesystemLocation::Free { len } => {
+                                if len >= file_len {
+                                    move_pos = l_idx;
+                                    free_len = len;
+
+                                    break;
+                                }
+                            }
+                        }
+                    }
+
+                    if free_len > 0 {
+                        disk[move_pos] = FilesystemLocation::File {
+                            idx: file_idx,
+                            len: file_len,
+                        };
+                        disk[r_idx] = FilesystemLocation::Free { len: file_len };
+
+                        if file_len < free_len {
+                            match disk[move_pos + 1] {
+                                FilesystemLocation::Free { len } => {
+                                    disk[move_pos + 1] = FilesystemLocation::Free {
+                                        len: (free_len - file_len) + len,
+                                    };
+                                }
+                                FilesystemLocation::File { .. } => {
+                                    disk.insert(
+                                        move_pos + 1,
+                                        FilesystemLocation::Free {
+                                            len: free_len - file_len,
+                                        },
+                                    );
+                                }
+                            }
+                        }
+                    }
+                }
+                FilesystemLocation::Free { .. } => {}
+            }
+        }
+
+        let mut pos = 0;
+        let mut checksum: usize = 0;
+
+        for loc in disk {
+            match loc {
+                FilesystemLocation::Free { len } => {
+                    pos += len;
+                }
+                FilesystemLocation::File { idx, len } => {
+                    for i in pos..(pos + len) {
+                        checksum += idx * i;
+                    }
+
+                    pos += len;
+                }
+            }
+        }
+
+        checksum.into()
+    }
+
+    fn generate(&self, rng: &mut Rng, size: Size) -> Option<String> {
+        let files = size.pick(10, 1000, 10_000);
+        let mut input = String::new();
+
+        for file in 0..files {
+            // Files are never empty, free space can be
+            input += &rng.int(1..=9).to_string();
+
+            if file + 1 < files {
+                input += &rng.int(0..=9).to_string();
+            }
+        }
+        input.push('\n');
+
+        Some(input)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum FilesystemLocation {
+    Free { len: usize },
+    File { idx: usize, len: usize },
+}
+
+/// One entry per block, holding the file index or -1 for free space.
+fn expand_blocks(disk: &[FilesystemLocation]) -> Vec<isize> {
+    let mut blocks = Vec::new();
+
+    for loc in disk {
+        match *loc {
+            FilesystemLocation::Free { len } => {
+                blocks.extend(std::iter::repeat_n(-1, len));
+            }
+            FilesystemLocation::File { idx, len } => {
+                blocks.extend(std::iter::repeat_n(idx as isize, len));
+            }
+        }
+    }
+
+    blocks
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    aoc_common::example_tests! {
+        Day9,
+        part1_example: part1(include_str!("example")) == 1928,
+        part2_example: part2(include_str!("example")) == 2858,
+    }
+}
@@ -0,0 +1,33 @@
+pub mod day1;
+pub mod day10;
+pub mod day11;
+pub mod day2;
+pub mod day3;
+pub mod day4;
+pub mod day5;
+pub mod day6;
+pub mod day7;
+pub mod day8;
+pub mod day9;
+
+use aoc_common::Solver;
+
+/// Returns the solution for the given day of 2024, if there is one.
+pub fn solution(day: u32) -> Option<&'static dyn Solver> {
+    let solution: &'static dyn Solver = match day {
+        1 => &day1::Day1,
+        2 => &day2::Day2,
+        3 => &day3::Day3,
+        4 => &day4::Day4,
+        5 => &day5::Day5,
+        6 => &day6::Day6,
+        7 => &day7::Day7,
+        8 => &day8::Day8,
+        9 => &day9::Day9,
+        10 => &day10::Day10,
+        11 => &day11::Day11,
+        _ => return None,
+    };
+
+    Some(solution)
+}
@@ -0,0 +1,163 @@
+use std::{fs, path::PathBuf};
+
+use aoc_common::Answer;
+
+use crate::{committed_input_path, input::InputSource, solution};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExpectedAnswer {
+    pub year: u32,
+    pub day: u32,
+    pub part: u32,
+    pub answer: Answer,
+}
+
+/// The checked-in `answers.toml` at the root of the repository.
+pub fn answers_path() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("..")
+        .join("answers.toml")
+}
+
+pub fn load_answers() -> Result<Vec<ExpectedAnswer>, String> {
+    let path = answers_path();
+
+    let text = fs::read_to_string(&path)
+        .map_err(|err| format!("Error opening {}: {err}", path.display()))?;
+
+    parse_answers(&text)
+}
+
+/// Parses answers in the form of `[2023.day1]` tables with `part1` and `part2` keys.
+pub fn parse_answers(text: &str) -> Result<Vec<ExpectedAnswer>, String> {
+    let table = text
+        .parse::<toml::Table>()
+        .map_err(|err| format!("Malformed answers file: {err}"))?;
+
+    let mut answers = Vec::new();
+
+    for (year_key, days) in table {
+        let Ok(year) = year_key.parse::<u32>() else {
+            return Err(format!("Malformed year: '{year_key}'"));
+        };
+        let Some(days) = days.as_table() else {
+            return Err(format!("Expected a table for {year}"));
+        };
+
+        for (day_key, parts) in days {
+            let Some(day) = day_key
+                .strip_prefix("day")
+                .and_then(|d| d.parse::<u32>().ok())
+            else {
+                return Err(format!("Malformed day in {year}: '{day_key}'"));
+            };
+            let Some(parts) = parts.as_table() else {
+                return Err(format!("Expected a table for {year} {day_key}"));
+            };
+
+            for (part_key, value) in parts {
+                let Some(part) = part_key
+                    .strip_prefix("part")
+                    .and_then(|p| p.parse::<u32>().ok())
+                else {
+                    return Err(format!("Malformed part in {year} {day_key}: '{part_key}'"));
+                };
+
+                let answer = match value {
+                    toml::Value::Integer(n) => Answer::Int(*n),
+                    toml::Value::String(s) => Answer::Str(s.clone()),
+                    _ => {
+                        return Err(format!(
+                            "Answer for {year} {day_key} {part_key} must be an integer or a string"
+                        ))
+                    }
+                };
+
+                answers.push(ExpectedAnswer {
+                    year,
+                    day,
+                    part,
+                    answer,
+                });
+            }
+        }
+    }
+
+    answers.sort_by_key(|a| (a.year, a.day, a.part));
+
+    Ok(answers)
+}
+
+/// Runs the solver for `expected` on its committed input, returning a
+/// description of the problem if the answer doesn't match.
+pub fn verify(expected: &ExpectedAnswer) -> Result<(), String> {
+    let ExpectedAnswer {
+        year, day, part, ..
+    } = *expected;
+
+    let Some(solution) = solution(year, day).filter(|s| (1..=s.parts()).contains(&part)) else {
+        return Err(format!("No solution for {year} day {day} part {part}"));
+    };
+
+    let input_path = committed_input_path(year, day);
+    let input = InputSource::File(input_path.clone()).read()?;
+
+    let answer = solution.run(&input, part).map_err(|err| {
+        format!(
+            "{year} day {day} part {part}: error parsing {}: {err}",
+            input_path.display()
+        )
+    })?;
+
+    if answer == expected.answer {
+        Ok(())
+    } else {
+        Err(format!(
+            "{year} day {day} part {part}: expected {}, got {answer}",
+            expected.answer
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_answers() {
+        let answers =
+            parse_answers("[2024.day2]\npart2 = \"abc\"\npart1 = 7\n\n[2023.day10]\npart1 = -3\n")
+                .unwrap();
+
+        assert_eq!(
+            answers,
+            vec![
+                ExpectedAnswer {
+                    year: 2023,
+                    day: 10,
+                    part: 1,
+                    answer: Answer::Int(-3),
+                },
+                ExpectedAnswer {
+                    year: 2024,
+                    day: 2,
+                    part: 1,
+                    answer: Answer::Int(7),
+                },
+                ExpectedAnswer {
+                    year: 2024,
+                    day: 2,
+                    part: 2,
+                    answer: Answer::Str("abc".to_string()),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn rejects_malformed_keys() {
+        assert!(parse_answers("[2023.one]\npart1 = 1\n").is_err());
+        assert!(parse_answers("[2023.day1]\nfirst = 1\n").is_err());
+        assert!(parse_answers("[2023.day1]\npart1 = 1.5\n").is_err());
+    }
+}
@@ -0,0 +1,277 @@
+use std::{
+    fmt, fs,
+    hint::black_box,
+    path::{Path, PathBuf},
+    time::{Duration, Instant, SystemTime, UNIX_EPOCH},
+};
+
+use aoc_common::{ParseError, Solver};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Phase {
+    Parse,
+    Part1,
+    Part2,
+}
+
+impl Phase {
+    pub fn part(part: u32) -> Phase {
+        match part {
+            1 => Phase::Part1,
+            2 => Phase::Part2,
+            _ => panic!("Unexpected part {part}"),
+        }
+    }
+}
+
+impl fmt::Display for Phase {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Phase::Parse => write!(f, "parse"),
+            Phase::Part1 => write!(f, "part1"),
+            Phase::Part2 => write!(f, "part2"),
+        }
+    }
+}
+
+/// Timings of one phase over all iterations, in nanoseconds.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BenchResult {
+    pub year: u32,
+    pub day: u32,
+    pub phase: Phase,
+    pub min_ns: u64,
+    pub median_ns: u64,
+    pub p95_ns: u64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BenchRun {
+    /// Seconds since the Unix epoch.
+    pub timestamp: u64,
+    pub iterations: u32,
+    pub results: Vec<BenchResult>,
+}
+
+impl BenchRun {
+    pub fn new(iterations: u32, results: Vec<BenchResult>) -> Self {
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_secs())
+            .unwrap_or(0);
+
+        BenchRun {
+            timestamp,
+            iterations,
+            results,
+        }
+    }
+}
+
+/// Returns `(min, median, p95)` of the samples.
+pub fn stats(samples: &[Duration]) -> (Duration, Duration, Duration) {
+    assert!(!samples.is_empty(), "Need at least one sample");
+
+    let mut sorted = samples.to_vec();
+    sorted.sort();
+
+    let percentile = |p: usize| sorted[(sorted.len() * p).div_ceil(100).max(1) - 1];
+
+    (sorted[0], percentile(50), percentile(95))
+}
+
+/// Times parsing `input` and solving each of `parts` separately, `iterations` times each.
+pub fn bench_day(
+    year: u32,
+    day: u32,
+    solution: &dyn Solver,
+    input: &str,
+    parts: &[u32],
+    iterations: u32,
+) -> Result<Vec<BenchResult>, ParseError> {
+    let mut parse_samples = Vec::new();
+    let mut part_samples = vec![Vec::new(); parts.len()];
+
+    for _ in 0..iterations.max(1) {
+        let start = Instant::now();
+        let parsed = solution.parse(black_box(input))?;
+        parse_samples.push(start.elapsed());
+
+        for (samples, part) in part_samples.iter_mut().zip(parts) {
+            let start = Instant::now();
+            black_box(solution.solve(parsed.as_ref(), *part));
+            samples.push(start.elapsed());
+        }
+    }
+
+    let result = |phase, samples: &[Duration]| {
+        let (min, median, p95) = stats(samples);
+
+        BenchResult {
+            year,
+            day,
+            phase,
+            min_ns: min.as_nanos() as u64,
+            median_ns: median.as_nanos() as u64,
+            p95_ns: p95.as_nanos() as u64,
+        }
+    };
+
+    let mut results = vec![result(Phase::Parse, &parse_samples)];
+
+    for (samples, part) in part_samples.iter().zip(parts) {
+        results.push(result(Phase::part(*part), samples));
+    }
+
+    Ok(results)
+}
+
+/// Where the benchmark history is kept unless another path is given.
+pub fn default_history_path() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("..")
+        .join("target")
+        .join("bench-history.json")
+}
+
+/// Loads all previous runs, oldest first. A missing file is an empty history.
+pub fn load_history(path: &Path) -> Result<Vec<BenchRun>, String> {
+    if !path.exists() {
+        return Ok(Vec::new());
+    }
+
+    let text = fs::read_to_string(path)
+        .map_err(|err| format!("Error opening {}: {err}", path.display()))?;
+
+    serde_json::from_str(&text)
+        .map_err(|err| format!("Malformed bench history {}: {err}", path.display()))
+}
+
+pub fn save_history(path: &Path, history: &[BenchRun]) -> Result<(), String> {
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)
+            .map_err(|err| format!("Error creating {}: {err}", dir.display()))?;
+    }
+
+    let text = serde_json::to_string_pretty(history).expect("History serializes");
+
+    fs::write(path, text).map_err(|err| format!("Error writing {}: {err}", path.display()))
+}
+
+/// The most recent earlier result for the same year, day and phase.
+pub fn previous_result<'a>(
+    history: &'a [BenchRun],
+    result: &BenchResult,
+) -> Option<&'a BenchResult> {
+    history.iter().rev().find_map(|run| {
+        run.results
+            .iter()
+            .find(|r| (r.year, r.day, r.phase) == (result.year, result.day, result.phase))
+    })
+}
+
+/// Formats nanoseconds with a unit that keeps the number readable, e.g. `1.23ms`.
+pub fn format_ns(ns: u64) -> String {
+    let ns = ns as f64;
+
+    if ns < 1e3 {
+        format!("{ns:.0}ns")
+    } else if ns < 1e6 {
+        format!("{:.2}µs", ns / 1e3)
+    } else if ns < 1e9 {
+        format!("{:.2}ms", ns / 1e6)
+    } else {
+        format!("{:.2}s", ns / 1e9)
+    }
+}
+
+/// The relative change of the median compared to a previous result, e.g. `-12.5%`.
+pub fn format_change(previous: &BenchResult, current: &BenchResult) -> String {
+    if previous.median_ns == 0 {
+        return "n/a".to_string();
+    }
+
+    let change = (current.median_ns as f64 / previous.median_ns as f64 - 1.0) * 100.0;
+
+    format!("{change:+.1}%")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn result(phase: Phase, median_ns: u64) -> BenchResult {
+        BenchResult {
+            year: 2023,
+            day: 5,
+            phase,
+            min_ns: median_ns,
+            median_ns,
+            p95_ns: median_ns,
+        }
+    }
+
+    #[test]
+    fn computes_min_median_and_p95() {
+        let samples = (1..=20)
+            .rev()
+            .map(Duration::from_millis)
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            stats(&samples),
+            (
+                Duration::from_millis(1),
+                Duration::from_millis(10),
+                Duration::from_millis(19)
+            )
+        );
+        assert_eq!(
+            stats(&[Duration::from_millis(3)]).2,
+            Duration::from_millis(3)
+        );
+    }
+
+    #[test]
+    fn finds_the_latest_previous_result() {
+        let history = vec![
+            BenchRun {
+                timestamp: 1,
+                iterations: 10,
+                results: vec![result(Phase::Parse, 100), result(Phase::Part1, 200)],
+            },
+            BenchRun {
+                timestamp: 2,
+                iterations: 10,
+                results: vec![result(Phase::Parse, 50)],
+            },
+        ];
+
+        let parse = previous_result(&history, &result(Phase::Parse, 0)).unwrap();
+        let part1 = previous_result(&history, &result(Phase::Part1, 0)).unwrap();
+
+        assert_eq!(parse.median_ns, 50);
+        assert_eq!(part1.median_ns, 200);
+        assert!(previous_result(&history, &result(Phase::Part2, 0)).is_none());
+        assert_eq!(format_change(part1, &result(Phase::Part1, 150)), "-25.0%");
+    }
+
+    #[test]
+    fn history_round_trips_through_json() {
+        let history = vec![BenchRun {
+            timestamp: 7,
+            iterations: 3,
+            results: vec![result(Phase::Part2, 1234)],
+        }];
+
+        let json = serde_json::to_string(&history).unwrap();
+
+        assert!(json.contains("\"phase\":\"part2\""));
+        assert_eq!(
+            serde_json::from_str::<Vec<BenchRun>>(&json).unwrap(),
+            history
+        );
+    }
+}
@@ -0,0 +1,179 @@
+use std::{
+    fs, process,
+    sync::{Arc, Mutex},
+    thread,
+    time::{Duration, Instant},
+};
+
+use crate::bench::{format_ns, Phase};
+
+/// The exit code of `aoc run` when the solver went over its budget.
+pub const OVER_BUDGET_EXIT_CODE: i32 = 3;
+
+const CHECK_INTERVAL: Duration = Duration::from_millis(10);
+
+/// Limits for one `aoc run`, covering parsing and solving together.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Budget {
+    pub time: Option<Duration>,
+    /// Peak resident memory in bytes.
+    pub memory: Option<u64>,
+}
+
+impl Budget {
+    /// Describes how the budget was exceeded, if it was.
+    pub fn check(&self, elapsed: Duration, peak_memory: Option<u64>) -> Option<String> {
+        if let Some(time) = self.time.filter(|time| elapsed > *time) {
+            return Some(format!(
+                "ran for more than {}",
+                format_ns(time.as_nanos() as u64)
+            ));
+        }
+
+        match (self.memory, peak_memory) {
+            (Some(memory), Some(peak)) if peak > memory => Some(format!(
+                "used {} of memory, more than {}",
+                format_bytes(peak),
+                format_bytes(memory)
+            )),
+            _ => None,
+        }
+    }
+}
+
+/// Parses sizes like `512M`, `2G` or `4096`, in powers of 1024.
+pub fn parse_size(s: &str) -> Result<u64, String> {
+    let s = s.trim();
+    let (number, unit) = s.split_at(s.find(|c: char| !c.is_ascii_digit()).unwrap_or(s.len()));
+
+    let factor = match unit
+        .to_ascii_uppercase()
+        .trim_end_matches("IB")
+        .trim_end_matches('B')
+    {
+        "" => 1,
+        "K" => 1 << 10,
+        "M" => 1 << 20,
+        "G" => 1 << 30,
+        _ => return Err(format!("Unknown size unit in '{s}', expected K, M or G")),
+    };
+
+    number
+        .parse::<u64>()
+        .ok()
+        .and_then(|n| n.checked_mul(factor))
+        .ok_or_else(|| format!("Malformed size '{s}'"))
+}
+
+pub fn format_bytes(bytes: u64) -> String {
+    let bytes = bytes as f64;
+
+    if bytes < 1024.0 {
+        format!("{bytes:.0}B")
+    } else if bytes < 1024.0 * 1024.0 {
+        format!("{:.1}KiB", bytes / 1024.0)
+    } else if bytes < 1024.0 * 1024.0 * 1024.0 {
+        format!("{:.1}MiB", bytes / 1024.0 / 1024.0)
+    } else {
+        format!("{:.2}GiB", bytes / 1024.0 / 1024.0 / 1024.0)
+    }
+}
+
+/// The peak resident memory of this process, where `/proc` has it.
+pub fn peak_memory() -> Option<u64> {
+    let status = fs::read_to_string("/proc/self/status").ok()?;
+    let kb = status
+        .lines()
+        .find_map(|line| line.strip_prefix("VmHWM:"))?
+        .trim()
+        .strip_suffix("kB")?
+        .trim()
+        .parse::<u64>()
+        .ok()?;
+
+    Some(kb * 1024)
+}
+
+/// Watches the budget from a thread of its own and exits the process with
+/// [`OVER_BUDGET_EXIT_CODE`] once it's exceeded, naming the phase that was
+/// running.
+pub struct Watchdog {
+    phase: Arc<Mutex<Phase>>,
+}
+
+impl Watchdog {
+    pub fn start(budget: Budget) -> Result<Watchdog, String> {
+        let phase = Arc::new(Mutex::new(Phase::Parse));
+
+        if budget == Budget::default() {
+            return Ok(Watchdog { phase });
+        }
+        if budget.memory.is_some() && peak_memory().is_none() {
+            return Err("Memory budgets need /proc/self/status to measure memory".to_string());
+        }
+
+        let start = Instant::now();
+        let check = move || budget.check(start.elapsed(), peak_memory());
+
+        // Checked once up front, so even solvers that finish before the
+        // first interval are held to the budget
+        if let Some(reason) = check() {
+            over_budget(Phase::Parse, &reason);
+        }
+
+        let current = Arc::clone(&phase);
+        thread::spawn(move || loop {
+            thread::sleep(CHECK_INTERVAL);
+
+            if let Some(reason) = check() {
+                over_budget(*current.lock().unwrap(), &reason);
+            }
+        });
+
+        Ok(Watchdog { phase })
+    }
+
+    /// Marks the start of a phase, to be named if the budget runs out.
+    pub fn enter(&self, phase: Phase) {
+        *self.phase.lock().unwrap() = phase;
+    }
+}
+
+fn over_budget(phase: Phase, reason: &str) -> ! {
+    eprintln!("Over budget in {phase}: {reason}");
+    process::exit(OVER_BUDGET_EXIT_CODE);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_sizes() {
+        assert_eq!(parse_size("4096"), Ok(4096));
+        assert_eq!(parse_size("512M"), Ok(512 << 20));
+        assert_eq!(parse_size("2GiB"), Ok(2 << 30));
+        assert_eq!(parse_size("1kb"), Ok(1024));
+        assert!(parse_size("1T").is_err());
+        assert!(parse_size("M").is_err());
+    }
+
+    #[test]
+    fn checks_time_before_memory() {
+        let budget = Budget {
+            time: Some(Duration::from_secs(10)),
+            memory: Some(1 << 30),
+        };
+
+        assert_eq!(budget.check(Duration::from_secs(1), Some(1 << 20)), None);
+        assert_eq!(
+            budget.check(Duration::from_secs(11), Some(2 << 30)),
+            Some("ran for more than 10.00s".to_string())
+        );
+        assert_eq!(
+            budget.check(Duration::from_secs(1), Some(3 << 29)),
+            Some("used 1.50GiB of memory, more than 1.00GiB".to_string())
+        );
+        assert_eq!(Budget::default().check(Duration::MAX, Some(u64::MAX)), None);
+    }
+}
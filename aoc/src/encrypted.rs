@@ -0,0 +1,174 @@
+use std::{fmt, fs, path::Path, str::FromStr};
+
+use base64::{engine::general_purpose::STANDARD, Engine};
+use chacha20poly1305::{aead::Aead, ChaCha20Poly1305, Key, KeyInit, Nonce};
+
+/// The first line of an encrypted input, followed by the base64 of the nonce
+/// and the ChaCha20-Poly1305 ciphertext.
+pub const HEADER: &str = "aoc-encrypted-input v1";
+
+const NONCE_LEN: usize = 12;
+
+/// The key inputs are encrypted with, given as 64 hex digits.
+#[derive(Clone, PartialEq, Eq)]
+pub struct InputKey([u8; 32]);
+
+impl FromStr for InputKey {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        let mut key = [0; 32];
+
+        if s.len() != 64 || !s.bytes().all(|b| b.is_ascii_hexdigit()) {
+            return Err("The input key must be 64 hex digits".to_string());
+        }
+
+        for (byte, digits) in key.iter_mut().zip(s.as_bytes().chunks(2)) {
+            let digits = std::str::from_utf8(digits).expect("Checked to be hex digits");
+            *byte = u8::from_str_radix(digits, 16).expect("Checked to be hex digits");
+        }
+
+        Ok(InputKey(key))
+    }
+}
+
+// Keeps the key out of error messages and logs
+impl fmt::Debug for InputKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "InputKey(..)")
+    }
+}
+
+pub fn is_encrypted(text: &str) -> bool {
+    text.lines().next() == Some(HEADER)
+}
+
+pub fn encrypt(key: &InputKey, input: &str) -> Result<String, String> {
+    let mut nonce = [0; NONCE_LEN];
+    getrandom::getrandom(&mut nonce).map_err(|err| format!("No random nonce: {err}"))?;
+
+    let ciphertext = ChaCha20Poly1305::new(Key::from_slice(&key.0))
+        .encrypt(Nonce::from_slice(&nonce), input.as_bytes())
+        .map_err(|_| "Encryption failed".to_string())?;
+
+    let encoded = STANDARD.encode([&nonce[..], &ciphertext].concat());
+
+    let mut text = format!("{HEADER}\n");
+    for line in encoded.as_bytes().chunks(76) {
+        text += std::str::from_utf8(line).expect("base64 is ASCII");
+        text.push('\n');
+    }
+
+    Ok(text)
+}
+
+pub fn decrypt(key: &InputKey, text: &str) -> Result<String, String> {
+    let Some(encoded) = text.strip_prefix(HEADER) else {
+        return Err("Not an encrypted input".to_string());
+    };
+
+    let data = STANDARD
+        .decode(encoded.split_whitespace().collect::<String>())
+        .map_err(|_| "Malformed encrypted input".to_string())?;
+
+    if data.len() < NONCE_LEN {
+        return Err("Malformed encrypted input".to_string());
+    }
+    let (nonce, ciphertext) = data.split_at(NONCE_LEN);
+
+    let plain = ChaCha20Poly1305::new(Key::from_slice(&key.0))
+        .decrypt(Nonce::from_slice(nonce), ciphertext)
+        .map_err(|_| "Can't decrypt, the input key is wrong or the file is damaged".to_string())?;
+
+    String::from_utf8(plain).map_err(|_| "Decrypted input isn't UTF-8".to_string())
+}
+
+/// Encrypts the file in place. Returns `false` if it already was encrypted.
+pub fn encrypt_file(key: &InputKey, path: &Path) -> Result<bool, String> {
+    let text = read(path)?;
+
+    if is_encrypted(&text) {
+        return Ok(false);
+    }
+
+    write(path, &encrypt(key, &text)?)?;
+
+    Ok(true)
+}
+
+/// Decrypts the file in place. Returns `false` if it wasn't encrypted.
+pub fn decrypt_file(key: &InputKey, path: &Path) -> Result<bool, String> {
+    let text = read(path)?;
+
+    if !is_encrypted(&text) {
+        return Ok(false);
+    }
+
+    let input = decrypt(key, &text).map_err(|err| format!("{}: {err}", path.display()))?;
+    write(path, &input)?;
+
+    Ok(true)
+}
+
+fn read(path: &Path) -> Result<String, String> {
+    fs::read_to_string(path).map_err(|err| format!("Error opening {}: {err}", path.display()))
+}
+
+/// Replaces the file at `path` with `text`, which only happens once it has
+/// been written in full.
+fn write(path: &Path, text: &str) -> Result<(), String> {
+    // Writing in place could cut the file short and lose the only copy
+    let partial = path.with_extension("partial");
+    fs::write(&partial, text)
+        .and_then(|()| fs::rename(&partial, path))
+        .map_err(|err| format!("Error writing {}: {err}", path.display()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const KEY: &str = "000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f";
+
+    #[test]
+    fn decrypts_what_it_encrypted() {
+        let key = KEY.parse().unwrap();
+        let input = "3   4\n4   3\n".repeat(20);
+
+        let encrypted = encrypt(&key, &input).unwrap();
+
+        assert!(is_encrypted(&encrypted));
+        assert!(!encrypted.contains("3   4"));
+        assert!(encrypted.lines().all(|line| line.len() <= 76));
+        assert_eq!(decrypt(&key, &encrypted).unwrap(), input);
+
+        // A fresh nonce every time
+        assert_ne!(encrypt(&key, &input).unwrap(), encrypted);
+    }
+
+    #[test]
+    fn rejects_the_wrong_key() {
+        let encrypted = encrypt(&KEY.parse().unwrap(), "1 2 3\n").unwrap();
+        let other_key = KEY.replace("00", "ff").parse().unwrap();
+
+        assert_eq!(
+            decrypt(&other_key, &encrypted),
+            Err("Can't decrypt, the input key is wrong or the file is damaged".to_string())
+        );
+        assert!(decrypt(&other_key, &format!("{HEADER}\nAAAA\n")).is_err());
+        assert!(decrypt(&other_key, "1 2 3\n").is_err());
+    }
+
+    #[test]
+    fn parses_hex_keys() {
+        assert!(KEY.parse::<InputKey>().is_ok());
+        assert!(KEY.to_uppercase().parse::<InputKey>().is_ok());
+        assert!(KEY[2..].parse::<InputKey>().is_err());
+        assert!(KEY.replace('0', "g").parse::<InputKey>().is_err());
+        assert_eq!(
+            format!("{:?}", KEY.parse::<InputKey>().unwrap()),
+            "InputKey(..)"
+        );
+    }
+}
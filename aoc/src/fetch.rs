@@ -0,0 +1,157 @@
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+    thread,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+pub const BASE_URL: &str = "https://adventofcode.com";
+/// Overrides [`BASE_URL`], e.g. to point `aoc fetch` at a local server.
+pub const BASE_URL_VAR: &str = "AOC_BASE_URL";
+/// The least time between two requests to the site, across all `aoc` runs.
+pub const REQUEST_INTERVAL: Duration = Duration::from_secs(5);
+
+const USER_AGENT: &str = concat!(
+    "aoc/",
+    env!("CARGO_PKG_VERSION"),
+    " (inputs cached locally)"
+);
+
+/// Spaces out requests by remembering when the last one was made in a file,
+/// so separate `aoc` runs share the limit.
+#[derive(Debug, Clone)]
+pub struct RateLimit {
+    pub path: PathBuf,
+    pub interval: Duration,
+}
+
+impl RateLimit {
+    /// Sleeps until `interval` has passed since the last request and records
+    /// the one about to be made.
+    pub fn wait(&self) -> Result<(), String> {
+        let now = SystemTime::now();
+
+        let last = fs::read_to_string(&self.path)
+            .ok()
+            .and_then(|text| text.trim().parse::<u64>().ok())
+            .map(|nanos| UNIX_EPOCH + Duration::from_nanos(nanos));
+
+        if let Some(elapsed) = last.and_then(|last| now.duration_since(last).ok()) {
+            thread::sleep(self.interval.saturating_sub(elapsed));
+        }
+
+        if let Some(dir) = self.path.parent() {
+            fs::create_dir_all(dir)
+                .map_err(|err| format!("Error creating {}: {err}", dir.display()))?;
+        }
+
+        let nanos = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_nanos();
+
+        fs::write(&self.path, nanos.to_string())
+            .map_err(|err| format!("Error writing {}: {err}", self.path.display()))
+    }
+}
+
+pub struct Client {
+    agent: ureq::Agent,
+    base_url: String,
+    session: String,
+    rate_limit: RateLimit,
+}
+
+impl Client {
+    pub fn new(base_url: &str, session: &str, rate_limit: RateLimit) -> Client {
+        Client {
+            agent: ureq::AgentBuilder::new()
+                .user_agent(USER_AGENT)
+                .timeout(Duration::from_secs(30))
+                .build(),
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: session.to_string(),
+            rate_limit,
+        }
+    }
+
+    /// Downloads the puzzle input of the given day.
+    pub fn input(&self, year: u32, day: u32) -> Result<String, String> {
+        self.rate_limit.wait()?;
+
+        let url = format!("{}/{year}/day/{day}/input", self.base_url);
+        let response = self.request("GET", &url).call();
+
+        read_response(&url, year, day, response)
+    }
+
+    /// Posts an answer for the given part and returns the page describing
+    /// whether it was right.
+    pub fn answer(&self, year: u32, day: u32, part: u32, answer: &str) -> Result<String, String> {
+        self.rate_limit.wait()?;
+
+        let url = format!("{}/{year}/day/{day}/answer", self.base_url);
+        let response = self
+            .request("POST", &url)
+            .send_form(&[("level", &part.to_string()), ("answer", answer)]);
+
+        read_response(&url, year, day, response)
+    }
+
+    fn request(&self, method: &str, url: &str) -> ureq::Request {
+        self.agent
+            .request(method, url)
+            .set("Cookie", &format!("session={}", self.session))
+    }
+}
+
+fn read_response(
+    url: &str,
+    year: u32,
+    day: u32,
+    response: Result<ureq::Response, ureq::Error>,
+) -> Result<String, String> {
+    match response {
+        Ok(response) => response
+            .into_string()
+            .map_err(|err| format!("Error reading {url}: {err}")),
+        Err(ureq::Error::Status(404, _)) => Err(format!("{year} day {day} isn't unlocked yet")),
+        Err(ureq::Error::Status(400 | 401 | 403, _)) => Err(
+            "The session token was rejected, it has probably expired. Log in again and \
+             update AOC_SESSION or `session` in ~/.aoc/config.toml"
+                .to_string(),
+        ),
+        Err(ureq::Error::Status(status, _)) => {
+            Err(format!("Unexpected status {status} from {url}"))
+        }
+        Err(ureq::Error::Transport(err)) => Err(format!("Error requesting {url}: {err}")),
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Fetched {
+    Downloaded,
+    Cached,
+}
+
+/// Downloads the input of the given day to `path`, unless it's already there.
+pub fn fetch_input(client: &Client, path: &Path, year: u32, day: u32) -> Result<Fetched, String> {
+    if path.exists() {
+        return Ok(Fetched::Cached);
+    }
+
+    let input = client.input(year, day)?;
+
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)
+            .map_err(|err| format!("Error creating {}: {err}", dir.display()))?;
+    }
+
+    // A partially written file would be taken for the whole input next time
+    let partial = path.with_extension("partial");
+    fs::write(&partial, input)
+        .and_then(|()| fs::rename(&partial, path))
+        .map_err(|err: io::Error| format!("Error writing {}: {err}", path.display()))?;
+
+    Ok(Fetched::Downloaded)
+}
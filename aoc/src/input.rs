@@ -0,0 +1,218 @@
+use std::{
+    env, fmt, fs,
+    io::{self, IsTerminal, Read},
+    path::{Path, PathBuf},
+};
+
+use serde::Deserialize;
+
+use crate::encrypted::{self, InputKey};
+
+/// Overrides the `input_root` of the config file.
+pub const INPUT_ROOT_VAR: &str = "AOC_INPUT_ROOT";
+/// Overrides the `session` of the config file.
+pub const SESSION_VAR: &str = "AOC_SESSION";
+/// Overrides the `input_key` of the config file.
+pub const INPUT_KEY_VAR: &str = "AOC_INPUT_KEY";
+
+/// Settings read from `~/.aoc/config.toml`.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Config {
+    /// Directory holding inputs as `<year>/<day>.txt`, e.g. `2023/05.txt`.
+    pub input_root: Option<PathBuf>,
+    /// The `session` cookie of a logged in adventofcode.com browser.
+    pub session: Option<String>,
+    /// 64 hex digits to encrypt and decrypt inputs with.
+    pub input_key: Option<String>,
+}
+
+impl Config {
+    pub fn parse(text: &str) -> Result<Config, String> {
+        toml::from_str(text).map_err(|err| err.message().to_string())
+    }
+}
+
+fn home_dir() -> Option<PathBuf> {
+    env::var_os("HOME")
+        .or_else(|| env::var_os("USERPROFILE"))
+        .filter(|home| !home.is_empty())
+        .map(PathBuf::from)
+}
+
+/// `~/.aoc`, holding the config file and the state of `aoc fetch`.
+pub fn aoc_dir() -> Option<PathBuf> {
+    home_dir().map(|home| home.join(".aoc"))
+}
+
+pub fn config_path() -> Option<PathBuf> {
+    aoc_dir().map(|dir| dir.join("config.toml"))
+}
+
+/// Replaces a leading `~` with the home directory.
+pub fn expand_home(path: &Path) -> PathBuf {
+    match (path.strip_prefix("~"), home_dir()) {
+        (Ok(rest), Some(home)) => home.join(rest),
+        _ => path.to_path_buf(),
+    }
+}
+
+fn load_config() -> Result<Config, String> {
+    let Some(path) = config_path().filter(|path| path.exists()) else {
+        return Ok(Config::default());
+    };
+
+    let text = fs::read_to_string(&path)
+        .map_err(|err| format!("Error opening {}: {err}", path.display()))?;
+
+    Config::parse(&text).map_err(|err| format!("Malformed config {}: {err}", path.display()))
+}
+
+/// The configured input root from `AOC_INPUT_ROOT` or the config file, if any.
+pub fn input_root() -> Result<Option<PathBuf>, String> {
+    if let Some(root) = env::var_os(INPUT_ROOT_VAR).filter(|root| !root.is_empty()) {
+        return Ok(Some(expand_home(Path::new(&root))));
+    }
+
+    Ok(load_config()?.input_root.as_deref().map(expand_home))
+}
+
+/// The session token from `AOC_SESSION` or the config file.
+pub fn session() -> Result<String, String> {
+    if let Some(session) = env::var(SESSION_VAR).ok().filter(|s| !s.is_empty()) {
+        return Ok(session);
+    }
+
+    load_config()?.session.ok_or_else(|| {
+        format!("No session token, set {SESSION_VAR} or `session` in ~/.aoc/config.toml")
+    })
+}
+
+/// The input key from `AOC_INPUT_KEY` or the config file.
+pub fn input_key() -> Result<InputKey, String> {
+    let key = match env::var(INPUT_KEY_VAR).ok().filter(|key| !key.is_empty()) {
+        Some(key) => key,
+        None => load_config()?.input_key.ok_or_else(|| {
+            format!("No input key, set {INPUT_KEY_VAR} or `input_key` in ~/.aoc/config.toml")
+        })?,
+    };
+
+    key.parse()
+}
+
+/// Decrypts `text` if it is an encrypted input, or returns it as it is.
+pub fn decrypt_if_encrypted(text: String) -> Result<String, String> {
+    if encrypted::is_encrypted(&text) {
+        encrypted::decrypt(&input_key()?, &text)
+    } else {
+        Ok(text)
+    }
+}
+
+/// Where the input of the given day lives below an input root.
+pub fn input_path_in(root: &Path, year: u32, day: u32) -> PathBuf {
+    root.join(year.to_string()).join(format!("{day:02}.txt"))
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputSource {
+    Stdin,
+    File(PathBuf),
+}
+
+impl InputSource {
+    /// `-` stands for stdin, anything else is a file path.
+    pub fn from_arg(arg: &str) -> InputSource {
+        if arg == "-" {
+            InputSource::Stdin
+        } else {
+            InputSource::File(PathBuf::from(arg))
+        }
+    }
+
+    pub fn read(&self) -> Result<String, String> {
+        match self {
+            InputSource::Stdin => {
+                let mut input = String::new();
+
+                io::stdin()
+                    .read_to_string(&mut input)
+                    .map_err(|err| format!("Error reading stdin: {err}"))?;
+
+                decrypt_if_encrypted(input)
+            }
+            InputSource::File(path) => {
+                let text = fs::read_to_string(path)
+                    .map_err(|err| format!("Error opening input file {}: {err}", path.display()))?;
+
+                decrypt_if_encrypted(text).map_err(|err| format!("{}: {err}", path.display()))
+            }
+        }
+    }
+}
+
+impl fmt::Display for InputSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InputSource::Stdin => write!(f, "<stdin>"),
+            InputSource::File(path) => write!(f, "{}", path.display()),
+        }
+    }
+}
+
+/// Reads input piped into the process. Returns `None` when stdin is a terminal
+/// or nothing was piped, so the caller can fall back to the default input.
+pub fn read_piped_stdin() -> Result<Option<String>, String> {
+    if io::stdin().is_terminal() {
+        return Ok(None);
+    }
+
+    let input = InputSource::Stdin.read()?;
+
+    Ok(Some(input).filter(|input| !input.is_empty()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn inputs_are_named_by_zero_padded_day() {
+        assert_eq!(
+            input_path_in(Path::new("/inputs"), 2023, 5),
+            Path::new("/inputs/2023/05.txt")
+        );
+        assert_eq!(
+            input_path_in(Path::new("/inputs"), 2024, 11),
+            Path::new("/inputs/2024/11.txt")
+        );
+    }
+
+    #[test]
+    fn parses_the_config_file() {
+        assert_eq!(
+            Config::parse("input_root = \"~/.aoc/inputs\"\n").unwrap(),
+            Config {
+                input_root: Some(PathBuf::from("~/.aoc/inputs")),
+                session: None,
+                input_key: None,
+            }
+        );
+        assert_eq!(
+            Config::parse("session = \"53616c74\"\n").unwrap().session,
+            Some("53616c74".to_string())
+        );
+        assert_eq!(Config::parse("").unwrap(), Config::default());
+        assert!(Config::parse("input_rot = \"x\"").is_err());
+    }
+
+    #[test]
+    fn dash_means_stdin() {
+        assert_eq!(InputSource::from_arg("-"), InputSource::Stdin);
+        assert_eq!(
+            InputSource::from_arg("in.txt"),
+            InputSource::File(PathBuf::from("in.txt"))
+        );
+        assert_eq!(InputSource::Stdin.to_string(), "<stdin>");
+    }
+}
@@ -0,0 +1,48 @@
+pub mod answers;
+pub mod bench;
+pub mod budget;
+pub mod encrypted;
+pub mod fetch;
+pub mod input;
+pub mod report;
+pub mod run_all;
+pub mod scaffold;
+pub mod submit;
+
+use std::path::PathBuf;
+
+use aoc_common::Solver;
+
+pub const YEARS: [u32; 2] = [2023, 2024];
+
+/// Returns the solution for the given year and day, if there is one.
+pub fn solution(year: u32, day: u32) -> Option<&'static dyn Solver> {
+    match year {
+        2023 => aoc_2023::solution(day),
+        2024 => aoc_2024::solution(day),
+        _ => None,
+    }
+}
+
+/// All solutions of the given year as `(day, solution)`, ordered by day.
+pub fn solutions(year: u32) -> impl Iterator<Item = (u32, &'static dyn Solver)> {
+    (1..=25).filter_map(move |day| solution(year, day).map(|s| (day, s)))
+}
+
+/// The input file below the configured input root, or the committed one if
+/// there is no root.
+pub fn default_input_path(year: u32, day: u32) -> Result<PathBuf, String> {
+    Ok(match input::input_root()? {
+        Some(root) => input::input_path_in(&root, year, day),
+        None => committed_input_path(year, day),
+    })
+}
+
+/// The `input` file committed next to the day's solution.
+pub fn committed_input_path(year: u32, day: u32) -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("..")
+        .join(year.to_string())
+        .join(format!("day{day}"))
+        .join("input")
+}
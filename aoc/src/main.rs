@@ -0,0 +1,705 @@
+use std::{
+    env,
+    fs::File,
+    io::{self, IsTerminal},
+    path::{Path, PathBuf},
+    process,
+    sync::Mutex,
+    thread,
+    time::Duration,
+};
+
+use aoc::{
+    answers::{load_answers, verify},
+    bench::{
+        bench_day, default_history_path, format_change, format_ns, load_history, previous_result,
+        save_history, BenchRun,
+    },
+    budget::{parse_size, Budget, Watchdog},
+    committed_input_path, default_input_path,
+    encrypted::{decrypt_file, encrypt_file, InputKey},
+    fetch::{fetch_input, Client, Fetched, RateLimit, BASE_URL, BASE_URL_VAR, REQUEST_INTERVAL},
+    input::{aoc_dir, input_key, input_root, read_piped_stdin, session, InputSource},
+    report::{Format, RunReport},
+    run_all::{format_table, run_all, Job},
+    scaffold::{new_day, workspace_root},
+    solution, solutions,
+    submit::{default_log_path, submit as submit_answer, Verdict},
+    YEARS,
+};
+use aoc_common::{Rng, Size};
+use tracing::level_filters::LevelFilter;
+use tracing_subscriber::fmt::format::FmtSpan;
+
+const USAGE: &str = "Usage:
+    aoc run <year> <day> <part> [input | -] [--format text|json]
+            [--time-limit <secs>] [--memory-limit <size>]
+    aoc run --all [--year <year>] [--timeout <secs>] [--memory-limit <size>] [--jobs <n>]
+    aoc verify [year] [day]
+    aoc bench [year] [day] [--iterations <n>] [--history <file>] [--all-parts]
+    aoc new <year> <day>
+    aoc fetch <year> <day>
+    aoc submit <year> <day> <part> [input | -]
+    aoc gen <year> <day> [--seed <n>] [--size small|medium|large]
+    aoc inputs encrypt|decrypt [year] [day]
+
+Every command also takes --verbose, --trace and --trace-file <file>";
+
+fn main() {
+    let mut args = env::args().skip(1).collect::<Vec<_>>();
+
+    init_tracing(&mut args);
+
+    match args.first().map(String::as_str) {
+        Some("run") => run(&args[1..]),
+        Some("verify") => verify_answers(&args[1..]),
+        Some("bench") => bench(&args[1..]),
+        Some("new") => new(&args[1..]),
+        Some("fetch") => fetch(&args[1..]),
+        Some("submit") => submit(&args[1..]),
+        Some("gen") => generate(&args[1..]),
+        Some("inputs") => inputs(&args[1..]),
+        _ => usage(),
+    }
+}
+
+/// Sets up logging from the `--verbose`, `--trace` and `--trace-file <file>`
+/// flags, which may appear anywhere, and removes them from `args`. Logs go to
+/// stderr or the trace file so stdout only carries answers.
+fn init_tracing(args: &mut Vec<String>) {
+    let mut level = None;
+    let mut trace_file = None;
+
+    let mut i = 0;
+
+    while i < args.len() {
+        match args[i].as_str() {
+            "--verbose" => level = level.max(Some(LevelFilter::DEBUG)),
+            "--trace" => level = Some(LevelFilter::TRACE),
+            "--trace-file" if i + 1 < args.len() => {
+                trace_file = Some(PathBuf::from(args.remove(i + 1)));
+            }
+            "--trace-file" => usage(),
+            _ => {
+                i += 1;
+                continue;
+            }
+        }
+
+        args.remove(i);
+    }
+
+    let level = match (level, &trace_file) {
+        (Some(level), _) => level,
+        (None, Some(_)) => LevelFilter::TRACE,
+        (None, None) => LevelFilter::WARN,
+    };
+
+    let subscriber = tracing_subscriber::fmt()
+        .with_max_level(level)
+        .with_span_events(FmtSpan::CLOSE)
+        .with_target(false);
+
+    match trace_file {
+        Some(path) => match File::create(&path) {
+            Ok(file) => subscriber
+                .with_ansi(false)
+                .with_writer(Mutex::new(file))
+                .init(),
+            Err(err) => {
+                eprintln!("Error creating {}: {err}", path.display());
+                process::exit(1);
+            }
+        },
+        None => subscriber
+            .with_ansi(io::stderr().is_terminal())
+            .with_writer(io::stderr)
+            .init(),
+    }
+}
+
+fn usage() -> ! {
+    eprintln!("{USAGE}");
+    process::exit(2);
+}
+
+fn run(args: &[String]) {
+    if args.iter().any(|a| a == "--all") {
+        run_all_parts(args);
+        return;
+    }
+
+    let mut positional = Vec::new();
+    let mut format = Format::Text;
+    let mut budget = Budget::default();
+
+    let mut args = args.iter();
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--format" => match args.next().map(|f| f.parse()) {
+                Some(Ok(f)) => format = f,
+                Some(Err(err)) => {
+                    eprintln!("{err}");
+                    usage();
+                }
+                None => usage(),
+            },
+            "--time-limit" => budget.time = Some(parse_secs(args.next())),
+            "--memory-limit" => budget.memory = Some(parse_memory(args.next())),
+            _ => positional.push(arg),
+        }
+    }
+
+    let (Some(year), Some(day), Some(part)) = (
+        positional.first().and_then(|a| a.parse::<u32>().ok()),
+        positional.get(1).and_then(|a| a.parse::<u32>().ok()),
+        positional.get(2).and_then(|a| a.parse::<u32>().ok()),
+    ) else {
+        usage();
+    };
+
+    if positional.len() > 4 {
+        usage();
+    }
+
+    let Some(solution) = solution(year, day).filter(|s| (1..=s.parts()).contains(&part)) else {
+        eprintln!("No solution for {year} day {day} part {part}");
+        process::exit(1);
+    };
+
+    let (source, input) = match read_run_input(positional.get(3).copied(), year, day) {
+        Ok(source_and_input) => source_and_input,
+        Err(err) => {
+            eprintln!("{err}");
+            process::exit(1);
+        }
+    };
+
+    let watchdog = match Watchdog::start(budget) {
+        Ok(watchdog) => watchdog,
+        Err(err) => {
+            eprintln!("{err}");
+            process::exit(1);
+        }
+    };
+
+    let report = match RunReport::run_with(year, day, part, solution, &input, |phase| {
+        watchdog.enter(phase)
+    }) {
+        Ok(report) => report,
+        Err(err) => {
+            eprintln!("Error parsing {source}: {err}");
+            process::exit(1);
+        }
+    };
+
+    match format {
+        Format::Text => println!("{}", report.answer_text()),
+        Format::Json => println!(
+            "{}",
+            serde_json::to_string(&report).expect("Report serializes")
+        ),
+    }
+}
+
+fn run_all_parts(args: &[String]) {
+    let mut year = None;
+    let mut budget = Budget {
+        time: Some(Duration::from_secs(30)),
+        memory: None,
+    };
+    let mut workers = thread::available_parallelism().map_or(1, |n| n.get());
+
+    let mut args = args.iter();
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--all" => {}
+            "--year" => match args.next().and_then(|y| y.parse::<u32>().ok()) {
+                Some(y) => year = Some(y),
+                None => usage(),
+            },
+            "--timeout" => budget.time = Some(parse_secs(args.next())),
+            "--memory-limit" => budget.memory = Some(parse_memory(args.next())),
+            "--jobs" => match args.next().and_then(|n| n.parse::<usize>().ok()) {
+                Some(n) if n > 0 => workers = n,
+                _ => usage(),
+            },
+            _ => usage(),
+        }
+    }
+
+    let setup = (|| {
+        // The known answers are for the committed inputs only
+        let expected = match input_root()? {
+            Some(_) => Vec::new(),
+            None => load_answers()?,
+        };
+        let exe = env::current_exe().map_err(|err| format!("Can't find the aoc binary: {err}"))?;
+
+        let mut jobs = Vec::new();
+
+        for year in YEARS
+            .into_iter()
+            .filter(|y| year.is_none_or(|year| year == *y))
+        {
+            for (day, solution) in solutions(year) {
+                let input = default_input_path(year, day)?;
+
+                jobs.extend((1..=solution.parts()).map(|part| Job {
+                    year,
+                    day,
+                    part,
+                    input: input.clone(),
+                }));
+            }
+        }
+
+        Ok::<_, String>((expected, exe, jobs))
+    })();
+
+    let (expected, exe, jobs) = match setup {
+        Ok(setup) => setup,
+        Err(err) => {
+            eprintln!("{err}");
+            process::exit(1);
+        }
+    };
+
+    let results = run_all(&exe, jobs, &expected, budget, workers);
+
+    print!("{}", format_table(&results));
+
+    if results.iter().any(|r| r.is_failure(&expected)) {
+        process::exit(1);
+    }
+}
+
+fn parse_secs(arg: Option<&String>) -> Duration {
+    match arg.and_then(|secs| secs.parse::<f64>().ok()) {
+        Some(secs) if secs > 0.0 && secs.is_finite() => Duration::from_secs_f64(secs),
+        _ => usage(),
+    }
+}
+
+fn parse_memory(arg: Option<&String>) -> u64 {
+    match arg.map(|size| parse_size(size)) {
+        Some(Ok(bytes)) => bytes,
+        Some(Err(err)) => {
+            eprintln!("{err}");
+            usage();
+        }
+        None => usage(),
+    }
+}
+
+/// Reads the input named on the command line, else anything piped into stdin,
+/// else the day's default input.
+fn read_run_input(
+    arg: Option<&String>,
+    year: u32,
+    day: u32,
+) -> Result<(InputSource, String), String> {
+    let source = match arg {
+        Some(arg) => InputSource::from_arg(arg),
+        None => match read_piped_stdin()? {
+            Some(input) => return Ok((InputSource::Stdin, input)),
+            None => InputSource::File(default_input_path(year, day)?),
+        },
+    };
+
+    let input = source.read()?;
+
+    Ok((source, input))
+}
+
+fn verify_answers(args: &[String]) {
+    let year = args.first().map(|a| a.parse::<u32>());
+    let day = args.get(1).map(|a| a.parse::<u32>());
+
+    let (Ok(year), Ok(day)) = (year.transpose(), day.transpose()) else {
+        usage();
+    };
+
+    let answers = match load_answers() {
+        Ok(answers) => answers,
+        Err(err) => {
+            eprintln!("{err}");
+            process::exit(1);
+        }
+    };
+
+    let mut failures = 0;
+
+    for expected in answers
+        .iter()
+        .filter(|a| year.is_none_or(|y| a.year == y) && day.is_none_or(|d| a.day == d))
+    {
+        match verify(expected) {
+            Ok(()) => println!(
+                "{} day {} part {}: ok",
+                expected.year, expected.day, expected.part
+            ),
+            Err(err) => {
+                println!("{err}");
+                failures += 1;
+            }
+        }
+    }
+
+    if failures > 0 {
+        println!("{failures} answer(s) didn't match");
+        process::exit(1);
+    }
+}
+
+fn bench(args: &[String]) {
+    let mut positional = Vec::new();
+    let mut iterations = 10;
+    let mut history_path = default_history_path();
+    let mut all_parts = false;
+
+    let mut args = args.iter();
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--iterations" => match args.next().and_then(|n| n.parse().ok()) {
+                Some(n) => iterations = n,
+                None => usage(),
+            },
+            "--history" => match args.next() {
+                Some(path) => history_path = PathBuf::from(path),
+                None => usage(),
+            },
+            "--all-parts" => all_parts = true,
+            _ => match arg.parse::<u32>() {
+                Ok(n) => positional.push(n),
+                Err(_) => usage(),
+            },
+        }
+    }
+
+    let (year, day) = match positional[..] {
+        [] => (None, None),
+        [year] => (Some(year), None),
+        [year, day] => (Some(year), Some(day)),
+        _ => usage(),
+    };
+
+    let (answers, history) = match (load_answers(), load_history(&history_path)) {
+        (Ok(answers), Ok(history)) => (answers, history),
+        (Err(err), _) | (_, Err(err)) => {
+            eprintln!("{err}");
+            process::exit(1);
+        }
+    };
+
+    let mut results = Vec::new();
+
+    for year in YEARS
+        .into_iter()
+        .filter(|y| year.is_none_or(|year| year == *y))
+    {
+        for (day, solution) in solutions(year).filter(|(d, _)| day.is_none_or(|day| day == *d)) {
+            // Parts without a known answer include ones that take minutes or never finish
+            let parts = (1..=solution.parts())
+                .filter(|part| {
+                    all_parts
+                        || answers
+                            .iter()
+                            .any(|a| (a.year, a.day, a.part) == (year, day, *part))
+                })
+                .collect::<Vec<_>>();
+
+            let source = match default_input_path(year, day) {
+                Ok(path) => InputSource::File(path),
+                Err(err) => {
+                    eprintln!("{err}");
+                    process::exit(1);
+                }
+            };
+            let input = match source.read() {
+                Ok(input) => input,
+                Err(err) => {
+                    eprintln!("{err}");
+                    continue;
+                }
+            };
+
+            let day_results = match bench_day(year, day, solution, &input, &parts, iterations) {
+                Ok(day_results) => day_results,
+                Err(err) => {
+                    eprintln!("Error parsing {source}: {err}");
+                    continue;
+                }
+            };
+
+            for result in &day_results {
+                let change = previous_result(&history, result)
+                    .map(|previous| format!("  {} vs previous", format_change(previous, result)))
+                    .unwrap_or_default();
+
+                println!(
+                    "{year} day {day:>2} {:<5}  min {:>9}  median {:>9}  p95 {:>9}{change}",
+                    result.phase.to_string(),
+                    format_ns(result.min_ns),
+                    format_ns(result.median_ns),
+                    format_ns(result.p95_ns),
+                );
+            }
+
+            for part in (1..=solution.parts()).filter(|p| !parts.contains(p)) {
+                println!(
+                    "{year} day {day:>2} part{part}  skipped, no known answer (use --all-parts)"
+                );
+            }
+
+            results.extend(day_results);
+        }
+    }
+
+    if results.is_empty() {
+        return;
+    }
+
+    let mut history = history;
+    history.push(BenchRun::new(iterations, results));
+
+    if let Err(err) = save_history(&history_path, &history) {
+        eprintln!("{err}");
+        process::exit(1);
+    }
+}
+
+fn new(args: &[String]) {
+    let [year, day] = args else {
+        usage();
+    };
+    let (Ok(year), Ok(day)) = (year.parse::<u32>(), day.parse::<u32>()) else {
+        usage();
+    };
+
+    match new_day(&workspace_root(), year, day) {
+        Ok(paths) => {
+            for path in paths {
+                println!("{}", path.display());
+            }
+        }
+        Err(err) => {
+            eprintln!("{err}");
+            process::exit(1);
+        }
+    }
+}
+
+fn fetch(args: &[String]) {
+    let [year, day] = args else {
+        usage();
+    };
+    let (Ok(year), Ok(day)) = (year.parse::<u32>(), day.parse::<u32>()) else {
+        usage();
+    };
+
+    let fetched = default_input_path(year, day).and_then(|path| {
+        if path.exists() {
+            return Ok((path, Fetched::Cached));
+        }
+
+        fetch_input(&client()?, &path, year, day).map(|fetched| (path, fetched))
+    });
+
+    match fetched {
+        Ok((path, Fetched::Downloaded)) => println!("Downloaded {}", path.display()),
+        Ok((path, Fetched::Cached)) => println!("Already have {}", path.display()),
+        Err(err) => {
+            eprintln!("{err}");
+            process::exit(1);
+        }
+    }
+}
+
+fn submit(args: &[String]) {
+    let (Some(year), Some(day), Some(part)) = (
+        args.first().and_then(|a| a.parse::<u32>().ok()),
+        args.get(1).and_then(|a| a.parse::<u32>().ok()),
+        args.get(2).and_then(|a| a.parse::<u32>().ok()),
+    ) else {
+        usage();
+    };
+
+    if args.len() > 4 {
+        usage();
+    }
+
+    let Some(solution) = solution(year, day).filter(|s| (1..=s.parts()).contains(&part)) else {
+        eprintln!("No solution for {year} day {day} part {part}");
+        process::exit(1);
+    };
+
+    let (source, input) = match read_run_input(args.get(3), year, day) {
+        Ok(source_and_input) => source_and_input,
+        Err(err) => {
+            eprintln!("{err}");
+            process::exit(1);
+        }
+    };
+
+    let answer = match solution.run(&input, part) {
+        Ok(answer) => answer,
+        Err(err) => {
+            eprintln!("Error parsing {source}: {err}");
+            process::exit(1);
+        }
+    };
+
+    println!("Submitting {answer} for {year} day {day} part {part}");
+
+    let response = default_log_path()
+        .ok_or_else(|| "No home directory to keep the submission log in".to_string())
+        .and_then(|log_path| submit_answer(&client()?, &log_path, year, day, part, &answer));
+
+    match response {
+        Ok(response) => {
+            let wait = response
+                .wait_secs
+                .map(|secs| format!(", wait {secs}s before the next answer"))
+                .unwrap_or_default();
+
+            println!("{}{wait}", response.verdict);
+
+            if response.verdict != Verdict::Correct {
+                process::exit(1);
+            }
+        }
+        Err(err) => {
+            eprintln!("{err}");
+            process::exit(1);
+        }
+    }
+}
+
+/// Prints a generated input for a day, for a given seed and size.
+fn generate(args: &[String]) {
+    let mut positional = Vec::new();
+    let mut seed = 0;
+    let mut size = Size::default();
+
+    let mut args = args.iter();
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--seed" => match args.next().and_then(|n| n.parse::<u64>().ok()) {
+                Some(n) => seed = n,
+                None => usage(),
+            },
+            "--size" => match args.next().map(|s| s.parse()) {
+                Some(Ok(s)) => size = s,
+                Some(Err(err)) => {
+                    eprintln!("{err}");
+                    usage();
+                }
+                None => usage(),
+            },
+            _ => positional.push(arg),
+        }
+    }
+
+    let [year, day] = positional[..] else {
+        usage();
+    };
+    let (Ok(year), Ok(day)) = (year.parse::<u32>(), day.parse::<u32>()) else {
+        usage();
+    };
+
+    let Some(solution) = solution(year, day) else {
+        eprintln!("No solution for {year} day {day}");
+        process::exit(1);
+    };
+
+    match solution.generate(&mut Rng::new(seed), size) {
+        Some(input) => print!("{input}"),
+        None => {
+            eprintln!("No input generator for {year} day {day}");
+            process::exit(1);
+        }
+    }
+}
+
+/// Encrypts or decrypts the committed inputs in place, skipping the ones that
+/// already are.
+fn inputs(args: &[String]) {
+    let (encrypt, args) = match args.split_first() {
+        Some((command, args)) if command == "encrypt" => (true, args),
+        Some((command, args)) if command == "decrypt" => (false, args),
+        _ => usage(),
+    };
+
+    let year = args.first().map(|a| a.parse::<u32>());
+    let day = args.get(1).map(|a| a.parse::<u32>());
+
+    let (Ok(year), Ok(day)) = (year.transpose(), day.transpose()) else {
+        usage();
+    };
+
+    let key = match input_key() {
+        Ok(key) => key,
+        Err(err) => {
+            eprintln!("{err}");
+            process::exit(1);
+        }
+    };
+
+    let (verb, convert): (_, fn(&InputKey, &Path) -> _) = if encrypt {
+        ("Encrypted", encrypt_file)
+    } else {
+        ("Decrypted", decrypt_file)
+    };
+    let mut converted = 0;
+    let mut failures = 0;
+
+    for year in YEARS
+        .into_iter()
+        .filter(|y| year.is_none_or(|year| year == *y))
+    {
+        for (day, _) in solutions(year).filter(|(d, _)| day.is_none_or(|day| day == *d)) {
+            let path = committed_input_path(year, day);
+
+            if !path.exists() {
+                continue;
+            }
+
+            match convert(&key, &path) {
+                Ok(true) => {
+                    println!("{verb} {year} day {day}");
+                    converted += 1;
+                }
+                Ok(false) => {}
+                Err(err) => {
+                    eprintln!("{err}");
+                    failures += 1;
+                }
+            }
+        }
+    }
+
+    println!("{verb} {converted} input(s)");
+
+    if failures > 0 {
+        process::exit(1);
+    }
+}
+
+/// A client for adventofcode.com, or the server in `AOC_BASE_URL`.
+fn client() -> Result<Client, String> {
+    let Some(aoc_dir) = aoc_dir() else {
+        return Err("No home directory to keep the request times in".to_string());
+    };
+    let rate_limit = RateLimit {
+        path: aoc_dir.join("last-request"),
+        interval: REQUEST_INTERVAL,
+    };
+    let base_url = env::var(BASE_URL_VAR).unwrap_or_else(|_| BASE_URL.to_string());
+
+    Ok(Client::new(&base_url, &session()?, rate_limit))
+}
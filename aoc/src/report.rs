@@ -0,0 +1,148 @@
+use std::{str::FromStr, time::Instant};
+
+use aoc_common::{diagnostics, Answer, ParseError, Solver};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::bench::Phase;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    Text,
+    Json,
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            _ => Err(format!("Unknown format '{s}', expected 'text' or 'json'")),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DiagnosticReport {
+    pub name: String,
+    pub value: Value,
+}
+
+/// Everything `aoc run --format json` prints about one solved part.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RunReport {
+    pub year: u32,
+    pub day: u32,
+    pub part: u32,
+    pub answer: Value,
+    pub parse_ns: u64,
+    pub solve_ns: u64,
+    pub diagnostics: Vec<DiagnosticReport>,
+}
+
+impl RunReport {
+    /// Parses `input` and solves `part`, timing both and capturing the
+    /// diagnostics the solver records.
+    pub fn run(
+        year: u32,
+        day: u32,
+        part: u32,
+        solution: &dyn Solver,
+        input: &str,
+    ) -> Result<RunReport, ParseError> {
+        RunReport::run_with(year, day, part, solution, input, |_| {})
+    }
+
+    /// Like [`RunReport::run`], calling `enter` as each phase starts.
+    pub fn run_with(
+        year: u32,
+        day: u32,
+        part: u32,
+        solution: &dyn Solver,
+        input: &str,
+        enter: impl Fn(Phase),
+    ) -> Result<RunReport, ParseError> {
+        let _run = tracing::info_span!("run", year, day, part).entered();
+
+        enter(Phase::Parse);
+
+        let start = Instant::now();
+        let parsed = tracing::info_span!("parse").in_scope(|| solution.parse(input))?;
+        let parse_ns = start.elapsed().as_nanos() as u64;
+
+        enter(Phase::part(part));
+
+        let start = Instant::now();
+        let (answer, diagnostics) = tracing::info_span!("solve")
+            .in_scope(|| diagnostics::capture(|| solution.solve(parsed.as_ref(), part)));
+        let solve_ns = start.elapsed().as_nanos() as u64;
+
+        Ok(RunReport {
+            year,
+            day,
+            part,
+            answer: answer_json(&answer),
+            parse_ns,
+            solve_ns,
+            diagnostics: diagnostics
+                .into_iter()
+                .map(|d| DiagnosticReport {
+                    name: d.name.to_string(),
+                    value: answer_json(&d.value),
+                })
+                .collect(),
+        })
+    }
+
+    /// The answer as `aoc run` prints it in text mode.
+    pub fn answer_text(&self) -> String {
+        match &self.answer {
+            Value::String(s) => s.clone(),
+            other => other.to_string(),
+        }
+    }
+}
+
+/// Numbers stay numbers, everything else becomes a string.
+pub fn answer_json(answer: &Answer) -> Value {
+    match answer {
+        Answer::Int(n) => Value::from(*n),
+        Answer::Str(s) => Value::from(s.as_str()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reports_answer_and_diagnostics_as_json() {
+        let solution = crate::solution(2023, 13).unwrap();
+        let input = include_str!("../../2023/day13/example");
+
+        let report = RunReport::run(2023, 13, 1, solution, input).unwrap();
+        let json = serde_json::to_value(&report).unwrap();
+
+        assert_eq!(json["year"], 2023);
+        assert_eq!(json["part"], 1);
+        assert_eq!(json["answer"], 405);
+        assert!(json["parse_ns"].is_u64() && json["solve_ns"].is_u64());
+        assert_eq!(
+            json["diagnostics"],
+            serde_json::json!([
+                { "name": "mirror_axis", "value": "Vertical 5" },
+                { "name": "mirror_axis", "value": "Horizontal 4" },
+            ])
+        );
+        assert_eq!(report.answer_text(), "405");
+    }
+
+    #[test]
+    fn parses_formats() {
+        assert_eq!("json".parse::<Format>(), Ok(Format::Json));
+        assert_eq!("text".parse::<Format>(), Ok(Format::Text));
+        assert!("yaml".parse::<Format>().is_err());
+    }
+}
@@ -0,0 +1,378 @@
+use std::{
+    io::Read,
+    path::{Path, PathBuf},
+    process::{Child, Command, ExitStatus, Stdio},
+    sync::Mutex,
+    thread,
+    time::{Duration, Instant},
+};
+
+use crate::{
+    answers::ExpectedAnswer,
+    bench::format_ns,
+    budget::{Budget, OVER_BUDGET_EXIT_CODE},
+    report::{answer_json, RunReport},
+};
+
+/// How long a solver may run past its time budget before it's killed, in
+/// case it can't stop itself.
+const KILL_GRACE: Duration = Duration::from_secs(2);
+
+/// One part to solve, in a child process of its own so it can be stopped.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Job {
+    pub year: u32,
+    pub day: u32,
+    pub part: u32,
+    pub input: PathBuf,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Status {
+    Pass,
+    Fail {
+        expected: String,
+    },
+    /// Solved, but there is no known answer to compare with.
+    Unchecked,
+    Panicked {
+        message: String,
+    },
+    /// Stopped itself for going over its time or memory budget. The message
+    /// starts with the phase it was in.
+    OverBudget {
+        message: String,
+    },
+    /// Killed for not stopping itself after its time budget.
+    TimedOut,
+    Error {
+        message: String,
+    },
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JobResult {
+    pub job: Job,
+    pub answer: Option<String>,
+    /// Parse and solve time as measured by the child, or the wall-clock time
+    /// until it was stopped.
+    pub elapsed_ns: u64,
+    pub status: Status,
+}
+
+impl JobResult {
+    /// Whether the result means something is broken. Going over budget only
+    /// counts for parts with a known answer, as the others include known slow
+    /// solutions.
+    pub fn is_failure(&self, expected: &[ExpectedAnswer]) -> bool {
+        match self.status {
+            Status::Pass | Status::Unchecked => false,
+            Status::OverBudget { .. } | Status::TimedOut => {
+                expected_answer(expected, &self.job).is_some()
+            }
+            Status::Fail { .. } | Status::Panicked { .. } | Status::Error { .. } => true,
+        }
+    }
+}
+
+fn expected_answer<'a>(expected: &'a [ExpectedAnswer], job: &Job) -> Option<&'a ExpectedAnswer> {
+    expected
+        .iter()
+        .find(|a| (a.year, a.day, a.part) == (job.year, job.day, job.part))
+}
+
+/// Runs all jobs on `workers` threads, each solving its part within `budget`
+/// with `aoc run --format json` via `exe`. Results are in the order of `jobs`.
+pub fn run_all(
+    exe: &Path,
+    jobs: Vec<Job>,
+    expected: &[ExpectedAnswer],
+    budget: Budget,
+    workers: usize,
+) -> Vec<JobResult> {
+    let queue = Mutex::new(jobs.into_iter().enumerate());
+    let results = Mutex::new(Vec::new());
+
+    thread::scope(|scope| {
+        for _ in 0..workers.max(1) {
+            scope.spawn(|| loop {
+                let Some((idx, job)) = queue.lock().unwrap().next() else {
+                    break;
+                };
+
+                let result = run_job(exe, job, expected, budget);
+                results.lock().unwrap().push((idx, result));
+            });
+        }
+    });
+
+    let mut results = results.into_inner().unwrap();
+    results.sort_by_key(|(idx, _)| *idx);
+
+    results.into_iter().map(|(_, result)| result).collect()
+}
+
+fn run_job(exe: &Path, job: Job, expected: &[ExpectedAnswer], budget: Budget) -> JobResult {
+    let start = Instant::now();
+
+    let mut command = Command::new(exe);
+    command
+        .args([
+            "run",
+            &job.year.to_string(),
+            &job.day.to_string(),
+            &job.part.to_string(),
+        ])
+        .arg(&job.input)
+        .args(["--format", "json"]);
+
+    if let Some(time) = budget.time {
+        command.args(["--time-limit", &time.as_secs_f64().to_string()]);
+    }
+    if let Some(memory) = budget.memory {
+        command.args(["--memory-limit", &memory.to_string()]);
+    }
+
+    let child = command
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn();
+
+    let outcome = match child {
+        Ok(child) => wait_with_timeout(child, budget.time.map(|time| time + KILL_GRACE)),
+        Err(err) => Err(format!("Error starting {}: {err}", exe.display())),
+    };
+    let wall_ns = start.elapsed().as_nanos() as u64;
+
+    let (status, stdout, stderr) = match outcome {
+        Ok(Some(output)) => output,
+        Ok(None) => {
+            return JobResult {
+                job,
+                answer: None,
+                elapsed_ns: wall_ns,
+                status: Status::TimedOut,
+            }
+        }
+        Err(message) => {
+            return JobResult {
+                job,
+                answer: None,
+                elapsed_ns: wall_ns,
+                status: Status::Error { message },
+            }
+        }
+    };
+
+    let report = stdout
+        .lines()
+        .last()
+        .and_then(|line| serde_json::from_str::<RunReport>(line).ok());
+
+    let (report, status) = match report {
+        Some(report) if status.success() => {
+            let status = match expected_answer(expected, &job) {
+                Some(e) if answer_json(&e.answer) == report.answer => Status::Pass,
+                Some(e) => Status::Fail {
+                    expected: e.answer.to_string(),
+                },
+                None => Status::Unchecked,
+            };
+
+            (Some(report), status)
+        }
+        _ => {
+            let message = stderr.trim().to_string();
+
+            // Panics exit with 101, everything else `aoc run` reports exits with 1
+            if status.code() == Some(OVER_BUDGET_EXIT_CODE) {
+                let message = message
+                    .strip_prefix("Over budget in ")
+                    .unwrap_or(&message)
+                    .to_string();
+
+                (None, Status::OverBudget { message })
+            } else if status.code() == Some(101) {
+                let message = message
+                    .lines()
+                    .skip_while(|line| !line.contains("panicked at"))
+                    .nth(1)
+                    .unwrap_or(&message)
+                    .to_string();
+
+                (None, Status::Panicked { message })
+            } else {
+                (None, Status::Error { message })
+            }
+        }
+    };
+
+    JobResult {
+        job,
+        answer: report.as_ref().map(RunReport::answer_text),
+        elapsed_ns: report.map_or(wall_ns, |r| r.parse_ns + r.solve_ns),
+        status,
+    }
+}
+
+/// Waits for the child to exit and returns its output, or kills it and
+/// returns `None` once `timeout` has passed.
+fn wait_with_timeout(
+    mut child: Child,
+    timeout: Option<Duration>,
+) -> Result<Option<(ExitStatus, String, String)>, String> {
+    // Read in the background so a chatty solver can't block on a full pipe
+    let read = |pipe: Option<Box<dyn Read + Send>>| {
+        thread::spawn(move || {
+            let mut text = String::new();
+            if let Some(mut pipe) = pipe {
+                let _ = pipe.read_to_string(&mut text);
+            }
+            text
+        })
+    };
+    let stdout = read(child.stdout.take().map(|p| Box::new(p) as _));
+    let stderr = read(child.stderr.take().map(|p| Box::new(p) as _));
+
+    let deadline = timeout.map(|timeout| Instant::now() + timeout);
+
+    let status = loop {
+        match child.try_wait() {
+            Ok(Some(status)) => break status,
+            Ok(None) if deadline.is_some_and(|deadline| Instant::now() >= deadline) => {
+                let _ = child.kill();
+                let _ = child.wait();
+                return Ok(None);
+            }
+            Ok(None) => thread::sleep(Duration::from_millis(5)),
+            Err(err) => return Err(format!("Error waiting for the solver: {err}")),
+        }
+    };
+
+    Ok(Some((
+        status,
+        stdout.join().unwrap_or_default(),
+        stderr.join().unwrap_or_default(),
+    )))
+}
+
+/// Formats the results as a table with one row per part and a closing count.
+pub fn format_table(results: &[JobResult]) -> String {
+    let answer_width = results
+        .iter()
+        .filter_map(|r| r.answer.as_ref().map(|a| a.chars().count()))
+        .max()
+        .unwrap_or(0)
+        .max("answer".len());
+
+    let mut table = format!(
+        "year  day  part  {:<answer_width$}  {:>9}  status\n",
+        "answer", "time"
+    );
+
+    for result in results {
+        let Job {
+            year, day, part, ..
+        } = result.job;
+
+        let status = match &result.status {
+            Status::Pass => "pass".to_string(),
+            Status::Fail { expected } => format!("FAIL, expected {expected}"),
+            Status::Unchecked => "no known answer".to_string(),
+            Status::Panicked { message } => format!("PANICKED: {message}"),
+            Status::OverBudget { message } => format!("OVER BUDGET in {message}"),
+            Status::TimedOut => "TIMED OUT".to_string(),
+            Status::Error { message } => format!("ERROR: {message}"),
+        };
+
+        table += &format!(
+            "{year}  {day:>3}  {part:>4}  {:<answer_width$}  {:>9}  {status}\n",
+            result.answer.as_deref().unwrap_or("-"),
+            format_ns(result.elapsed_ns),
+        );
+    }
+
+    let count = |f: fn(&Status) -> bool| results.iter().filter(|r| f(&r.status)).count();
+
+    table += &format!(
+        "{} passed, {} failed, {} unchecked, {} panicked, {} over budget, {} errors\n",
+        count(|s| *s == Status::Pass),
+        count(|s| matches!(s, Status::Fail { .. })),
+        count(|s| *s == Status::Unchecked),
+        count(|s| matches!(s, Status::Panicked { .. })),
+        count(|s| matches!(s, Status::OverBudget { .. } | Status::TimedOut)),
+        count(|s| matches!(s, Status::Error { .. })),
+    );
+
+    table
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::Answer;
+
+    use super::*;
+
+    fn result(day: u32, answer: Option<&str>, status: Status) -> JobResult {
+        JobResult {
+            job: Job {
+                year: 2023,
+                day,
+                part: 1,
+                input: PathBuf::from("input"),
+            },
+            answer: answer.map(str::to_string),
+            elapsed_ns: 1_500_000,
+            status,
+        }
+    }
+
+    #[test]
+    fn formats_a_table_with_totals() {
+        let results = [
+            result(1, Some("53334"), Status::Pass),
+            result(
+                2,
+                Some("1"),
+                Status::Fail {
+                    expected: "2879".to_string(),
+                },
+            ),
+            result(12, None, Status::TimedOut),
+        ];
+
+        assert_eq!(
+            format_table(&results),
+            "\
+year  day  part  answer       time  status
+2023    1     1  53334      1.50ms  pass
+2023    2     1  1          1.50ms  FAIL, expected 2879
+2023   12     1  -          1.50ms  TIMED OUT
+1 passed, 1 failed, 0 unchecked, 0 panicked, 1 over budget, 0 errors
+"
+        );
+    }
+
+    #[test]
+    fn timeouts_fail_only_parts_with_known_answers() {
+        let expected = [ExpectedAnswer {
+            year: 2023,
+            day: 1,
+            part: 1,
+            answer: Answer::Int(53334),
+        }];
+
+        assert!(result(1, None, Status::TimedOut).is_failure(&expected));
+        assert!(!result(12, None, Status::TimedOut).is_failure(&expected));
+        assert!(!result(12, Some("1"), Status::Unchecked).is_failure(&expected));
+        assert!(result(
+            12,
+            None,
+            Status::Panicked {
+                message: "oops".to_string()
+            }
+        )
+        .is_failure(&expected));
+    }
+}
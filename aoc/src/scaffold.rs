@@ -0,0 +1,260 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+/// The root of the repository, holding the year crates and `answers.toml`.
+pub fn workspace_root() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("..")
+}
+
+/// The `mod.rs` of a new day: a parser that keeps the lines, `todo!()` parts
+/// and example tests waiting for the puzzle's example and expected answers.
+pub fn day_module(year: u32, day: u32) -> String {
+    format!(
+        "\
+use aoc_common::{{Answer, ParseError, Solution}};
+
+pub struct Day{day};
+
+impl Solution for Day{day} {{
+    type Parsed = Vec<String>;
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed, ParseError> {{
+        Ok(input.lines().map(str::to_string).collect())
+    }}
+
+    fn part1(&self, _lines: &Self::Parsed) -> Answer {{
+        todo!(\"{year} day {day} part 1\")
+    }}
+
+    fn part2(&self, _lines: &Self::Parsed) -> Answer {{
+        todo!(\"{year} day {day} part 2\")
+    }}
+}}
+
+#[cfg(test)]
+mod tests {{
+    use super::*;
+
+    aoc_common::example_tests! {{
+        Day{day},
+        part1_example: part1(include_str!(\"example\")) == 0,
+        part2_example: part2(include_str!(\"example\")) == 0,
+    }}
+}}
+"
+    )
+}
+
+/// Adds `pub mod dayN;` and the `solution` match arm for the day to a year
+/// crate's `lib.rs`, keeping both in order.
+pub fn register_day(lib_rs: &str, day: u32) -> Result<String, String> {
+    let name = format!("day{day}");
+    let module = format!("pub mod {name};");
+    let arm = format!("{day} => &day{day}::Day{day},");
+
+    let mut lines = lib_rs.lines().map(str::to_string).collect::<Vec<_>>();
+
+    if lines.iter().any(|line| line.trim() == module) {
+        return Err(format!("day{day} is already registered"));
+    }
+
+    // Module declarations are sorted by name, the way rustfmt orders them
+    let module_idx = lines
+        .iter()
+        .position(|line| {
+            line.strip_prefix("pub mod ")
+                .and_then(|line| line.strip_suffix(';'))
+                .is_some_and(|other| other.starts_with("day") && other > name.as_str())
+        })
+        .or_else(|| {
+            lines
+                .iter()
+                .rposition(|line| line.starts_with("pub mod day"))
+                .map(|idx| idx + 1)
+        })
+        .unwrap_or(0);
+    lines.insert(module_idx, module);
+
+    let Some(fallback_idx) = lines
+        .iter()
+        .position(|line| line.trim() == "_ => return None,")
+    else {
+        return Err("Couldn't find the `solution` match in lib.rs".to_string());
+    };
+    let arm_idx = lines
+        .iter()
+        .position(|line| {
+            line.trim()
+                .split_once(" => &day")
+                .and_then(|(n, _)| n.parse::<u32>().ok())
+                .is_some_and(|n| n > day)
+        })
+        .unwrap_or(fallback_idx);
+    let fallback = &lines[fallback_idx];
+    let indent = fallback[..fallback.len() - fallback.trim_start().len()].to_string();
+    lines.insert(arm_idx, format!("{indent}{arm}"));
+
+    Ok(lines.join("\n") + "\n")
+}
+
+/// Adds an empty `[year.dayN]` table with commented-out parts to
+/// `answers.toml`, before the first later day.
+pub fn add_answers_placeholder(answers: &str, year: u32, day: u32) -> Result<String, String> {
+    let parse_header = |line: &str| -> Option<(u32, u32)> {
+        let (y, d) = line
+            .strip_prefix('[')?
+            .strip_suffix(']')?
+            .split_once(".day")?;
+        Some((y.parse().ok()?, d.parse().ok()?))
+    };
+
+    let mut lines = answers.lines().collect::<Vec<_>>();
+
+    if lines
+        .iter()
+        .any(|line| parse_header(line) == Some((year, day)))
+    {
+        return Err(format!("answers.toml already has [{year}.day{day}]"));
+    }
+
+    let header = format!("[{year}.day{day}]");
+    let placeholder = [header.as_str(), "# part1 =", "# part2 =", ""];
+
+    match lines
+        .iter()
+        .position(|line| parse_header(line).is_some_and(|key| key > (year, day)))
+    {
+        Some(idx) => {
+            lines.splice(idx..idx, placeholder);
+        }
+        None => {
+            if lines.last().is_some_and(|line| !line.is_empty()) {
+                lines.push("");
+            }
+            lines.extend(&placeholder[..3]);
+        }
+    }
+
+    Ok(lines.join("\n") + "\n")
+}
+
+/// Creates `<year>/day<day>/` with its `mod.rs` and an empty `example`,
+/// registers it in the year's `lib.rs` and adds an `answers.toml` placeholder.
+/// Returns the created and changed files relative to `root`. Nothing is
+/// written if any step would fail.
+pub fn new_day(root: &Path, year: u32, day: u32) -> Result<Vec<PathBuf>, String> {
+    if !(1..=25).contains(&day) {
+        return Err(format!("There is no day {day}, expected 1 to 25"));
+    }
+
+    let year_dir = root.join(year.to_string());
+    let lib_path = year_dir.join("lib.rs");
+    let day_dir = year_dir.join(format!("day{day}"));
+    let answers_path = root.join("answers.toml");
+
+    if !lib_path.exists() {
+        return Err(format!(
+            "There is no crate for {year}, expected {year}/lib.rs"
+        ));
+    }
+    if day_dir.exists() {
+        return Err(format!("{year}/day{day} already exists"));
+    }
+
+    let read = |path: &Path| {
+        fs::read_to_string(path).map_err(|err| format!("Error opening {}: {err}", path.display()))
+    };
+    let lib_rs = register_day(&read(&lib_path)?, day)?;
+    let answers = add_answers_placeholder(&read(&answers_path)?, year, day)?;
+
+    let module_path = day_dir.join("mod.rs");
+    let example_path = day_dir.join("example");
+
+    let write = |path: &Path, contents: &str| {
+        fs::write(path, contents).map_err(|err| format!("Error writing {}: {err}", path.display()))
+    };
+    fs::create_dir(&day_dir)
+        .map_err(|err| format!("Error creating {}: {err}", day_dir.display()))?;
+    write(&module_path, &day_module(year, day))?;
+    write(&example_path, "")?;
+    write(&lib_path, &lib_rs)?;
+    write(&answers_path, &answers)?;
+
+    Ok([module_path, example_path, lib_path, answers_path]
+        .into_iter()
+        .map(|path| {
+            path.strip_prefix(root)
+                .map(Path::to_path_buf)
+                .unwrap_or(path)
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LIB_RS: &str = "\
+pub mod day1;
+pub mod day10;
+pub mod day2;
+
+use aoc_common::Solver;
+
+pub fn solution(day: u32) -> Option<&'static dyn Solver> {
+    let solution: &'static dyn Solver = match day {
+        1 => &day1::Day1,
+        2 => &day2::Day2,
+        10 => &day10::Day10,
+        _ => return None,
+    };
+
+    Some(solution)
+}
+";
+
+    #[test]
+    fn registers_days_in_order() {
+        let lib_rs = register_day(LIB_RS, 3).unwrap();
+        assert!(lib_rs.contains("pub mod day2;\npub mod day3;\n\nuse"));
+        assert!(lib_rs.contains("2 => &day2::Day2,\n        3 => &day3::Day3,\n        10 =>"));
+
+        let lib_rs = register_day(LIB_RS, 11).unwrap();
+        assert!(lib_rs.contains("pub mod day1;\npub mod day10;\npub mod day11;\npub mod day2;"));
+        assert!(lib_rs.contains("&day10::Day10,\n        11 => &day11::Day11,\n        _ =>"));
+
+        assert!(register_day(LIB_RS, 10).is_err());
+    }
+
+    #[test]
+    fn adds_answers_placeholder_before_later_days() {
+        let answers = "# Answers\n\n[2023.day1]\npart1 = 1\n\n[2024.day1]\npart1 = 2\n";
+
+        assert_eq!(
+            add_answers_placeholder(answers, 2023, 2).unwrap(),
+            "# Answers\n\n[2023.day1]\npart1 = 1\n\n[2023.day2]\n# part1 =\n# part2 =\n\n\
+             [2024.day1]\npart1 = 2\n"
+        );
+        assert_eq!(
+            add_answers_placeholder(answers, 2024, 12).unwrap(),
+            "# Answers\n\n[2023.day1]\npart1 = 1\n\n[2024.day1]\npart1 = 2\n\n\
+             [2024.day12]\n# part1 =\n# part2 =\n"
+        );
+        assert!(add_answers_placeholder(answers, 2024, 1).is_err());
+
+        let answers = add_answers_placeholder(answers, 2024, 12).unwrap();
+        assert_eq!(crate::answers::parse_answers(&answers).unwrap().len(), 2);
+    }
+
+    #[test]
+    fn day_module_uses_the_day_number() {
+        let module = day_module(2024, 12);
+
+        assert!(module.contains("pub struct Day12;"));
+        assert!(module.contains("impl Solution for Day12 {"));
+        assert!(module.contains("todo!(\"2024 day 12 part 1\")"));
+        assert!(module.contains("include_str!(\"example\")"));
+    }
+}
@@ -0,0 +1,331 @@
+use std::{
+    fmt, fs,
+    path::{Path, PathBuf},
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use aoc_common::Answer;
+use serde::{Deserialize, Serialize};
+
+use crate::{fetch::Client, input::aoc_dir};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    /// Wrong, without saying in which direction.
+    Wrong,
+    /// Not checked because the last answer was submitted too recently.
+    TooSoon,
+    /// The part was solved before, so the answer wasn't checked.
+    AlreadySolved,
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let text = match self {
+            Verdict::Correct => "correct",
+            Verdict::TooHigh => "too high",
+            Verdict::TooLow => "too low",
+            Verdict::Wrong => "wrong",
+            Verdict::TooSoon => "submitted too soon",
+            Verdict::AlreadySolved => "already solved",
+        };
+
+        write!(f, "{text}")
+    }
+}
+
+/// What the site made of a submitted answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Response {
+    pub verdict: Verdict,
+    /// How long to wait before the next answer may be submitted.
+    pub wait_secs: Option<u64>,
+}
+
+/// Parses the page returned for a submitted answer.
+pub fn parse_response(page: &str) -> Result<Response, String> {
+    let page = page.to_lowercase();
+
+    let verdict = if page.contains("that's the right answer") {
+        Verdict::Correct
+    } else if page.contains("you don't seem to be solving the right level") {
+        Verdict::AlreadySolved
+    } else if page.contains("you gave an answer too recently") {
+        Verdict::TooSoon
+    } else if page.contains("that's not the right answer") {
+        if page.contains("your answer is too high") {
+            Verdict::TooHigh
+        } else if page.contains("your answer is too low") {
+            Verdict::TooLow
+        } else {
+            Verdict::Wrong
+        }
+    } else {
+        return Err("Unrecognised response to the submitted answer".to_string());
+    };
+
+    let wait_secs = between(&page, "you have ", " left to wait")
+        .or_else(|| between(&page, "please wait ", " before trying again"))
+        .and_then(parse_wait);
+
+    Ok(Response { verdict, wait_secs })
+}
+
+/// The text between `end` and the closest `start` before it.
+fn between<'a>(text: &'a str, start: &str, end: &str) -> Option<&'a str> {
+    let (before, _) = text.split_once(end)?;
+    let (_, between) = before.rsplit_once(start)?;
+
+    Some(between)
+}
+
+/// Parses waits like `4m 12s`, `39s`, `one minute` and `5 minutes`.
+fn parse_wait(text: &str) -> Option<u64> {
+    let mut words = text.split_whitespace();
+    let mut secs = 0;
+
+    while let Some(word) = words.next() {
+        let (n, unit) = match word.find(|c: char| !c.is_ascii_digit()) {
+            Some(0) => match word {
+                "one" => (1, words.next()?),
+                _ => return None,
+            },
+            Some(idx) => (word[..idx].parse::<u64>().ok()?, &word[idx..]),
+            None => (word.parse::<u64>().ok()?, words.next()?),
+        };
+
+        secs += n * match unit {
+            "h" | "hour" | "hours" => 3600,
+            "m" | "minute" | "minutes" => 60,
+            "s" | "second" | "seconds" => 1,
+            _ => return None,
+        };
+    }
+
+    Some(secs)
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Submission {
+    /// Seconds since the Unix epoch.
+    pub timestamp: u64,
+    pub year: u32,
+    pub day: u32,
+    pub part: u32,
+    pub answer: String,
+    pub verdict: Verdict,
+    pub wait_secs: Option<u64>,
+}
+
+pub fn default_log_path() -> Option<PathBuf> {
+    aoc_dir().map(|dir| dir.join("submissions.json"))
+}
+
+pub fn load_log(path: &Path) -> Result<Vec<Submission>, String> {
+    if !path.exists() {
+        return Ok(Vec::new());
+    }
+
+    let text = fs::read_to_string(path)
+        .map_err(|err| format!("Error opening {}: {err}", path.display()))?;
+
+    serde_json::from_str(&text)
+        .map_err(|err| format!("Malformed submission log {}: {err}", path.display()))
+}
+
+pub fn save_log(path: &Path, log: &[Submission]) -> Result<(), String> {
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)
+            .map_err(|err| format!("Error creating {}: {err}", dir.display()))?;
+    }
+
+    let text = serde_json::to_string_pretty(log).expect("Submission log serializes");
+
+    fs::write(path, text).map_err(|err| format!("Error writing {}: {err}", path.display()))
+}
+
+/// Rejects answers that the log already shows to be wrong, answers for solved
+/// parts, and submissions before the last wait is over.
+pub fn check_submission(
+    log: &[Submission],
+    year: u32,
+    day: u32,
+    part: u32,
+    answer: &Answer,
+    now: u64,
+) -> Result<(), String> {
+    let puzzle = format!("{year} day {day} part {part}");
+    let earlier = log
+        .iter()
+        .filter(|s| (s.year, s.day, s.part) == (year, day, part))
+        .collect::<Vec<_>>();
+
+    if let Some(correct) = earlier.iter().find(|s| s.verdict == Verdict::Correct) {
+        return Err(format!(
+            "{puzzle} is already solved with {}",
+            correct.answer
+        ));
+    }
+
+    let answer_text = answer.to_string();
+
+    if let Some(submitted) = earlier
+        .iter()
+        .find(|s| s.answer == answer_text && s.verdict != Verdict::TooSoon)
+    {
+        return Err(format!(
+            "{answer_text} was already submitted for {puzzle} and was {}",
+            submitted.verdict
+        ));
+    }
+
+    if let Answer::Int(n) = answer {
+        let bound = |verdict| {
+            earlier
+                .iter()
+                .filter(move |s| s.verdict == verdict)
+                .filter_map(|s| s.answer.parse::<i64>().ok())
+        };
+
+        if let Some(low) = bound(Verdict::TooLow).max().filter(|low| n <= low) {
+            return Err(format!("{n} is too low for {puzzle}, {low} already was"));
+        }
+        if let Some(high) = bound(Verdict::TooHigh).min().filter(|high| n >= high) {
+            return Err(format!("{n} is too high for {puzzle}, {high} already was"));
+        }
+    }
+
+    let wait_until = earlier
+        .iter()
+        .filter_map(|s| s.wait_secs.map(|wait| s.timestamp + wait))
+        .max();
+
+    if let Some(wait_until) = wait_until.filter(|until| *until > now) {
+        return Err(format!(
+            "Wait another {}s before submitting {puzzle}",
+            wait_until - now
+        ));
+    }
+
+    Ok(())
+}
+
+/// Submits `answer` unless the log rules it out, and logs the result.
+pub fn submit(
+    client: &Client,
+    log_path: &Path,
+    year: u32,
+    day: u32,
+    part: u32,
+    answer: &Answer,
+) -> Result<Response, String> {
+    let mut log = load_log(log_path)?;
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0);
+
+    check_submission(&log, year, day, part, answer, now)?;
+
+    let response = parse_response(&client.answer(year, day, part, &answer.to_string())?)?;
+
+    log.push(Submission {
+        timestamp: now,
+        year,
+        day,
+        part,
+        answer: answer.to_string(),
+        verdict: response.verdict,
+        wait_secs: response.wait_secs,
+    });
+    save_log(log_path, &log)?;
+
+    Ok(response)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn submission(answer: &str, verdict: Verdict, wait_secs: Option<u64>) -> Submission {
+        Submission {
+            timestamp: 1000,
+            year: 2023,
+            day: 21,
+            part: 2,
+            answer: answer.to_string(),
+            verdict,
+            wait_secs,
+        }
+    }
+
+    #[test]
+    fn parses_responses() {
+        let response = |page| parse_response(page).unwrap();
+
+        assert_eq!(
+            response("<article><p>That's the right answer!  You are <span class=\"day-success\">one gold star</span> closer to restoring snow operations.</p></article>"),
+            Response { verdict: Verdict::Correct, wait_secs: None }
+        );
+        assert_eq!(
+            response("<article><p>That's not the right answer; your answer is too high.  If you're stuck, make sure you're using the full input data. (You guessed <span style=\"white-space:nowrap;\"><code>1234</code>.)</span>  Please wait one minute before trying again.</p></article>"),
+            Response { verdict: Verdict::TooHigh, wait_secs: Some(60) }
+        );
+        assert_eq!(
+            response("<article><p>That's not the right answer; your answer is too low.  Please wait 5 minutes before trying again.</p></article>"),
+            Response { verdict: Verdict::TooLow, wait_secs: Some(300) }
+        );
+        assert_eq!(
+            response("<article><p>That's not the right answer.  Please wait one minute before trying again.</p></article>"),
+            Response { verdict: Verdict::Wrong, wait_secs: Some(60) }
+        );
+        assert_eq!(
+            response("<article><p>You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 4m 12s left to wait.</p></article>"),
+            Response { verdict: Verdict::TooSoon, wait_secs: Some(252) }
+        );
+        assert_eq!(
+            response("<article><p>You don't seem to be solving the right level.  Did you already complete it?</p></article>"),
+            Response { verdict: Verdict::AlreadySolved, wait_secs: None }
+        );
+        assert!(parse_response("<html>Something else</html>").is_err());
+    }
+
+    #[test]
+    fn rejects_answers_ruled_out_by_the_log() {
+        let log = vec![
+            submission("100", Verdict::TooLow, Some(60)),
+            submission("500", Verdict::TooHigh, Some(60)),
+            submission("300", Verdict::Wrong, Some(300)),
+        ];
+        let check = |answer: i64, now| check_submission(&log, 2023, 21, 2, &answer.into(), now);
+
+        assert!(check(50, 2000).unwrap_err().contains("too low"));
+        assert!(check(100, 2000).unwrap_err().contains("already submitted"));
+        assert!(check(600, 2000).unwrap_err().contains("too high"));
+        assert!(check(300, 2000).unwrap_err().contains("already submitted"));
+        assert_eq!(
+            check(200, 1200),
+            Err("Wait another 100s before submitting 2023 day 21 part 2".to_string())
+        );
+        assert_eq!(check(200, 2000), Ok(()));
+
+        assert_eq!(
+            check_submission(&log, 2023, 21, 1, &50.into(), 2000),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn rejects_solved_parts() {
+        let log = vec![submission("abc", Verdict::Correct, None)];
+
+        assert_eq!(
+            check_submission(&log, 2023, 21, 2, &"abd".into(), 2000),
+            Err("2023 day 21 part 2 is already solved with abc".to_string())
+        );
+    }
+}
@@ -0,0 +1,23 @@
+use aoc::answers::{load_answers, verify};
+
+fn verify_year(year: u32) {
+    let answers = load_answers().expect("Failed to load answers");
+
+    let failures = answers
+        .iter()
+        .filter(|a| a.year == year)
+        .filter_map(|a| verify(a).err())
+        .collect::<Vec<_>>();
+
+    assert!(failures.is_empty(), "{}", failures.join("\n"));
+}
+
+#[test]
+fn answers_2023() {
+    verify_year(2023);
+}
+
+#[test]
+fn answers_2024() {
+    verify_year(2024);
+}
@@ -0,0 +1,94 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    process::{Command, Output},
+};
+
+use aoc::encrypted::{decrypt_file, encrypt, encrypt_file, InputKey};
+
+const EXAMPLE: &str = include_str!("../../2024/day1/example");
+const KEY: &str = "000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f";
+
+fn tmp_dir(name: &str) -> PathBuf {
+    let dir = Path::new(env!("CARGO_TARGET_TMPDIR")).join(name);
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+/// Runs aoc without a config file, with the given input key.
+fn aoc(args: &[&str], home: &Path, key: Option<&str>) -> Output {
+    let mut command = Command::new(env!("CARGO_BIN_EXE_aoc"));
+
+    command
+        .args(args)
+        .env("HOME", home)
+        .env_remove("AOC_INPUT_ROOT")
+        .env_remove("AOC_INPUT_KEY");
+
+    if let Some(key) = key {
+        command.env("AOC_INPUT_KEY", key);
+    }
+
+    command.output().unwrap()
+}
+
+#[test]
+fn runs_encrypted_inputs() {
+    let dir = tmp_dir("encrypted-run");
+    let path = dir.join("input");
+    fs::write(&path, encrypt(&KEY.parse().unwrap(), EXAMPLE).unwrap()).unwrap();
+    let path = path.to_str().unwrap();
+
+    let output = aoc(&["run", "2024", "1", "1", path], &dir, Some(KEY));
+    assert!(output.status.success());
+    assert_eq!(String::from_utf8_lossy(&output.stdout).trim(), "11");
+
+    let output = aoc(&["run", "2024", "1", "1", path], &dir, None);
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("No input key, set AOC_INPUT_KEY"));
+
+    let wrong_key = KEY.replace("00", "ff");
+    let output = aoc(&["run", "2024", "1", "1", path], &dir, Some(&wrong_key));
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("the input key is wrong"));
+}
+
+#[test]
+fn encrypts_and_decrypts_files_in_place() {
+    let key = KEY.parse::<InputKey>().unwrap();
+    let dir = tmp_dir("encrypted-files");
+    let path = dir.join("input");
+    fs::write(&path, EXAMPLE).unwrap();
+
+    assert_eq!(encrypt_file(&key, &path), Ok(true));
+    assert!(!fs::read_to_string(&path).unwrap().contains("3   4"));
+    assert_eq!(encrypt_file(&key, &path), Ok(false));
+
+    assert_eq!(decrypt_file(&key, &path), Ok(true));
+    assert_eq!(fs::read_to_string(&path).unwrap(), EXAMPLE);
+    assert_eq!(decrypt_file(&key, &path), Ok(false));
+
+    // Nothing is left over from writing the files
+    let files = fs::read_dir(&dir)
+        .unwrap()
+        .map(|entry| entry.unwrap().file_name())
+        .collect::<Vec<_>>();
+    assert_eq!(files, ["input"]);
+}
+
+#[test]
+fn leaves_plain_committed_inputs_alone_when_decrypting() {
+    let dir = tmp_dir("encrypted-commands");
+
+    let output = aoc(&["inputs", "decrypt", "2024", "1"], &dir, Some(KEY));
+    assert!(output.status.success());
+    assert_eq!(
+        String::from_utf8_lossy(&output.stdout),
+        "Decrypted 0 input(s)\n"
+    );
+
+    let output = aoc(&["inputs", "encrypt"], &dir, None);
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("No input key"));
+}
@@ -0,0 +1,135 @@
+mod mock;
+
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    process::Command,
+    time::{Duration, Instant},
+};
+
+use aoc::fetch::{fetch_input, Client, Fetched, RateLimit};
+use mock::MockServer;
+
+const INPUT: &str = "3   4\n4   3\n";
+
+fn tmp_dir(name: &str) -> PathBuf {
+    let dir = Path::new(env!("CARGO_TARGET_TMPDIR")).join(name);
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+fn client(server: &MockServer, dir: &Path) -> Client {
+    let rate_limit = RateLimit {
+        path: dir.join("last-request"),
+        interval: Duration::ZERO,
+    };
+
+    Client::new(&server.url, "53616c74", rate_limit)
+}
+
+#[test]
+fn downloads_once_and_then_uses_the_cache() {
+    let dir = tmp_dir("fetch-cache");
+    let server = MockServer::start(|_| (200, INPUT.to_string()));
+    let client = client(&server, &dir);
+    let path = dir.join("2024").join("12.txt");
+
+    assert_eq!(
+        fetch_input(&client, &path, 2024, 12),
+        Ok(Fetched::Downloaded)
+    );
+    assert_eq!(fs::read_to_string(&path).unwrap(), INPUT);
+    assert_eq!(fetch_input(&client, &path, 2024, 12), Ok(Fetched::Cached));
+
+    let requests = server.requests();
+    assert_eq!(requests.len(), 1);
+    assert_eq!(requests[0].method, "GET");
+    assert_eq!(requests[0].path, "/2024/day/12/input");
+    assert_eq!(requests[0].header("cookie"), Some("session=53616c74"));
+}
+
+#[test]
+fn reports_an_expired_session() {
+    let dir = tmp_dir("fetch-session");
+    let server = MockServer::start(|_| {
+        (
+            400,
+            "Puzzle inputs differ by user.  Please log in to get your puzzle input.".to_string(),
+        )
+    });
+    let path = dir.join("2024").join("12.txt");
+
+    let err = fetch_input(&client(&server, &dir), &path, 2024, 12).unwrap_err();
+
+    assert!(err.contains("session token was rejected"), "{err}");
+    assert!(!path.exists());
+}
+
+#[test]
+fn reports_days_that_are_not_unlocked_yet() {
+    let dir = tmp_dir("fetch-locked");
+    let server = MockServer::start(|_| {
+        (
+            404,
+            "Please don't repeatedly request this endpoint before it unlocks!".to_string(),
+        )
+    });
+    let path = dir.join("2024").join("25.txt");
+
+    assert_eq!(
+        fetch_input(&client(&server, &dir), &path, 2024, 25),
+        Err("2024 day 25 isn't unlocked yet".to_string())
+    );
+    assert!(!path.exists());
+}
+
+#[test]
+fn rate_limit_spaces_out_requests() {
+    let dir = tmp_dir("fetch-rate-limit");
+    let rate_limit = RateLimit {
+        path: dir.join("last-request"),
+        interval: Duration::from_millis(300),
+    };
+
+    let start = Instant::now();
+    rate_limit.wait().unwrap();
+    assert!(start.elapsed() < Duration::from_millis(300));
+
+    rate_limit.wait().unwrap();
+    assert!(start.elapsed() >= Duration::from_millis(300));
+}
+
+#[test]
+fn fetch_command_saves_below_the_input_root() {
+    let dir = tmp_dir("fetch-command");
+    let server = MockServer::start(|_| (200, INPUT.to_string()));
+
+    let aoc = || {
+        Command::new(env!("CARGO_BIN_EXE_aoc"))
+            .args(["fetch", "2024", "12"])
+            .env("HOME", &dir)
+            .env("AOC_INPUT_ROOT", dir.join("inputs"))
+            .env("AOC_SESSION", "53616c74")
+            .env("AOC_BASE_URL", &server.url)
+            .output()
+            .unwrap()
+    };
+
+    let output = aoc();
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    assert!(String::from_utf8_lossy(&output.stdout).starts_with("Downloaded"));
+    assert_eq!(
+        fs::read_to_string(dir.join("inputs").join("2024").join("12.txt")).unwrap(),
+        INPUT
+    );
+    assert!(dir.join(".aoc").join("last-request").exists());
+
+    let output = aoc();
+    assert!(String::from_utf8_lossy(&output.stdout).starts_with("Already have"));
+    assert_eq!(server.requests().len(), 1);
+}
@@ -0,0 +1,64 @@
+use std::process::Command;
+
+use aoc::{solutions, YEARS};
+use aoc_common::{Rng, Size};
+
+#[test]
+fn generated_inputs_parse() {
+    for year in YEARS {
+        for (day, solution) in solutions(year) {
+            for size in Size::ALL {
+                for seed in 0..3 {
+                    // Days scaffolded without a generator yet have nothing to check
+                    let Some(input) = solution.generate(&mut Rng::new(seed), size) else {
+                        continue;
+                    };
+
+                    if let Err(err) = solution.parse(&input) {
+                        panic!("{year} day {day}, {size} seed {seed}: {err}");
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn same_seed_same_input() {
+    for year in YEARS {
+        for (day, solution) in solutions(year) {
+            let a = solution.generate(&mut Rng::new(42), Size::Small);
+            let b = solution.generate(&mut Rng::new(42), Size::Small);
+
+            assert_eq!(a, b, "{year} day {day}");
+        }
+    }
+}
+
+#[test]
+fn prints_the_generated_input() {
+    let output = Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .args(["gen", "2023", "12", "--seed", "42", "--size", "large"])
+        .output()
+        .expect("Failed to run aoc");
+
+    let expected = aoc::solution(2023, 12)
+        .and_then(|solution| solution.generate(&mut Rng::new(42), Size::Large))
+        .expect("2023 day 12 has a generator");
+
+    assert!(output.status.success());
+    assert_eq!(String::from_utf8(output.stdout).unwrap(), expected);
+}
+
+#[test]
+fn rejects_an_unknown_size() {
+    let output = Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .args(["gen", "2023", "12", "--size", "huge"])
+        .output()
+        .expect("Failed to run aoc");
+
+    assert!(!output.status.success());
+    assert!(String::from_utf8(output.stderr)
+        .unwrap()
+        .contains("Unknown size 'huge'"));
+}
@@ -0,0 +1,84 @@
+use std::{
+    fs,
+    io::Write,
+    path::Path,
+    process::{Command, Output, Stdio},
+};
+
+const EXAMPLE: &str = include_str!("../../2024/day1/example");
+
+fn aoc(args: &[&str], input_root: Option<&Path>, stdin: Option<&str>) -> Output {
+    let mut command = Command::new(env!("CARGO_BIN_EXE_aoc"));
+
+    command
+        .args(args)
+        .env_remove("AOC_INPUT_ROOT")
+        .stdin(if stdin.is_some() {
+            Stdio::piped()
+        } else {
+            Stdio::null()
+        })
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped());
+
+    if let Some(root) = input_root {
+        command.env("AOC_INPUT_ROOT", root);
+    }
+
+    let mut child = command.spawn().expect("Failed to start aoc");
+
+    if let Some(stdin) = stdin {
+        child
+            .stdin
+            .take()
+            .unwrap()
+            .write_all(stdin.as_bytes())
+            .unwrap();
+    }
+
+    child.wait_with_output().unwrap()
+}
+
+fn stdout(output: &Output) -> &str {
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    std::str::from_utf8(&output.stdout).unwrap().trim()
+}
+
+#[test]
+fn reads_stdin_for_dash_and_when_piped() {
+    let output = aoc(&["run", "2024", "1", "1", "-"], None, Some(EXAMPLE));
+    assert_eq!(stdout(&output), "11");
+
+    let output = aoc(&["run", "2024", "1", "2"], None, Some(EXAMPLE));
+    assert_eq!(stdout(&output), "31");
+}
+
+#[test]
+fn names_stdin_in_parse_errors() {
+    let output = aoc(&["run", "2024", "1", "1", "-"], None, Some("1   x\n"));
+
+    assert!(!output.status.success());
+    assert_eq!(
+        String::from_utf8_lossy(&output.stderr).trim(),
+        "Error parsing <stdin>: line 1, column 5: Expected a number in 'x'"
+    );
+}
+
+#[test]
+fn resolves_the_default_input_below_the_input_root() {
+    let root = Path::new(env!("CARGO_TARGET_TMPDIR")).join("input-root");
+    fs::create_dir_all(root.join("2024")).unwrap();
+    fs::write(root.join("2024").join("01.txt"), EXAMPLE).unwrap();
+
+    let output = aoc(&["run", "2024", "1", "1"], Some(&root), None);
+    assert_eq!(stdout(&output), "11");
+
+    let output = aoc(&["run", "2024", "2", "1"], Some(&root), None);
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("02.txt"));
+}
@@ -0,0 +1,105 @@
+use std::panic::{self, AssertUnwindSafe};
+
+use aoc::{solution, solutions, YEARS};
+use aoc_common::{Rng, Size};
+
+#[test]
+fn reports_the_position_of_malformed_input() {
+    let solution = solution(2024, 1).expect("2024 day 1 exists");
+
+    let err = solution
+        .run("1   2\n3   x4\n", 1)
+        .expect_err("Input is malformed");
+
+    assert_eq!((err.line, err.column), (2, 5));
+    assert_eq!(
+        err.to_string(),
+        "line 2, column 5: Expected a number in 'x4'"
+    );
+}
+
+#[test]
+fn reports_input_of_the_wrong_day() {
+    let solution = solution(2023, 17).expect("2023 day 17 exists");
+
+    let err = solution.run(".|.\n\\..\n", 1).expect_err("Not a heat map");
+
+    assert_eq!((err.line, err.column), (1, 1));
+}
+
+/// Characters that parsers split on or treat specially.
+const INTERESTING: &[&str] = &[
+    "",
+    " ",
+    "\n",
+    ",",
+    ":",
+    "|",
+    "-",
+    "=",
+    "#",
+    ".",
+    "0",
+    "9",
+    "S",
+    "(",
+    ")",
+    "{",
+    "}",
+    "->",
+    "@",
+    "~",
+    "99999999999999999999",
+    "-1",
+];
+
+/// Changes `input` in a few random places: dropping, repeating or replacing
+/// a span, or cutting it short.
+fn mutate(rng: &mut Rng, input: &str) -> String {
+    let mut chars = input.chars().map(String::from).collect::<Vec<_>>();
+
+    for _ in 0..rng.int(1..=3) {
+        if chars.is_empty() {
+            break;
+        }
+
+        let start = rng.index(chars.len());
+        let end = (start + rng.index(8) + 1).min(chars.len());
+
+        match rng.index(4) {
+            0 => {
+                chars.drain(start..end);
+            }
+            1 => {
+                let span = chars[start..end].to_vec();
+                chars.splice(start..start, span);
+            }
+            2 => chars[start] = rng.pick(INTERESTING).to_string(),
+            _ => chars.truncate(start),
+        }
+    }
+
+    chars.concat()
+}
+
+#[test]
+fn malformed_input_never_panics() {
+    for year in YEARS {
+        for (day, solution) in solutions(year) {
+            let mut rng = Rng::new(year as u64 * 100 + day as u64);
+            // Days scaffolded without a generator yet have no input to mutate
+            let Some(input) = solution.generate(&mut rng, Size::Small) else {
+                continue;
+            };
+
+            for _ in 0..300 {
+                let mutated = mutate(&mut rng, &input);
+                let parsed = panic::catch_unwind(AssertUnwindSafe(|| {
+                    let _ = solution.parse(&mutated);
+                }));
+
+                assert!(parsed.is_ok(), "{year} day {day} panicked on {mutated:?}");
+            }
+        }
+    }
+}
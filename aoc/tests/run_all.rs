@@ -0,0 +1,67 @@
+use std::process::{Command, Output};
+
+fn aoc(args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .args(args)
+        .env_remove("AOC_INPUT_ROOT")
+        .output()
+        .unwrap()
+}
+
+#[test]
+fn runs_every_part_of_a_year() {
+    let output = aoc(&["run", "--all", "--year", "2024", "--timeout", "120"]);
+    let stdout = String::from_utf8_lossy(&output.stdout);
+
+    assert!(output.status.success(), "{stdout}");
+    assert!(stdout.starts_with("year  day  part  answer"), "{stdout}");
+    assert!(stdout.contains("2024    1     1  2285373"), "{stdout}");
+    assert!(!stdout.contains("2023"), "{stdout}");
+    assert!(
+        stdout.ends_with("22 passed, 0 failed, 0 unchecked, 0 panicked, 0 over budget, 0 errors\n"),
+        "{stdout}"
+    );
+}
+
+#[test]
+fn reports_parts_over_budget() {
+    let output = aoc(&[
+        "run",
+        "--all",
+        "--year",
+        "2024",
+        "--timeout",
+        "0.000001",
+        "--jobs",
+        "2",
+    ]);
+    let stdout = String::from_utf8_lossy(&output.stdout);
+
+    assert!(!output.status.success());
+    assert!(stdout.contains("OVER BUDGET in "), "{stdout}");
+    assert!(stdout.contains(": ran for more than 1.00µs"), "{stdout}");
+}
+
+#[test]
+fn stops_a_single_run_over_budget() {
+    let output = aoc(&["run", "2023", "18", "2", "--time-limit", "0.2"]);
+
+    assert_eq!(output.status.code(), Some(3));
+    assert_eq!(
+        String::from_utf8_lossy(&output.stderr).trim(),
+        "Over budget in part2: ran for more than 200.00ms"
+    );
+
+    let output = aoc(&["run", "2024", "1", "1", "--memory-limit", "1K"]);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+
+    assert_eq!(output.status.code(), Some(3));
+    assert!(
+        stderr.starts_with("Over budget in parse: used "),
+        "{stderr}"
+    );
+    assert!(
+        stderr.trim().ends_with("of memory, more than 1.0KiB"),
+        "{stderr}"
+    );
+}
@@ -0,0 +1,106 @@
+mod mock;
+
+use std::{
+    fs,
+    io::Write,
+    path::{Path, PathBuf},
+    process::{Command, Stdio},
+    time::Duration,
+};
+
+use aoc::{
+    fetch::{Client, RateLimit},
+    submit::{load_log, submit, Verdict},
+};
+use mock::MockServer;
+
+const TOO_HIGH: &str = "<article><p>That's not the right answer; your answer is too high.  \
+                        Please wait one minute before trying again.</p></article>";
+const CORRECT: &str = "<article><p>That's the right answer!  You are <span \
+                       class=\"day-success\">one gold star</span> closer.</p></article>";
+
+fn tmp_dir(name: &str) -> PathBuf {
+    let dir = Path::new(env!("CARGO_TARGET_TMPDIR")).join(name);
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+#[test]
+fn logs_submissions_and_never_repeats_a_wrong_answer() {
+    let dir = tmp_dir("submit-log");
+    let server = MockServer::start(|_| (200, TOO_HIGH.to_string()));
+    let rate_limit = RateLimit {
+        path: dir.join("last-request"),
+        interval: Duration::ZERO,
+    };
+    let client = Client::new(&server.url, "53616c74", rate_limit);
+    let log_path = dir.join("submissions.json");
+
+    let response = submit(&client, &log_path, 2023, 21, 2, &1234.into()).unwrap();
+    assert_eq!(response.verdict, Verdict::TooHigh);
+    assert_eq!(response.wait_secs, Some(60));
+
+    let requests = server.requests();
+    assert_eq!(requests.len(), 1);
+    assert_eq!(requests[0].method, "POST");
+    assert_eq!(requests[0].path, "/2023/day/21/answer");
+    assert_eq!(requests[0].body, "level=2&answer=1234");
+
+    let log = load_log(&log_path).unwrap();
+    assert_eq!(log.len(), 1);
+    assert_eq!(
+        (log[0].answer.as_str(), log[0].verdict),
+        ("1234", Verdict::TooHigh)
+    );
+
+    let err = submit(&client, &log_path, 2023, 21, 2, &1234.into()).unwrap_err();
+    assert!(err.contains("already submitted"), "{err}");
+
+    let err = submit(&client, &log_path, 2023, 21, 2, &2000.into()).unwrap_err();
+    assert!(err.contains("too high"), "{err}");
+
+    let err = submit(&client, &log_path, 2023, 21, 2, &1000.into()).unwrap_err();
+    assert!(err.starts_with("Wait another"), "{err}");
+
+    assert_eq!(server.requests().len(), 1);
+}
+
+#[test]
+fn submit_command_posts_the_computed_answer() {
+    let dir = tmp_dir("submit-command");
+    let server = MockServer::start(|_| (200, CORRECT.to_string()));
+
+    let mut child = Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .args(["submit", "2024", "1", "1", "-"])
+        .env("HOME", &dir)
+        .env("AOC_SESSION", "53616c74")
+        .env("AOC_BASE_URL", &server.url)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(include_bytes!("../../2024/day1/example"))
+        .unwrap();
+
+    let output = child.wait_with_output().unwrap();
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    assert_eq!(
+        String::from_utf8_lossy(&output.stdout),
+        "Submitting 11 for 2024 day 1 part 1\ncorrect\n"
+    );
+    assert_eq!(server.requests()[0].body, "level=1&answer=11");
+
+    let log = load_log(&dir.join(".aoc").join("submissions.json")).unwrap();
+    assert_eq!(log[0].verdict, Verdict::Correct);
+}
@@ -0,0 +1,62 @@
+use std::{fs, path::Path, process::Command};
+
+const EXAMPLE: &str = "../2023/day18/example";
+
+fn aoc(args: &[&str]) -> (String, String) {
+    let output = Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .args(args)
+        .current_dir(env!("CARGO_MANIFEST_DIR"))
+        .output()
+        .unwrap();
+
+    assert!(output.status.success());
+
+    (
+        String::from_utf8(output.stdout).unwrap(),
+        String::from_utf8(output.stderr).unwrap(),
+    )
+}
+
+#[test]
+fn stdout_only_carries_the_answer() {
+    let (stdout, stderr) = aoc(&["run", "2023", "18", "1", EXAMPLE]);
+    assert_eq!(stdout, "62\n");
+    assert_eq!(stderr, "");
+
+    let (stdout, stderr) = aoc(&["--trace", "run", "2023", "18", "1", EXAMPLE]);
+    assert_eq!(stdout, "62\n");
+    assert!(
+        stderr.contains("run{year=2023 day=18 part=1}:solve: filled:"),
+        "{stderr}"
+    );
+    assert!(stderr.contains("parse: close"), "{stderr}");
+}
+
+#[test]
+fn verbose_leaves_out_trace_events() {
+    let (stdout, stderr) = aoc(&["run", "2023", "18", "1", EXAMPLE, "--verbose"]);
+
+    assert_eq!(stdout, "62\n");
+    assert!(stderr.contains("solve: close"), "{stderr}");
+    assert!(!stderr.contains("filled:"), "{stderr}");
+}
+
+#[test]
+fn writes_traces_to_a_file() {
+    let path = Path::new(env!("CARGO_TARGET_TMPDIR")).join("day18.trace");
+    let _ = fs::remove_file(&path);
+
+    let (stdout, stderr) = aoc(&[
+        "run",
+        "2023",
+        "18",
+        "1",
+        EXAMPLE,
+        "--trace-file",
+        path.to_str().unwrap(),
+    ]);
+
+    assert_eq!(stdout, "62\n");
+    assert_eq!(stderr, "");
+    assert!(fs::read_to_string(&path).unwrap().contains("filled:"));
+}
@@ -0,0 +1,223 @@
+use std::{collections::HashMap, hash::Hash};
+
+/// Where the states of a simulation start repeating: after `start` steps, the
+/// same `period` states come back over and over.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cycle {
+    /// The number of steps before the first state that is on the cycle.
+    pub start: usize,
+    pub period: usize,
+}
+
+impl Cycle {
+    /// The first step that reaches the same state as step `n`.
+    pub fn earliest_equivalent(&self, n: usize) -> usize {
+        if n < self.start {
+            n
+        } else {
+            self.start + (n - self.start) % self.period
+        }
+    }
+}
+
+/// Finds the cycle reached by repeatedly calling `step` on `start`, remembering
+/// every state on the way in a hash map. States are compared exactly, so hash
+/// collisions can't cause a wrong cycle. The states must repeat eventually.
+pub fn find_cycle<S>(start: S, mut step: impl FnMut(&S) -> S) -> Cycle
+where
+    S: Clone + Eq + Hash,
+{
+    let mut seen = HashMap::new();
+    let mut state = start;
+
+    loop {
+        let steps = seen.len();
+
+        if let Some(&first) = seen.get(&state) {
+            break Cycle {
+                start: first,
+                period: steps - first,
+            };
+        }
+
+        let next = step(&state);
+        seen.insert(state, steps);
+        state = next;
+    }
+}
+
+/// Finds the cycle like [`find_cycle`], but only ever keeps two states around.
+/// Takes about three times as many steps, for states that are too big to
+/// remember or can't be hashed.
+pub fn brent<S>(start: S, mut step: impl FnMut(&S) -> S) -> Cycle
+where
+    S: Clone + Eq,
+{
+    // Find the period by letting the hare run ahead of the tortoise, which
+    // jumps to the hare after every power of two steps
+    let mut power = 1;
+    let mut period = 1;
+    let mut tortoise = start.clone();
+    let mut hare = step(&start);
+
+    while tortoise != hare {
+        if power == period {
+            tortoise = hare.clone();
+            power *= 2;
+            period = 0;
+        }
+
+        hare = step(&hare);
+        period += 1;
+    }
+
+    // With the hare one period ahead, they first meet at the start of the cycle
+    let mut tortoise = start.clone();
+    let mut hare = start;
+    for _ in 0..period {
+        hare = step(&hare);
+    }
+
+    let mut cycle_start = 0;
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        cycle_start += 1;
+    }
+
+    Cycle {
+        start: cycle_start,
+        period,
+    }
+}
+
+/// Finds the cycle with Floyd's tortoise and hare, which like [`brent`] only
+/// keeps two states around but usually takes more steps.
+pub fn floyd<S>(start: S, mut step: impl FnMut(&S) -> S) -> Cycle
+where
+    S: Clone + Eq,
+{
+    // The hare takes two steps for every one of the tortoise, so they meet
+    // once both are on the cycle
+    let mut tortoise = step(&start);
+    let mut hare = step(&tortoise);
+
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        hare = step(&hare);
+    }
+
+    // The distance they are apart is a multiple of the period, so starting
+    // over with the tortoise they meet at the start of the cycle
+    let mut cycle_start = 0;
+    tortoise = start;
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        cycle_start += 1;
+    }
+
+    let mut period = 1;
+    hare = step(&tortoise);
+    while tortoise != hare {
+        hare = step(&hare);
+        period += 1;
+    }
+
+    Cycle {
+        start: cycle_start,
+        period,
+    }
+}
+
+/// The state after calling `step` `n` times on `start`. Once a state repeats,
+/// the rest of the steps are skipped by looking up where the cycle will be at
+/// step `n`.
+pub fn fast_forward<S>(start: S, mut step: impl FnMut(&S) -> S, n: usize) -> S
+where
+    S: Clone + Eq + Hash,
+{
+    let mut seen = HashMap::new();
+    let mut states = Vec::new();
+    let mut state = start;
+
+    while states.len() < n {
+        if let Some(&first) = seen.get(&state) {
+            let cycle = Cycle {
+                start: first,
+                period: states.len() - first,
+            };
+
+            return states.swap_remove(cycle.earliest_equivalent(n));
+        }
+
+        let next = step(&state);
+        seen.insert(state.clone(), states.len());
+        states.push(state);
+        state = next;
+    }
+
+    state
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A list of `len` states where the last one leads back to state
+    /// `len - period`.
+    fn rho(len: usize, period: usize) -> impl Fn(&usize) -> usize {
+        move |&state| {
+            if state + 1 == len {
+                len - period
+            } else {
+                state + 1
+            }
+        }
+    }
+
+    #[test]
+    fn finders_agree_on_cycles() {
+        for len in 1..20 {
+            for period in 1..=len {
+                let expected = Cycle {
+                    start: len - period,
+                    period,
+                };
+
+                assert_eq!(find_cycle(0, rho(len, period)), expected);
+                assert_eq!(brent(0, rho(len, period)), expected);
+                assert_eq!(floyd(0, rho(len, period)), expected);
+            }
+        }
+    }
+
+    #[test]
+    fn finds_cycles_of_a_sequence() {
+        // 2, 4, 5, 3, 9, 4, ...
+        let square = |x: &u64| x * x % 11;
+
+        let cycle = Cycle {
+            start: 1,
+            period: 4,
+        };
+
+        assert_eq!(find_cycle(2, square), cycle);
+        assert_eq!(brent(2, square), cycle);
+        assert_eq!(floyd(2, square), cycle);
+    }
+
+    #[test]
+    fn fast_forward_matches_stepping() {
+        let step = rho(7, 3);
+
+        for n in 0..30 {
+            let stepped = (0..n).fold(0, |state, _| step(&state));
+
+            assert_eq!(fast_forward(0, &step, n), stepped, "after {n} steps");
+        }
+
+        assert_eq!(fast_forward(0, &step, 1_000_000_000_000), 4);
+    }
+}
@@ -0,0 +1,69 @@
+use std::cell::RefCell;
+
+use crate::Answer;
+
+/// A named value a solver found on the way to its answer, e.g. the sizes of
+/// the groups a graph was split into.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Diagnostic {
+    pub name: &'static str,
+    pub value: Answer,
+}
+
+thread_local! {
+    static CAPTURED: RefCell<Option<Vec<Diagnostic>>> = const { RefCell::new(None) };
+}
+
+/// Records a diagnostic if the current thread is inside [`capture`], otherwise
+/// does nothing.
+pub fn record(name: &'static str, value: impl Into<Answer>) {
+    CAPTURED.with_borrow_mut(|captured| {
+        if let Some(captured) = captured {
+            captured.push(Diagnostic {
+                name,
+                value: value.into(),
+            });
+        }
+    });
+}
+
+/// Runs `f` and returns its result with all diagnostics it recorded, in order.
+pub fn capture<T>(f: impl FnOnce() -> T) -> (T, Vec<Diagnostic>) {
+    let outer = CAPTURED.replace(Some(Vec::new()));
+    let result = f();
+    let diagnostics = CAPTURED.replace(outer).unwrap_or_default();
+
+    (result, diagnostics)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn captures_only_inside_capture() {
+        record("ignored", 1);
+
+        let (answer, diagnostics) = capture(|| {
+            record("size", 3);
+            record("axis", "horizontal");
+            42
+        });
+
+        assert_eq!(answer, 42);
+        assert_eq!(
+            diagnostics,
+            vec![
+                Diagnostic {
+                    name: "size",
+                    value: Answer::Int(3)
+                },
+                Diagnostic {
+                    name: "axis",
+                    value: Answer::Str("horizontal".to_string())
+                },
+            ]
+        );
+        assert!(capture(|| ()).1.is_empty());
+    }
+}
@@ -0,0 +1,186 @@
+use std::{
+    fmt::Debug,
+    panic::{self, AssertUnwindSafe},
+};
+
+use crate::{grid::Grid, Rng};
+
+/// An input on which the optimised implementation disagrees with the
+/// reference, shrunk as far as possible.
+#[derive(Debug)]
+pub struct Counterexample<I, O> {
+    /// The seed of the case that first disagreed.
+    pub seed: u64,
+    pub input: I,
+    pub reference: O,
+    /// The optimised result, or its panic message.
+    pub optimised: Result<O, String>,
+}
+
+/// Runs `reference` and `optimised` side by side on `cases` inputs from
+/// `generate`, seeded `0..cases`. The first disagreement is shrunk by
+/// repeatedly taking the first candidate from `shrink` that still disagrees.
+///
+/// `shrink` must only return valid inputs: a candidate the reference panics on
+/// doesn't count as a disagreement.
+pub fn find_counterexample<I, O>(
+    cases: u64,
+    generate: impl Fn(&mut Rng) -> I,
+    shrink: impl Fn(&I) -> Vec<I>,
+    reference: impl Fn(&I) -> O,
+    optimised: impl Fn(&I) -> O,
+) -> Option<Counterexample<I, O>>
+where
+    O: PartialEq,
+{
+    let disagreement = |input: &I| {
+        let expected = catch(|| reference(input)).ok()?;
+        let actual = catch(|| optimised(input));
+
+        (actual.as_ref() != Ok(&expected)).then_some((expected, actual))
+    };
+
+    for seed in 0..cases {
+        let mut input = generate(&mut Rng::new(seed));
+
+        let Some(mut outcome) = disagreement(&input) else {
+            continue;
+        };
+
+        'shrinking: loop {
+            for candidate in shrink(&input) {
+                if let Some(candidate_outcome) = disagreement(&candidate) {
+                    input = candidate;
+                    outcome = candidate_outcome;
+                    continue 'shrinking;
+                }
+            }
+
+            break;
+        }
+
+        let (reference, optimised) = outcome;
+
+        return Some(Counterexample {
+            seed,
+            input,
+            reference,
+            optimised,
+        });
+    }
+
+    None
+}
+
+/// Like [`find_counterexample`], but panics with the counterexample.
+pub fn assert_equivalent<I, O>(
+    cases: u64,
+    generate: impl Fn(&mut Rng) -> I,
+    shrink: impl Fn(&I) -> Vec<I>,
+    reference: impl Fn(&I) -> O,
+    optimised: impl Fn(&I) -> O,
+) where
+    I: Debug,
+    O: PartialEq + Debug,
+{
+    if let Some(counterexample) = find_counterexample(cases, generate, shrink, reference, optimised)
+    {
+        panic!("Implementations disagree: {counterexample:#?}");
+    }
+}
+
+fn catch<O>(f: impl FnOnce() -> O) -> Result<O, String> {
+    panic::catch_unwind(AssertUnwindSafe(f)).map_err(|payload| {
+        payload
+            .downcast_ref::<&str>()
+            .map(|msg| msg.to_string())
+            .or_else(|| payload.downcast_ref::<String>().cloned())
+            .unwrap_or_else(|| "Panicked".to_string())
+    })
+}
+
+/// Shrinks a list by dropping its first or second half, then by dropping
+/// single items.
+pub fn shrink_vec<T: Clone>(items: &[T]) -> Vec<Vec<T>> {
+    let mut smaller = Vec::new();
+
+    if items.len() > 1 {
+        let half = items.len() / 2;
+        smaller.push(items[half..].to_vec());
+        smaller.push(items[..half].to_vec());
+    }
+
+    for idx in 0..items.len() {
+        let mut without = items.to_vec();
+        without.remove(idx);
+        smaller.push(without);
+    }
+
+    smaller
+}
+
+/// Shrinks a grid one cell at a time, replacing each cell `simpler` has a
+/// simpler value for.
+pub fn shrink_cells<T: Clone>(grid: &Grid<T>, simpler: impl Fn(&T) -> Option<T>) -> Vec<Grid<T>> {
+    grid.iter()
+        .filter_map(|(pos, cell)| {
+            let cell = simpler(cell)?;
+            let mut grid = grid.clone();
+            grid[pos] = cell;
+
+            Some(grid)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn shrinks_to_a_minimal_counterexample() {
+        let counterexample = find_counterexample(
+            100,
+            |rng| (0..20).map(|_| rng.int(0..=20)).collect::<Vec<_>>(),
+            |items| shrink_vec(items),
+            |items| items.iter().sum::<i64>(),
+            |items| items.iter().filter(|n| **n != 13).sum::<i64>(),
+        )
+        .expect("Finds the missing 13");
+
+        assert_eq!(counterexample.input, vec![13]);
+        assert_eq!(counterexample.reference, 13);
+        assert_eq!(counterexample.optimised, Ok(0));
+    }
+
+    #[test]
+    fn reports_panics_as_disagreements() {
+        let counterexample = find_counterexample(
+            10,
+            |rng| (0..5).map(|_| rng.int(1..=9)).collect::<Vec<_>>(),
+            |items| shrink_vec(items),
+            |items| items.len(),
+            |items| {
+                assert!(items.len() < 2, "Too long");
+                items.len()
+            },
+        )
+        .expect("Panics on the first case");
+
+        assert_eq!(counterexample.seed, 0);
+        assert_eq!(counterexample.input.len(), 2);
+        assert_eq!(counterexample.optimised, Err("Too long".to_string()));
+    }
+
+    #[test]
+    fn agrees_without_a_counterexample() {
+        assert!(find_counterexample(
+            10,
+            |rng| rng.int(0..=100),
+            |_| Vec::new(),
+            |n| n * 2,
+            |n| n + n,
+        )
+        .is_none());
+    }
+}
@@ -0,0 +1,96 @@
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Direction {
+    /// All directions in clockwise order, starting with `Up`.
+    pub const ALL: [Direction; 4] = [
+        Direction::Up,
+        Direction::Right,
+        Direction::Down,
+        Direction::Left,
+    ];
+
+    /// The `(dx, dy)` offset of a single step, with `y` growing downwards.
+    pub fn offset(&self) -> (isize, isize) {
+        match self {
+            Direction::Up => (0, -1),
+            Direction::Right => (1, 0),
+            Direction::Down => (0, 1),
+            Direction::Left => (-1, 0),
+        }
+    }
+
+    pub fn opposite(&self) -> Direction {
+        match self {
+            Direction::Up => Direction::Down,
+            Direction::Right => Direction::Left,
+            Direction::Down => Direction::Up,
+            Direction::Left => Direction::Right,
+        }
+    }
+
+    pub fn turn_right(&self) -> Direction {
+        match self {
+            Direction::Up => Direction::Right,
+            Direction::Right => Direction::Down,
+            Direction::Down => Direction::Left,
+            Direction::Left => Direction::Up,
+        }
+    }
+
+    pub fn turn_left(&self) -> Direction {
+        match self {
+            Direction::Up => Direction::Left,
+            Direction::Right => Direction::Up,
+            Direction::Down => Direction::Right,
+            Direction::Left => Direction::Down,
+        }
+    }
+
+    pub fn is_vertical(&self) -> bool {
+        matches!(self, Direction::Up | Direction::Down)
+    }
+
+    pub fn is_horizontal(&self) -> bool {
+        matches!(self, Direction::Left | Direction::Right)
+    }
+}
+
+/// Offsets of the 8 surrounding cells, clockwise starting with the one above.
+pub const OFFSETS_8: [(isize, isize); 8] = [
+    (0, -1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+    (0, 1),
+    (-1, 1),
+    (-1, 0),
+    (-1, -1),
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn turns_and_opposites() {
+        for (idx, dir) in Direction::ALL.into_iter().enumerate() {
+            assert_eq!(dir.turn_right(), Direction::ALL[(idx + 1) % 4]);
+            assert_eq!(dir.turn_left(), Direction::ALL[(idx + 3) % 4]);
+            assert_eq!(dir.opposite(), Direction::ALL[(idx + 2) % 4]);
+            assert_eq!(dir.turn_left().turn_left(), dir.opposite());
+            assert_eq!(dir.is_vertical(), !dir.is_horizontal());
+
+            let (dx, dy) = dir.offset();
+            assert_eq!(dir.opposite().offset(), (-dx, -dy));
+        }
+
+        assert_eq!(Direction::Up.turn_right(), Direction::Right);
+        assert_eq!(Direction::Up.turn_left(), Direction::Left);
+    }
+}
@@ -0,0 +1,113 @@
+use std::{error::Error, fmt, str::FromStr};
+
+/// An error found while parsing puzzle input, pointing at the offending text.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub message: String,
+    pub text: String,
+    /// 1-based line of the offending text, 0 if it couldn't be located.
+    pub line: usize,
+    /// 1-based column of the offending text, 0 if it couldn't be located.
+    pub column: usize,
+    address: usize,
+}
+
+impl ParseError {
+    /// Creates an error for `text`, which should be a slice of the puzzle
+    /// input so [`ParseError::locate`] can find its line and column.
+    pub fn new(text: &str, message: impl Into<String>) -> Self {
+        ParseError {
+            message: message.into(),
+            text: text.to_string(),
+            line: 0,
+            column: 0,
+            address: text.as_ptr() as usize,
+        }
+    }
+
+    /// Fills in the line and column of the offending text within `input`.
+    pub fn locate(mut self, input: &str) -> Self {
+        if self.line != 0 {
+            return self;
+        }
+
+        let start = input.as_ptr() as usize;
+
+        let offset = if (start..=start + input.len()).contains(&self.address)
+            && input.is_char_boundary(self.address - start)
+        {
+            Some(self.address - start)
+        } else if !self.text.is_empty() {
+            input.find(&self.text)
+        } else {
+            None
+        };
+
+        if let Some(offset) = offset {
+            let before = &input[..offset];
+            let line_start = before.rfind('\n').map(|i| i + 1).unwrap_or(0);
+
+            self.line = before.matches('\n').count() + 1;
+            self.column = before[line_start..].chars().count() + 1;
+        }
+
+        self
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.line != 0 {
+            write!(f, "line {}, column {}: ", self.line, self.column)?;
+        }
+
+        write!(f, "{}", self.message)?;
+
+        if self.text.is_empty() {
+            write!(f, " at end of input")
+        } else {
+            write!(f, " in '{}'", self.text)
+        }
+    }
+}
+
+impl Error for ParseError {}
+
+/// Parses `text` as a number, e.g. `parse_num::<u32>(" 12")` fails on the space.
+pub fn parse_num<T: FromStr>(text: &str) -> Result<T, ParseError> {
+    text.parse()
+        .map_err(|_| ParseError::new(text, "Expected a number"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn locates_slices_of_the_input() {
+        let input = "12 34\n56 x8\n";
+        let err = ParseError::new(&input[9..11], "Expected a number").locate(input);
+
+        assert_eq!((err.line, err.column), (2, 4));
+        assert_eq!(
+            err.to_string(),
+            "line 2, column 4: Expected a number in 'x8'"
+        );
+    }
+
+    #[test]
+    fn locates_copied_text_by_searching() {
+        let input = "a\nbcd\n";
+        let err = ParseError::new(&String::from("cd"), "Unexpected text").locate(input);
+
+        assert_eq!((err.line, err.column), (2, 2));
+    }
+
+    #[test]
+    fn keeps_unknown_positions_at_zero() {
+        let err = ParseError::new("zz", "Unexpected text").locate("abc");
+
+        assert_eq!((err.line, err.column), (0, 0));
+        assert_eq!(err.to_string(), "Unexpected text in 'zz'");
+    }
+}
@@ -0,0 +1,270 @@
+use std::{fmt, ops::RangeInclusive, str::FromStr};
+
+use crate::grid::Grid;
+
+/// A small deterministic random number generator (SplitMix64), so the same
+/// seed generates the same input on every platform and version.
+#[derive(Debug, Clone)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Rng { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+
+        z ^ (z >> 31)
+    }
+
+    /// A number in `range`, which must not be empty.
+    pub fn int(&mut self, range: RangeInclusive<i64>) -> i64 {
+        let (start, end) = range.into_inner();
+        assert!(start <= end, "Empty range {start}..={end}");
+
+        let span = end.abs_diff(start) as u128 + 1;
+
+        start.wrapping_add((self.next_u64() as u128 % span) as i64)
+    }
+
+    /// An index below `len`, which must not be 0.
+    pub fn index(&mut self, len: usize) -> usize {
+        assert!(len > 0, "No index below 0");
+
+        (self.next_u64() % len as u64) as usize
+    }
+
+    pub fn pick<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.index(items.len())]
+    }
+
+    /// `true` with a probability of `p`.
+    pub fn chance(&mut self, p: f64) -> bool {
+        (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64 <= p
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for idx in (1..items.len()).rev() {
+            items.swap(idx, self.index(idx + 1));
+        }
+    }
+}
+
+/// How big a generated input should be. `Large` is about the size of a real
+/// puzzle input, the others are smaller.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Size {
+    Small,
+    #[default]
+    Medium,
+    Large,
+}
+
+impl Size {
+    pub const ALL: [Size; 3] = [Size::Small, Size::Medium, Size::Large];
+
+    /// Picks the value for this size.
+    pub fn pick<T>(self, small: T, medium: T, large: T) -> T {
+        match self {
+            Size::Small => small,
+            Size::Medium => medium,
+            Size::Large => large,
+        }
+    }
+}
+
+impl FromStr for Size {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "small" => Ok(Size::Small),
+            "medium" => Ok(Size::Medium),
+            "large" => Ok(Size::Large),
+            _ => Err(format!(
+                "Unknown size '{s}', expected small, medium or large"
+            )),
+        }
+    }
+}
+
+impl fmt::Display for Size {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let text = match self {
+            Size::Small => "small",
+            Size::Medium => "medium",
+            Size::Large => "large",
+        };
+
+        write!(f, "{text}")
+    }
+}
+
+/// Grows a random region of up to `cells` cells within a `width` by `height`
+/// grid. The region has no holes and no cells that only touch at a corner, so
+/// its [`outline`] is a single loop that never touches itself.
+pub fn region(rng: &mut Rng, width: usize, height: usize, cells: usize) -> Grid<bool> {
+    let mut region = Grid::new(width, height, false);
+    let start = (rng.index(width), rng.index(height));
+    region[start] = true;
+
+    let mut members = vec![start];
+
+    for _ in 0..cells * 50 {
+        if members.len() >= cells {
+            break;
+        }
+
+        let from = *rng.pick(&members);
+        let Some(pos) = region
+            .neighbours4(from)
+            .nth(rng.index(4))
+            .filter(|pos| !region[*pos])
+        else {
+            continue;
+        };
+
+        if keeps_outline_simple(&region, pos) {
+            region[pos] = true;
+            members.push(pos);
+        }
+    }
+
+    region
+}
+
+/// Whether adding `pos` to the region keeps it free of holes and of corners
+/// only touching, which is when its neighbours in the region form one run
+/// around it that includes a side neighbour.
+fn keeps_outline_simple(region: &Grid<bool>, pos: (usize, usize)) -> bool {
+    const RING: [(isize, isize); 8] = [
+        (-1, -1),
+        (0, -1),
+        (1, -1),
+        (1, 0),
+        (1, 1),
+        (0, 1),
+        (-1, 1),
+        (-1, 0),
+    ];
+
+    let inside = RING.map(|offset| region.get_offset(pos, offset) == Some(&true));
+
+    let runs = (0..8)
+        .filter(|&i| inside[i] && !inside[(i + 7) % 8])
+        .count();
+    let has_side = (1..8).step_by(2).any(|i| inside[i]);
+
+    runs == 1 && has_side
+}
+
+/// The corners along the outline of a [`region`], one step apart, going
+/// clockwise from the top left corner of its first cell. Corner `(x, y)` is
+/// the top left corner of cell `(x, y)`.
+pub fn outline(region: &Grid<bool>) -> Vec<(usize, usize)> {
+    let inside = |x: usize, y: usize, dx: isize, dy: isize| {
+        region.get_offset((x, y), (dx, dy)) == Some(&true)
+    };
+    // The corners one step away along the outline, which runs between a
+    // cell in the region and one outside of it
+    let next = |(x, y): (usize, usize)| {
+        let mut next = Vec::new();
+
+        if inside(x, y, 0, -1) != inside(x, y, 0, 0) {
+            next.push((x + 1, y));
+        }
+        if inside(x, y, 0, 0) != inside(x, y, -1, 0) {
+            next.push((x, y + 1));
+        }
+        if x > 0 && inside(x, y, -1, -1) != inside(x, y, -1, 0) {
+            next.push((x - 1, y));
+        }
+        if y > 0 && inside(x, y, -1, -1) != inside(x, y, 0, -1) {
+            next.push((x, y - 1));
+        }
+
+        next
+    };
+
+    let Some(start) = region.position(|cell| *cell) else {
+        return Vec::new();
+    };
+
+    let mut corners = vec![start];
+    let mut current = (start.0 + 1, start.1);
+
+    while current != start {
+        let prev = corners[corners.len() - 1];
+        corners.push(current);
+
+        current = next(current)
+            .into_iter()
+            .find(|corner| *corner != prev)
+            .expect("Outline is a loop");
+    }
+
+    corners
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn same_seed_same_numbers() {
+        let mut a = Rng::new(42);
+        let mut b = Rng::new(42);
+
+        for _ in 0..100 {
+            assert_eq!(a.next_u64(), b.next_u64());
+        }
+        assert_ne!(Rng::new(1).next_u64(), Rng::new(2).next_u64());
+    }
+
+    #[test]
+    fn ints_stay_in_range() {
+        let mut rng = Rng::new(7);
+
+        for _ in 0..1000 {
+            assert!((-3..=5).contains(&rng.int(-3..=5)));
+        }
+        assert_eq!(rng.int(4..=4), 4);
+        rng.int(i64::MIN..=i64::MAX);
+    }
+
+    #[test]
+    fn outlines_regions_as_one_loop() {
+        for seed in 0..20 {
+            let mut rng = Rng::new(seed);
+            let region = region(&mut rng, 8, 6, 20);
+            let corners = outline(&region);
+
+            // Every cell adds 4 to the outline, minus 2 for each side it shares
+            let cells = region.iter().filter(|(_, cell)| **cell).count();
+            let shared = region
+                .iter()
+                .filter(|(_, cell)| **cell)
+                .map(|(pos, _)| {
+                    [(1, 0), (0, 1)]
+                        .into_iter()
+                        .filter(|offset| region.get_offset(pos, *offset) == Some(&true))
+                        .count()
+                })
+                .sum::<usize>();
+
+            assert_eq!(corners.len(), 4 * cells - 2 * shared);
+
+            let mut distinct = corners.clone();
+            distinct.sort_unstable();
+            distinct.dedup();
+            assert_eq!(distinct.len(), corners.len());
+        }
+    }
+}
@@ -0,0 +1,542 @@
+use std::{
+    collections::{BinaryHeap, HashMap, VecDeque},
+    fmt::Write,
+    hash::Hash,
+};
+
+/// The index of a node in a [`Graph`], in the order the nodes were added.
+pub type NodeId = usize;
+
+/// A graph whose nodes are any hashable values, interned to a [`NodeId`] when
+/// they are first added, with edges weighted by `W`. Parallel edges are kept.
+#[derive(Debug, Clone)]
+pub struct Graph<N, W = ()> {
+    directed: bool,
+    nodes: Vec<N>,
+    index: HashMap<N, NodeId>,
+    /// The edges leaving each node, in the order they were added. Undirected
+    /// edges are listed at both of their nodes.
+    edges: Vec<Vec<(NodeId, W)>>,
+}
+
+/// The cheapest way to split a graph in two, see [`Graph::min_cut`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MinCut {
+    /// The total weight of the edges between the two sides.
+    pub weight: u64,
+    /// The nodes on one side, all others are on the other.
+    pub side: Vec<NodeId>,
+}
+
+impl<N, W> Graph<N, W>
+where
+    N: Clone + Eq + Hash,
+    W: Clone,
+{
+    pub fn directed() -> Self {
+        Self::new(true)
+    }
+
+    pub fn undirected() -> Self {
+        Self::new(false)
+    }
+
+    fn new(directed: bool) -> Self {
+        Self {
+            directed,
+            nodes: Vec::new(),
+            index: HashMap::new(),
+            edges: Vec::new(),
+        }
+    }
+
+    pub fn is_directed(&self) -> bool {
+        self.directed
+    }
+
+    /// The id of `node`, adding it if it is new.
+    pub fn add_node(&mut self, node: N) -> NodeId {
+        if let Some(&id) = self.index.get(&node) {
+            return id;
+        }
+
+        let id = self.nodes.len();
+        self.index.insert(node.clone(), id);
+        self.nodes.push(node);
+        self.edges.push(Vec::new());
+
+        id
+    }
+
+    /// Adds an edge, and its nodes if they are new.
+    pub fn add_edge(&mut self, from: N, to: N, weight: W) -> (NodeId, NodeId) {
+        let (from, to) = (self.add_node(from), self.add_node(to));
+
+        if !self.directed && from != to {
+            self.edges[to].push((from, weight.clone()));
+        }
+        self.edges[from].push((to, weight));
+
+        (from, to)
+    }
+
+    pub fn id(&self, node: &N) -> Option<NodeId> {
+        self.index.get(node).copied()
+    }
+
+    pub fn node(&self, id: NodeId) -> &N {
+        &self.nodes[id]
+    }
+
+    pub fn nodes(&self) -> &[N] {
+        &self.nodes
+    }
+
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    /// The `(to, weight)` edges leaving `id`.
+    pub fn edges(&self, id: NodeId) -> &[(NodeId, W)] {
+        &self.edges[id]
+    }
+
+    pub fn neighbours(&self, id: NodeId) -> impl Iterator<Item = NodeId> + '_ {
+        self.edges[id].iter().map(|(to, _)| *to)
+    }
+
+    pub fn contains_edge(&self, from: &N, to: &N) -> bool {
+        match (self.id(from), self.id(to)) {
+            (Some(from), Some(to)) => self.neighbours(from).any(|next| next == to),
+            _ => false,
+        }
+    }
+
+    /// The same graph with every edge pointing the other way. The node ids
+    /// stay the same.
+    pub fn reversed(&self) -> Self {
+        let mut edges = vec![Vec::new(); self.len()];
+
+        for (from, out) in self.edges.iter().enumerate() {
+            for (to, weight) in out {
+                edges[*to].push((from, weight.clone()));
+            }
+        }
+
+        Self {
+            edges,
+            ..self.clone()
+        }
+    }
+
+    /// The number of edges on the shortest path from `start` to every node,
+    /// `None` for the ones it can't reach.
+    pub fn bfs(&self, start: NodeId) -> Vec<Option<usize>> {
+        let mut distances = vec![None; self.len()];
+        distances[start] = Some(0);
+
+        let mut queue = VecDeque::from([start]);
+
+        while let Some(id) = queue.pop_front() {
+            let distance = distances[id].map(|d| d + 1);
+
+            for next in self.neighbours(id) {
+                if distances[next].is_none() {
+                    distances[next] = distance;
+                    queue.push_back(next);
+                }
+            }
+        }
+
+        distances
+    }
+
+    /// The nodes reachable from `start`, in the order a depth-first search
+    /// first visits them.
+    pub fn dfs(&self, start: NodeId) -> Vec<NodeId> {
+        let mut visited = vec![false; self.len()];
+        let mut order = Vec::new();
+        let mut stack = vec![start];
+
+        while let Some(id) = stack.pop() {
+            if visited[id] {
+                continue;
+            }
+            visited[id] = true;
+            order.push(id);
+
+            // Reversed, so the first neighbour is visited first
+            stack.extend(
+                self.edges[id]
+                    .iter()
+                    .rev()
+                    .map(|(next, _)| *next)
+                    .filter(|next| !visited[*next]),
+            );
+        }
+
+        order
+    }
+
+    /// The nodes ordered so that every edge points forward, or `None` if the
+    /// graph has a cycle. Nodes that could go in any order stay in the order
+    /// they were added.
+    pub fn topological_sort(&self) -> Option<Vec<NodeId>> {
+        let mut in_degrees = vec![0; self.len()];
+        for id in 0..self.len() {
+            for next in self.neighbours(id) {
+                in_degrees[next] += 1;
+            }
+        }
+
+        let mut queue = (0..self.len())
+            .filter(|id| in_degrees[*id] == 0)
+            .collect::<VecDeque<_>>();
+        let mut order = Vec::new();
+
+        while let Some(id) = queue.pop_front() {
+            order.push(id);
+
+            for next in self.neighbours(id) {
+                in_degrees[next] -= 1;
+                if in_degrees[next] == 0 {
+                    queue.push_back(next);
+                }
+            }
+        }
+
+        (order.len() == self.len()).then_some(order)
+    }
+
+    /// The groups of nodes that can all reach each other, with Tarjan's
+    /// algorithm. A group comes before all groups that can reach it. For an
+    /// undirected graph, these are its connected components.
+    pub fn strongly_connected_components(&self) -> Vec<Vec<NodeId>> {
+        const UNVISITED: usize = usize::MAX;
+
+        let mut index = vec![UNVISITED; self.len()];
+        let mut low_link = vec![0; self.len()];
+        let mut on_stack = vec![false; self.len()];
+        let mut stack = Vec::new();
+        let mut next_index = 0;
+        let mut components = Vec::new();
+
+        for root in 0..self.len() {
+            if index[root] != UNVISITED {
+                continue;
+            }
+
+            // The recursion of the usual formulation, as (node, next edge)
+            let mut calls = vec![(root, 0)];
+            index[root] = next_index;
+            low_link[root] = next_index;
+            next_index += 1;
+            stack.push(root);
+            on_stack[root] = true;
+
+            while let Some(&(id, edge)) = calls.last() {
+                if let Some(&(next, _)) = self.edges[id].get(edge) {
+                    calls.last_mut().unwrap().1 += 1;
+
+                    if index[next] == UNVISITED {
+                        index[next] = next_index;
+                        low_link[next] = next_index;
+                        next_index += 1;
+                        stack.push(next);
+                        on_stack[next] = true;
+                        calls.push((next, 0));
+                    } else if on_stack[next] {
+                        low_link[id] = low_link[id].min(index[next]);
+                    }
+
+                    continue;
+                }
+
+                calls.pop();
+
+                if let Some(&(caller, _)) = calls.last() {
+                    low_link[caller] = low_link[caller].min(low_link[id]);
+                }
+
+                if low_link[id] == index[id] {
+                    let mut component = Vec::new();
+
+                    loop {
+                        let member = stack.pop().unwrap();
+                        on_stack[member] = false;
+                        component.push(member);
+
+                        if member == id {
+                            break;
+                        }
+                    }
+
+                    components.push(component);
+                }
+            }
+        }
+
+        components
+    }
+
+    /// The cheapest set of edges whose removal splits the graph in two, with
+    /// the Stoer-Wagner algorithm. `weight` gives the cost of removing an edge.
+    /// `None` for graphs with fewer than two nodes. Panics for directed graphs.
+    pub fn min_cut(&self, weight: impl Fn(&W) -> u64) -> Option<MinCut> {
+        assert!(!self.directed, "Min cuts need an undirected graph");
+
+        if self.len() < 2 {
+            return None;
+        }
+
+        // The weights between the merged nodes, and the original nodes each
+        // of them stands for
+        let mut weights = self
+            .edges
+            .iter()
+            .enumerate()
+            .map(|(from, out)| {
+                let mut merged = HashMap::new();
+                for (to, w) in out.iter().filter(|(to, _)| *to != from) {
+                    *merged.entry(*to).or_insert(0) += weight(w);
+                }
+                merged
+            })
+            .collect::<Vec<HashMap<NodeId, u64>>>();
+        let mut groups = (0..self.len()).map(|id| vec![id]).collect::<Vec<_>>();
+        let mut active = (0..self.len()).collect::<Vec<_>>();
+        let mut best: Option<MinCut> = None;
+
+        while active.len() > 1 {
+            // Add the node most tightly connected to the ones added so far
+            // until all are, the last two are then the cheapest to split
+            let mut connection = vec![0; self.len()];
+            let mut added = vec![false; self.len()];
+            let mut queue = active.iter().map(|id| (0, *id)).collect::<BinaryHeap<_>>();
+            let (mut before_last, mut last) = (active[0], active[0]);
+            let mut cut_weight = 0;
+
+            while let Some((connected, id)) = queue.pop() {
+                if added[id] || connected != connection[id] {
+                    continue;
+                }
+
+                added[id] = true;
+                (before_last, last) = (last, id);
+                cut_weight = connected;
+
+                for (&next, &w) in weights[id].iter() {
+                    if !added[next] {
+                        connection[next] += w;
+                        queue.push((connection[next], next));
+                    }
+                }
+            }
+
+            if best.as_ref().is_none_or(|best| cut_weight < best.weight) {
+                best = Some(MinCut {
+                    weight: cut_weight,
+                    side: groups[last].clone(),
+                });
+            }
+
+            // Merge the last node into the one before
+            let last_group = std::mem::take(&mut groups[last]);
+            groups[before_last].extend(last_group);
+
+            for (next, w) in std::mem::take(&mut weights[last]) {
+                weights[next].remove(&last);
+
+                if next != before_last {
+                    *weights[before_last].entry(next).or_insert(0) += w;
+                    *weights[next].entry(before_last).or_insert(0) += w;
+                }
+            }
+
+            active.retain(|id| *id != last);
+        }
+
+        best
+    }
+
+    /// The graph in Graphviz's DOT language, with the nodes labelled by
+    /// `node_label` and the edges by `edge_label` if it gives one.
+    pub fn to_dot(
+        &self,
+        node_label: impl Fn(&N) -> String,
+        edge_label: impl Fn(&W) -> Option<String>,
+    ) -> String {
+        let (keyword, arrow) = if self.directed {
+            ("digraph", "->")
+        } else {
+            ("graph", "--")
+        };
+
+        let mut dot = format!("{keyword} {{\n");
+
+        for (id, node) in self.nodes.iter().enumerate() {
+            writeln!(dot, "    {id} [label={:?}];", node_label(node)).unwrap();
+        }
+
+        for (from, out) in self.edges.iter().enumerate() {
+            // Undirected edges are listed at both nodes but drawn once
+            for (to, weight) in out.iter().filter(|(to, _)| self.directed || *to >= from) {
+                match edge_label(weight) {
+                    Some(label) => writeln!(dot, "    {from} {arrow} {to} [label={label:?}];"),
+                    None => writeln!(dot, "    {from} {arrow} {to};"),
+                }
+                .unwrap();
+            }
+        }
+
+        dot.push_str("}\n");
+
+        dot
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn directed(edges: &[(&'static str, &'static str)]) -> Graph<&'static str> {
+        let mut graph = Graph::directed();
+        for &(from, to) in edges {
+            graph.add_edge(from, to, ());
+        }
+        graph
+    }
+
+    fn names(graph: &Graph<&'static str>, ids: &[NodeId]) -> Vec<&'static str> {
+        ids.iter().map(|id| *graph.node(*id)).collect()
+    }
+
+    #[test]
+    fn interns_nodes() {
+        let mut graph = Graph::undirected();
+        let (a, b) = graph.add_edge("a", "b", 3);
+        graph.add_edge("b", "c", 4);
+
+        assert_eq!(graph.add_node("a"), a);
+        assert_eq!(graph.id(&"b"), Some(b));
+        assert_eq!(graph.len(), 3);
+        assert_eq!(graph.edges(b), &[(a, 3), (2, 4)]);
+        assert!(graph.contains_edge(&"c", &"b"));
+        assert!(!graph.contains_edge(&"a", &"c"));
+    }
+
+    #[test]
+    fn searches_in_order() {
+        let graph = directed(&[("a", "b"), ("a", "c"), ("b", "d"), ("c", "d"), ("e", "a")]);
+        let a = graph.id(&"a").unwrap();
+
+        assert_eq!(graph.bfs(a), vec![Some(0), Some(1), Some(1), Some(2), None]);
+        assert_eq!(names(&graph, &graph.dfs(a)), ["a", "b", "d", "c"]);
+        assert_eq!(names(&graph, &graph.reversed().dfs(a)), ["a", "e"]);
+    }
+
+    #[test]
+    fn sorts_topologically() {
+        let graph = directed(&[("shirt", "tie"), ("tie", "jacket"), ("trousers", "shoes")]);
+        let order = graph.topological_sort().unwrap();
+
+        assert_eq!(
+            names(&graph, &order),
+            ["shirt", "trousers", "tie", "shoes", "jacket"]
+        );
+
+        let cyclic = directed(&[("a", "b"), ("b", "c"), ("c", "a")]);
+        assert_eq!(cyclic.topological_sort(), None);
+    }
+
+    #[test]
+    fn finds_strongly_connected_components() {
+        let graph = directed(&[
+            ("a", "b"),
+            ("b", "c"),
+            ("c", "a"),
+            ("c", "d"),
+            ("d", "e"),
+            ("e", "d"),
+            ("f", "e"),
+        ]);
+
+        let mut components = graph
+            .strongly_connected_components()
+            .iter()
+            .map(|component| {
+                let mut names = names(&graph, component);
+                names.sort();
+                names
+            })
+            .collect::<Vec<_>>();
+
+        // Components that are reached come first
+        assert_eq!(components[0], ["d", "e"]);
+
+        components.sort();
+        assert_eq!(components, [vec!["a", "b", "c"], vec!["d", "e"], vec!["f"]]);
+    }
+
+    #[test]
+    fn cuts_between_dense_groups() {
+        let mut graph = Graph::undirected();
+
+        // Two groups of five where everything is connected, joined by three
+        // edges
+        for group in [0, 5] {
+            for a in group..group + 5 {
+                for b in a + 1..group + 5 {
+                    graph.add_edge(a, b, ());
+                }
+            }
+        }
+        for (a, b) in [(0, 5), (1, 6), (2, 5)] {
+            graph.add_edge(a, b, ());
+        }
+
+        let cut = graph.min_cut(|_| 1).unwrap();
+        let mut side = cut
+            .side
+            .iter()
+            .map(|id| *graph.node(*id))
+            .collect::<Vec<_>>();
+        side.sort();
+
+        assert_eq!(cut.weight, 3);
+        assert!(side == [0, 1, 2, 3, 4] || side == [5, 6, 7, 8, 9]);
+    }
+
+    #[test]
+    fn min_cut_uses_weights() {
+        let mut graph = Graph::undirected();
+        graph.add_edge("a", "b", 10);
+        graph.add_edge("b", "c", 2);
+        graph.add_edge("c", "d", 10);
+        graph.add_edge("d", "a", 3);
+
+        let cut = graph.min_cut(|w| *w).unwrap();
+
+        assert_eq!(cut.weight, 5);
+        assert_eq!(Graph::<u8>::undirected().min_cut(|_| 1), None);
+    }
+
+    #[test]
+    fn exports_dot() {
+        let mut graph = Graph::undirected();
+        graph.add_edge("a", "b", 1);
+        graph.add_edge("b", "\"c\"", 2);
+
+        let dot = graph.to_dot(|node| node.to_string(), |w| Some(format!("{w} m")));
+
+        assert_eq!(
+            dot,
+            "graph {\n    0 [label=\"a\"];\n    1 [label=\"b\"];\n    2 [label=\"\\\"c\\\"\"];\n    \
+             0 -- 1 [label=\"1 m\"];\n    1 -- 2 [label=\"2 m\"];\n}\n"
+        );
+    }
+}
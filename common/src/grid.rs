@@ -0,0 +1,355 @@
+use std::ops::{Index, IndexMut};
+
+use crate::{
+    direction::{Direction, OFFSETS_8},
+    error::ParseError,
+};
+
+/// A rectangular grid stored row by row. Positions are `(x, y)` with `(0, 0)`
+/// in the top left corner.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Grid {
+            width,
+            height,
+            cells: vec![value; width * height],
+        }
+    }
+
+    /// Builds a grid from its rows, or `None` if the rows differ in length.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Option<Self> {
+        let height = rows.len();
+        let width = rows.first().map(|r| r.len()).unwrap_or(0);
+
+        if rows.iter().any(|r| r.len() != width) {
+            return None;
+        }
+
+        Some(Grid {
+            width,
+            height,
+            cells: rows.into_iter().flatten().collect(),
+        })
+    }
+
+    /// Parses one row per line, mapping every char to a cell. Fails on chars
+    /// `f` returns `None` for and on rows of differing length.
+    pub fn parse(input: &str, f: impl FnMut(char) -> Option<T>) -> Result<Self, ParseError> {
+        let mut f = f;
+
+        let mut width = None;
+        let mut height = 0;
+        let mut cells = Vec::new();
+
+        for line in input.lines() {
+            for (idx, c) in line.char_indices() {
+                let Some(cell) = f(c) else {
+                    return Err(ParseError::new(
+                        &line[idx..idx + c.len_utf8()],
+                        "Unexpected character",
+                    ));
+                };
+
+                cells.push(cell);
+            }
+
+            let line_width = line.chars().count();
+
+            if *width.get_or_insert(line_width) != line_width {
+                return Err(ParseError::new(
+                    line,
+                    "Row length differs from the first row",
+                ));
+            }
+
+            height += 1;
+        }
+
+        Ok(Grid {
+            width: width.unwrap_or(0),
+            height,
+            cells,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, (x, y): (usize, usize)) -> bool {
+        x < self.width && y < self.height
+    }
+
+    pub fn get(&self, pos: (usize, usize)) -> Option<&T> {
+        if self.contains(pos) {
+            Some(&self.cells[pos.1 * self.width + pos.0])
+        } else {
+            None
+        }
+    }
+
+    pub fn get_mut(&mut self, pos: (usize, usize)) -> Option<&mut T> {
+        if self.contains(pos) {
+            Some(&mut self.cells[pos.1 * self.width + pos.0])
+        } else {
+            None
+        }
+    }
+
+    /// Moves `pos` by `(dx, dy)`, or `None` if that leaves the grid.
+    pub fn offset(
+        &self,
+        (x, y): (usize, usize),
+        (dx, dy): (isize, isize),
+    ) -> Option<(usize, usize)> {
+        let pos = (x.checked_add_signed(dx)?, y.checked_add_signed(dy)?);
+
+        if self.contains(pos) {
+            Some(pos)
+        } else {
+            None
+        }
+    }
+
+    pub fn get_offset(&self, pos: (usize, usize), offset: (isize, isize)) -> Option<&T> {
+        self.offset(pos, offset).map(|p| &self[p])
+    }
+
+    /// Takes one step from `pos` in `dir`, or `None` if that leaves the grid.
+    pub fn step(&self, pos: (usize, usize), dir: Direction) -> Option<(usize, usize)> {
+        self.offset(pos, dir.offset())
+    }
+
+    /// Number of steps that can be taken from `pos` in `dir` before reaching the edge.
+    pub fn distance_to_edge(&self, (x, y): (usize, usize), dir: Direction) -> usize {
+        match dir {
+            Direction::Up => y,
+            Direction::Right => self.width - 1 - x,
+            Direction::Down => self.height - 1 - y,
+            Direction::Left => x,
+        }
+    }
+
+    /// The up to 4 orthogonally adjacent positions, clockwise starting with the one above.
+    pub fn neighbours4(&self, pos: (usize, usize)) -> impl Iterator<Item = (usize, usize)> + '_ {
+        Direction::ALL
+            .into_iter()
+            .filter_map(move |dir| self.step(pos, dir))
+    }
+
+    /// The up to 8 adjacent positions including diagonals, clockwise starting with the one above.
+    pub fn neighbours8(&self, pos: (usize, usize)) -> impl Iterator<Item = (usize, usize)> + '_ {
+        OFFSETS_8
+            .into_iter()
+            .filter_map(move |offset| self.offset(pos, offset))
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn row_mut(&mut self, y: usize) -> &mut [T] {
+        &mut self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        assert!(x < self.width, "Column out of bounds");
+
+        self.cells.iter().skip(x).step_by(self.width)
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        (0..self.height).map(|y| self.row(y))
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|x| self.column(x))
+    }
+
+    /// All positions in row-major order.
+    pub fn positions(&self) -> impl Iterator<Item = (usize, usize)> {
+        let width = self.width;
+
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| (x, y)))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = ((usize, usize), &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    /// The first position in row-major order whose cell matches `predicate`.
+    pub fn position(&self, predicate: impl Fn(&T) -> bool) -> Option<(usize, usize)> {
+        self.iter().find(|(_, t)| predicate(t)).map(|(pos, _)| pos)
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+
+    pub fn transpose(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        self.rebuild(self.height, self.width, |x, y| (y, x))
+    }
+
+    pub fn rotate_clockwise(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        self.rebuild(self.height, self.width, |x, y| (y, self.height - 1 - x))
+    }
+
+    pub fn rotate_counter_clockwise(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        self.rebuild(self.height, self.width, |x, y| (self.width - 1 - y, x))
+    }
+
+    /// Builds a `width` x `height` grid where every cell is copied from the
+    /// position `source(x, y)` of this grid.
+    fn rebuild(
+        &self,
+        width: usize,
+        height: usize,
+        source: impl Fn(usize, usize) -> (usize, usize),
+    ) -> Grid<T>
+    where
+        T: Clone,
+    {
+        let cells = (0..height)
+            .flat_map(|y| (0..width).map(move |x| (x, y)))
+            .map(|(x, y)| self[source(x, y)].clone())
+            .collect();
+
+        Grid {
+            width,
+            height,
+            cells,
+        }
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: (usize, usize)) -> &T {
+        self.get(pos).expect("Grid position out of bounds")
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, pos: (usize, usize)) -> &mut T {
+        self.get_mut(pos).expect("Grid position out of bounds")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A 3 x 2 grid numbered row by row.
+    fn numbered() -> Grid<u8> {
+        Grid::parse("012\n345\n", |c| c.to_digit(10).map(|d| d as u8)).unwrap()
+    }
+
+    #[test]
+    fn parses_rectangles_only() {
+        let grid = numbered();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[(2, 1)], 5);
+
+        let empty = Grid::parse("", Some).unwrap();
+        assert_eq!((empty.width(), empty.height()), (0, 0));
+        assert_eq!(empty.positions().count(), 0);
+
+        assert!(Grid::parse("ab\nc\n", Some).is_err());
+        assert!(Grid::parse("ab\nabc\n", Some).is_err());
+        assert!(Grid::parse("0x\n", |c| c.to_digit(10)).is_err());
+    }
+
+    #[test]
+    fn neighbours_go_clockwise_from_above() {
+        let grid = Grid::new(3, 3, ());
+
+        assert_eq!(
+            grid.neighbours4((0, 0)).collect::<Vec<_>>(),
+            [(1, 0), (0, 1)]
+        );
+        assert_eq!(
+            grid.neighbours4((1, 0)).collect::<Vec<_>>(),
+            [(2, 0), (1, 1), (0, 0)]
+        );
+        assert_eq!(
+            grid.neighbours4((1, 1)).collect::<Vec<_>>(),
+            [(1, 0), (2, 1), (1, 2), (0, 1)]
+        );
+
+        assert_eq!(
+            grid.neighbours8((2, 2)).collect::<Vec<_>>(),
+            [(2, 1), (1, 2), (1, 1)]
+        );
+        assert_eq!(
+            grid.neighbours8((0, 1)).collect::<Vec<_>>(),
+            [(0, 0), (1, 0), (1, 1), (1, 2), (0, 2)]
+        );
+        assert_eq!(grid.neighbours8((1, 1)).count(), 8);
+    }
+
+    #[test]
+    fn rotates_and_transposes_rectangles() {
+        let grid = numbered();
+
+        let rows = |grid: &Grid<u8>| grid.rows().map(<[u8]>::to_vec).collect::<Vec<_>>();
+
+        assert_eq!(rows(&grid.transpose()), [[0, 3], [1, 4], [2, 5]]);
+        assert_eq!(rows(&grid.rotate_clockwise()), [[3, 0], [4, 1], [5, 2]]);
+        assert_eq!(
+            rows(&grid.rotate_counter_clockwise()),
+            [[2, 5], [1, 4], [0, 3]]
+        );
+        assert_eq!(grid.rotate_clockwise().rotate_counter_clockwise(), grid);
+        assert_eq!(grid.transpose().transpose(), grid);
+    }
+
+    #[test]
+    fn steps_stop_at_the_edges() {
+        let grid = numbered();
+
+        assert_eq!(grid.step((0, 0), Direction::Up), None);
+        assert_eq!(grid.step((0, 0), Direction::Left), None);
+        assert_eq!(grid.step((2, 1), Direction::Right), None);
+        assert_eq!(grid.step((2, 1), Direction::Down), None);
+        assert_eq!(grid.step((2, 0), Direction::Down), Some((2, 1)));
+
+        assert_eq!(grid.offset((1, 1), (1, -1)), Some((2, 0)));
+        assert_eq!(grid.offset((1, 1), (2, 0)), None);
+        assert_eq!(grid.offset((1, 1), (-2, 0)), None);
+        assert_eq!(grid.get_offset((0, 0), (2, 1)), Some(&5));
+
+        assert_eq!(grid.distance_to_edge((0, 0), Direction::Up), 0);
+        assert_eq!(grid.distance_to_edge((0, 0), Direction::Right), 2);
+        assert_eq!(grid.distance_to_edge((0, 0), Direction::Down), 1);
+        assert_eq!(grid.distance_to_edge((2, 1), Direction::Left), 2);
+        assert_eq!(grid.distance_to_edge((2, 1), Direction::Right), 0);
+    }
+}
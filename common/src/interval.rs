@@ -0,0 +1,338 @@
+use std::ops::{Add, Mul, Range, Sub};
+
+/// A set of values made of half-open ranges. The ranges are kept sorted,
+/// non-empty and coalesced, so no two of them overlap or touch.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct IntervalSet<T> {
+    ranges: Vec<Range<T>>,
+}
+
+impl<T> Default for IntervalSet<T> {
+    fn default() -> Self {
+        Self { ranges: Vec::new() }
+    }
+}
+
+impl<T: Copy + Ord> IntervalSet<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn ranges(&self) -> &[Range<T>] {
+        &self.ranges
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        let idx = self.ranges.partition_point(|range| range.end <= value);
+
+        self.ranges
+            .get(idx)
+            .is_some_and(|range| range.start <= value)
+    }
+
+    /// The smallest value in the set.
+    pub fn min(&self) -> Option<T> {
+        self.ranges.first().map(|range| range.start)
+    }
+
+    /// The number of values in the set.
+    pub fn len(&self) -> T
+    where
+        T: Add<Output = T> + Sub<Output = T> + Default,
+    {
+        self.ranges
+            .iter()
+            .fold(T::default(), |len, range| len + (range.end - range.start))
+    }
+
+    /// Adds a range, merging it with the ranges it overlaps or touches.
+    pub fn insert(&mut self, range: Range<T>) {
+        if range.is_empty() {
+            return;
+        }
+
+        // The ranges before `first` end before `range` starts, the ones from
+        // `last` on start after it ends
+        let first = self.ranges.partition_point(|r| r.end < range.start);
+        let last = self.ranges.partition_point(|r| r.start <= range.end);
+
+        let merged = if first < last {
+            range.start.min(self.ranges[first].start)..range.end.max(self.ranges[last - 1].end)
+        } else {
+            range
+        };
+
+        self.ranges.splice(first..last, [merged]);
+    }
+
+    pub fn union(&self, other: &Self) -> Self {
+        let mut union = self.clone();
+        for range in other.ranges.iter() {
+            union.insert(range.clone());
+        }
+
+        union
+    }
+
+    pub fn intersection(&self, other: &Self) -> Self {
+        let mut ranges = Vec::new();
+        let (mut i, mut j) = (0, 0);
+
+        while let (Some(a), Some(b)) = (self.ranges.get(i), other.ranges.get(j)) {
+            let overlap = a.start.max(b.start)..a.end.min(b.end);
+            if !overlap.is_empty() {
+                ranges.push(overlap);
+            }
+
+            // Whichever ends first can't overlap anything further on
+            if a.end < b.end {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+
+        Self { ranges }
+    }
+
+    /// The values in `self` that aren't in `other`.
+    pub fn difference(&self, other: &Self) -> Self {
+        let mut ranges = Vec::new();
+        let mut j = 0;
+
+        for range in self.ranges.iter() {
+            let mut start = range.start;
+
+            // Skip the ranges of `other` that end before this one starts
+            while other.ranges.get(j).is_some_and(|b| b.end <= start) {
+                j += 1;
+            }
+
+            let mut k = j;
+            while let Some(b) = other.ranges.get(k).filter(|b| b.start < range.end) {
+                if b.start > start {
+                    ranges.push(start..b.start);
+                }
+                start = start.max(b.end);
+                k += 1;
+            }
+
+            if start < range.end {
+                ranges.push(start..range.end);
+            }
+        }
+
+        Self { ranges }
+    }
+
+    /// Splits the set into the values below `at` and the values from `at` on.
+    pub fn split_at(&self, at: T) -> (Self, Self) {
+        let mut below = Vec::new();
+        let mut above = Vec::new();
+
+        for range in self.ranges.iter() {
+            let (low, high) = split_range(range, at);
+
+            if !low.is_empty() {
+                below.push(low);
+            }
+            if !high.is_empty() {
+                above.push(high);
+            }
+        }
+
+        (Self { ranges: below }, Self { ranges: above })
+    }
+}
+
+impl<T: Copy + Ord> From<Range<T>> for IntervalSet<T> {
+    fn from(range: Range<T>) -> Self {
+        let mut set = Self::new();
+        set.insert(range);
+
+        set
+    }
+}
+
+impl<T: Copy + Ord> FromIterator<Range<T>> for IntervalSet<T> {
+    fn from_iter<I: IntoIterator<Item = Range<T>>>(iter: I) -> Self {
+        let mut set = Self::new();
+        for range in iter {
+            set.insert(range);
+        }
+
+        set
+    }
+}
+
+/// An axis-aligned box in `N` dimensions, a half-open range along each axis.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct HyperRect<T, const N: usize> {
+    pub sides: [Range<T>; N],
+}
+
+impl<T: Copy + Ord, const N: usize> HyperRect<T, N> {
+    pub fn new(sides: [Range<T>; N]) -> Self {
+        Self { sides }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.sides.iter().any(|side| side.is_empty())
+    }
+
+    pub fn contains(&self, point: &[T; N]) -> bool {
+        self.sides
+            .iter()
+            .zip(point)
+            .all(|(side, value)| side.contains(value))
+    }
+
+    /// The number of points in the box.
+    pub fn volume(&self) -> T
+    where
+        T: Sub<Output = T> + Mul<Output = T> + From<u8>,
+    {
+        if self.is_empty() {
+            return T::from(0);
+        }
+
+        self.sides
+            .iter()
+            .fold(T::from(1), |volume, side| volume * (side.end - side.start))
+    }
+
+    pub fn intersection(&self, other: &Self) -> Self {
+        Self {
+            sides: std::array::from_fn(|axis| {
+                let (a, b) = (&self.sides[axis], &other.sides[axis]);
+                a.start.max(b.start)..a.end.min(b.end)
+            }),
+        }
+    }
+
+    /// Splits the box along `axis` into the part below `at` and the part from
+    /// `at` on. Either may be empty.
+    pub fn split(&self, axis: usize, at: T) -> (Self, Self) {
+        let (low, high) = split_range(&self.sides[axis], at);
+
+        let mut below = self.clone();
+        let mut above = self.clone();
+        below.sides[axis] = low;
+        above.sides[axis] = high;
+
+        (below, above)
+    }
+}
+
+fn split_range<T: Copy + Ord>(range: &Range<T>, at: T) -> (Range<T>, Range<T>) {
+    let at = at.clamp(range.start, range.end.max(range.start));
+
+    (range.start..at, at..range.end)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeSet;
+
+    use super::*;
+    use crate::Rng;
+
+    const DOMAIN: i64 = 40;
+
+    fn random_set(rng: &mut Rng) -> IntervalSet<i64> {
+        (0..rng.int(0..=5))
+            .map(|_| {
+                let start = rng.int(0..=DOMAIN);
+                start..rng.int(start..=DOMAIN)
+            })
+            .collect()
+    }
+
+    fn values(set: &IntervalSet<i64>) -> BTreeSet<i64> {
+        (0..DOMAIN).filter(|value| set.contains(*value)).collect()
+    }
+
+    fn assert_coalesced(set: &IntervalSet<i64>) {
+        assert!(set.ranges().iter().all(|range| !range.is_empty()));
+        assert!(set
+            .ranges()
+            .windows(2)
+            .all(|pair| pair[0].end < pair[1].start));
+    }
+
+    #[test]
+    fn set_operations_match_sets_of_values() {
+        for seed in 0..500 {
+            let mut rng = Rng::new(seed);
+            let (a, b) = (random_set(&mut rng), random_set(&mut rng));
+            let (a_values, b_values) = (values(&a), values(&b));
+
+            let union = a.union(&b);
+            let intersection = a.intersection(&b);
+            let difference = a.difference(&b);
+
+            for set in [&a, &b, &union, &intersection, &difference] {
+                assert_coalesced(set);
+                assert_eq!(set.len() as usize, values(set).len());
+            }
+
+            assert_eq!(values(&union), &a_values | &b_values, "{a:?} | {b:?}");
+            assert_eq!(
+                values(&intersection),
+                &a_values & &b_values,
+                "{a:?} & {b:?}"
+            );
+            assert_eq!(values(&difference), &a_values - &b_values, "{a:?} - {b:?}");
+
+            let at = rng.int(-1..=DOMAIN + 1);
+            let (below, above) = a.split_at(at);
+            assert_coalesced(&below);
+            assert_coalesced(&above);
+            assert!(values(&below).iter().all(|value| *value < at));
+            assert!(values(&above).iter().all(|value| *value >= at));
+            assert_eq!(below.union(&above), a);
+        }
+    }
+
+    #[test]
+    fn coalesces_touching_ranges() {
+        let set = IntervalSet::from_iter([5..8, 0..2, 2..3, 7..10, 12..12]);
+
+        assert_eq!(set.ranges(), &[0..3, 5..10]);
+        assert_eq!(set.len(), 8);
+        assert_eq!(set.min(), Some(0));
+        assert!(set.contains(9) && !set.contains(10) && !set.contains(3));
+    }
+
+    #[test]
+    fn splitting_boxes_keeps_their_volume() {
+        for seed in 0..200 {
+            let mut rng = Rng::new(seed);
+            let rect = HyperRect::new([(); 3].map(|_| {
+                let start = rng.int(0..=10);
+                start..rng.int(start..=10)
+            }));
+            let axis = rng.index(3);
+            let at = rng.int(-1..=11);
+
+            let (below, above) = rect.split(axis, at);
+
+            assert_eq!(below.volume() + above.volume(), rect.volume());
+            assert!(below.intersection(&above).is_empty());
+
+            let point = [(); 3].map(|_| rng.int(0..=10));
+            assert_eq!(
+                rect.contains(&point),
+                below.contains(&point) || above.contains(&point)
+            );
+            assert_eq!(
+                below.contains(&point),
+                rect.contains(&point) && point[axis] < at
+            );
+        }
+    }
+}
@@ -0,0 +1,18 @@
+pub mod cycle;
+pub mod diagnostics;
+pub mod differential;
+pub mod direction;
+pub mod error;
+pub mod generate;
+pub mod graph;
+pub mod grid;
+pub mod interval;
+pub mod number;
+pub mod search;
+pub mod solution;
+
+pub use direction::Direction;
+pub use error::{parse_num, ParseError};
+pub use generate::{Rng, Size};
+pub use grid::Grid;
+pub use solution::{Answer, Solution, Solver};
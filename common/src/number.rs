@@ -0,0 +1,285 @@
+use std::{
+    fmt::Debug,
+    ops::{Add, Div, Mul, Rem, Sub},
+};
+
+/// An arbitrary-precision integer, for when even `i128` overflows.
+#[cfg(feature = "bigint")]
+pub use num_bigint::BigInt;
+
+/// The primitive integer types, so the functions here work on all of them.
+pub trait Integer:
+    Copy
+    + Ord
+    + Debug
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Rem<Output = Self>
+{
+    const ZERO: Self;
+    const ONE: Self;
+
+    fn abs(self) -> Self;
+    fn checked_add(self, other: Self) -> Option<Self>;
+    fn checked_sub(self, other: Self) -> Option<Self>;
+    fn checked_mul(self, other: Self) -> Option<Self>;
+    /// The square root, rounded down. Panics for negative numbers.
+    fn isqrt(self) -> Self;
+}
+
+macro_rules! impl_integer {
+    ($($int:ty),* => |$n:ident| $abs:expr) => {$(
+        impl Integer for $int {
+            const ZERO: Self = 0;
+            const ONE: Self = 1;
+
+            fn abs(self) -> Self {
+                let $n = self;
+                $abs
+            }
+
+            fn checked_add(self, other: Self) -> Option<Self> {
+                <$int>::checked_add(self, other)
+            }
+
+            fn checked_sub(self, other: Self) -> Option<Self> {
+                <$int>::checked_sub(self, other)
+            }
+
+            fn checked_mul(self, other: Self) -> Option<Self> {
+                <$int>::checked_mul(self, other)
+            }
+
+            fn isqrt(self) -> Self {
+                <$int>::isqrt(self)
+            }
+        }
+    )*};
+}
+
+impl_integer!(u8, u16, u32, u64, u128, usize => |n| n);
+impl_integer!(i8, i16, i32, i64, i128, isize => |n| n.abs());
+
+/// The greatest common divisor, which is never negative. `gcd(0, 0)` is `0`.
+pub fn gcd<T: Integer>(a: T, b: T) -> T {
+    let (mut a, mut b) = (a.abs(), b.abs());
+
+    while b != T::ZERO {
+        (a, b) = (b, a % b);
+    }
+
+    a
+}
+
+/// The least common multiple, which is never negative. Panics if it
+/// overflows, see [`checked_lcm`].
+pub fn lcm<T: Integer>(a: T, b: T) -> T {
+    checked_lcm(a, b).unwrap_or_else(|| panic!("lcm({a:?}, {b:?}) overflows"))
+}
+
+/// The least common multiple, or `None` if it overflows.
+pub fn checked_lcm<T: Integer>(a: T, b: T) -> Option<T> {
+    if a == T::ZERO || b == T::ZERO {
+        return Some(T::ZERO);
+    }
+
+    (a.abs() / gcd(a, b)).checked_mul(b.abs())
+}
+
+/// The greatest common divisor of all numbers, `0` for none.
+pub fn gcd_all<T: Integer>(nums: &[T]) -> T {
+    nums.iter().fold(T::ZERO, |acc, &num| gcd(acc, num))
+}
+
+/// The least common multiple of all numbers, `1` for none. Panics if it
+/// overflows.
+pub fn lcm_all<T: Integer>(nums: &[T]) -> T {
+    nums.iter().fold(T::ONE, |acc, &num| lcm(acc, num))
+}
+
+/// The square root if `n` is a perfect square.
+pub fn exact_sqrt<T: Integer>(n: T) -> Option<T> {
+    if n < T::ZERO {
+        return None;
+    }
+
+    let root = n.isqrt();
+    (root * root == n).then_some(root)
+}
+
+/// The greatest common divisor `g` of `a` and `b` together with `x` and `y`
+/// such that `a * x + b * y == g`.
+pub fn extended_gcd(a: i128, b: i128) -> (i128, i128, i128) {
+    // Invariants: a * x0 + b * y0 == r0 and a * x1 + b * y1 == r1
+    let (mut r0, mut r1) = (a, b);
+    let (mut x0, mut x1) = (1, 0);
+    let (mut y0, mut y1) = (0, 1);
+
+    while r1 != 0 {
+        let q = r0 / r1;
+        (r0, r1) = (r1, r0 - q * r1);
+        (x0, x1) = (x1, x0 - q * x1);
+        (y0, y1) = (y1, y0 - q * y1);
+    }
+
+    if r0 < 0 {
+        (-r0, -x0, -y0)
+    } else {
+        (r0, x0, y0)
+    }
+}
+
+/// The `x` in `0..modulus` with `a * x` congruent to `1`, if `a` and
+/// `modulus` are coprime.
+pub fn mod_inverse(a: i128, modulus: i128) -> Option<i128> {
+    let (g, x, _) = extended_gcd(a.rem_euclid(modulus), modulus);
+
+    (g == 1).then(|| x.rem_euclid(modulus))
+}
+
+/// `a * b % modulus` in `0..modulus`, without overflowing when the product
+/// doesn't fit.
+pub fn mul_mod(a: i128, b: i128, modulus: i128) -> i128 {
+    let (mut a, mut b) = (a.rem_euclid(modulus), b.rem_euclid(modulus));
+
+    if let Some(product) = a.checked_mul(b) {
+        return product % modulus;
+    }
+
+    let mut product = 0;
+    while b > 0 {
+        if b & 1 == 1 {
+            product = add_mod(product, a, modulus);
+        }
+        a = add_mod(a, a, modulus);
+        b >>= 1;
+    }
+
+    product
+}
+
+/// `(a + b) % modulus` for `a` and `b` in `0..modulus`, without overflowing.
+fn add_mod(a: i128, b: i128, modulus: i128) -> i128 {
+    if a >= modulus - b {
+        a - (modulus - b)
+    } else {
+        a + b
+    }
+}
+
+/// Solves `x ≡ residue (mod modulus)` for all `(residue, modulus)` pairs with
+/// positive moduli, which don't have to be coprime. Returns the smallest
+/// non-negative `x` and the least common multiple of the moduli, by multiples
+/// of which all other solutions differ from it. `None` if there is no solution
+/// or the least common multiple overflows.
+pub fn crt(congruences: &[(i128, i128)]) -> Option<(i128, i128)> {
+    congruences
+        .iter()
+        .try_fold((0, 1), |(x, modulus), &(residue, other_modulus)| {
+            let g = gcd(modulus, other_modulus);
+            let diff = residue.rem_euclid(other_modulus) - x;
+
+            if diff % g != 0 {
+                return None;
+            }
+
+            // x + modulus * k ≡ residue (mod other_modulus), divided by g
+            let reduced = other_modulus / g;
+            let k = mul_mod(diff / g, mod_inverse(modulus / g, reduced)?, reduced);
+            let combined = checked_lcm(modulus, other_modulus)?;
+
+            Some((
+                add_mod(x, mul_mod(modulus, k, combined), combined),
+                combined,
+            ))
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn gcd_and_lcm_of_mixed_signs() {
+        assert_eq!(gcd(12, 18), 6);
+        assert_eq!(gcd(-12, 18), 6);
+        assert_eq!(gcd(0, -7), 7);
+        assert_eq!(gcd(0u64, 0), 0);
+        assert_eq!(lcm(-4, 6), 12);
+        assert_eq!(lcm(0, 6), 0);
+        assert_eq!(gcd_all(&[24usize, 36, 60]), 12);
+        assert_eq!(lcm_all(&[2u64, 3, 4, 5]), 60);
+        assert_eq!(lcm_all::<u32>(&[]), 1);
+        assert_eq!(checked_lcm(u64::MAX, u64::MAX - 1), None);
+    }
+
+    #[test]
+    fn extended_gcd_gives_bezout_coefficients() {
+        for a in -30..30 {
+            for b in -30..30 {
+                let (g, x, y) = extended_gcd(a, b);
+
+                assert_eq!(g, gcd(a, b), "gcd({a}, {b})");
+                assert_eq!(a * x + b * y, g, "coefficients of ({a}, {b})");
+            }
+        }
+    }
+
+    #[test]
+    fn inverses_only_exist_for_coprimes() {
+        assert_eq!(mod_inverse(3, 7), Some(5));
+        assert_eq!(mod_inverse(-3, 7), Some(2));
+        assert_eq!(mod_inverse(4, 8), None);
+        assert_eq!(mul_mod(i128::MAX - 1, i128::MAX - 2, i128::MAX), 2);
+    }
+
+    #[test]
+    fn crt_matches_brute_force() {
+        for m1 in 1..12 {
+            for m2 in 1..12 {
+                for r1 in 0..m1 {
+                    for r2 in 0..m2 {
+                        let expected = (0..m1 * m2).find(|x| x % m1 == r1 && x % m2 == r2);
+
+                        assert_eq!(
+                            crt(&[(r1, m1), (r2, m2)]).map(|(x, _)| x),
+                            expected,
+                            "x ≡ {r1} (mod {m1}), x ≡ {r2} (mod {m2})"
+                        );
+                    }
+                }
+            }
+        }
+
+        assert_eq!(crt(&[(2, 3), (3, 5), (2, 7)]), Some((23, 105)));
+        assert_eq!(crt(&[(-1, 4), (3, 6)]), Some((3, 12)));
+    }
+
+    #[test]
+    fn square_roots_are_exact() {
+        assert_eq!(exact_sqrt(0u64), Some(0));
+        assert_eq!(exact_sqrt(49i32), Some(7));
+        assert_eq!(exact_sqrt(50i32), None);
+        assert_eq!(exact_sqrt(-4i64), None);
+
+        // Too big to survive a round trip through f64
+        let root = (1u64 << 53) + 1;
+        assert_eq!(exact_sqrt(root as u128 * root as u128), Some(root as u128));
+        assert_eq!(exact_sqrt(root as u128 * root as u128 - 1), None);
+    }
+
+    #[cfg(feature = "bigint")]
+    #[test]
+    fn big_integers_go_past_i128() {
+        let primes = [
+            2u32, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37, 41, 43, 47, 53, 59, 61, 67,
+        ];
+        let primorial = primes.iter().map(|&p| BigInt::from(p)).product::<BigInt>();
+        let squared = &primorial * &primorial;
+
+        assert!(i128::try_from(&squared).is_err());
+        assert_eq!(&squared / &primorial, primorial);
+    }
+}
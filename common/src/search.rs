@@ -0,0 +1,282 @@
+use std::{
+    cmp::Reverse,
+    collections::{BinaryHeap, HashMap},
+    hash::Hash,
+    ops::Add,
+};
+
+/// How much work a search did.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Stats {
+    /// States taken from the frontier to look at their successors.
+    pub expanded: usize,
+    /// The most states waiting in the frontier at once.
+    pub frontier_peak: usize,
+}
+
+/// The cheapest path found by a search, from the start to the goal.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Path<S, C> {
+    pub cost: C,
+    /// The states along the path, including the start and the goal.
+    pub states: Vec<S>,
+    pub stats: Stats,
+}
+
+/// The cheapest cost from the start to every state a search reached.
+#[derive(Debug, Clone)]
+pub struct Costs<S, C> {
+    states: Vec<S>,
+    index: HashMap<S, usize>,
+    costs: Vec<C>,
+    /// The index of the state each one was reached from, `usize::MAX` for the
+    /// start.
+    parents: Vec<usize>,
+    pub stats: Stats,
+}
+
+impl<S, C> Costs<S, C>
+where
+    S: Clone + Eq + Hash,
+    C: Copy,
+{
+    pub fn cost(&self, state: &S) -> Option<C> {
+        self.index.get(state).map(|&idx| self.costs[idx])
+    }
+
+    /// The cheapest path from the start to `state`, if it was reached.
+    pub fn path(&self, state: &S) -> Option<Path<S, C>> {
+        let &goal = self.index.get(state)?;
+        Some(self.path_to(goal))
+    }
+
+    fn path_to(&self, goal: usize) -> Path<S, C> {
+        let mut states = Vec::new();
+        let mut idx = goal;
+
+        while idx != usize::MAX {
+            states.push(self.states[idx].clone());
+            idx = self.parents[idx];
+        }
+        states.reverse();
+
+        Path {
+            cost: self.costs[goal],
+            states,
+            stats: self.stats,
+        }
+    }
+
+    /// The index of `state`, adding it if it is new.
+    fn intern(&mut self, state: S, cost: C, parent: usize) -> (usize, bool) {
+        if let Some(&idx) = self.index.get(&state) {
+            return (idx, false);
+        }
+
+        let idx = self.states.len();
+        self.index.insert(state.clone(), idx);
+        self.states.push(state);
+        self.costs.push(cost);
+        self.parents.push(parent);
+
+        (idx, true)
+    }
+}
+
+/// The cheapest path from `start` to a state for which `is_goal` holds. Costs
+/// must not be negative.
+pub fn dijkstra<S, C, I>(
+    start: S,
+    successors: impl FnMut(&S) -> I,
+    is_goal: impl FnMut(&S) -> bool,
+) -> Option<Path<S, C>>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Add<Output = C> + Default,
+    I: IntoIterator<Item = (S, C)>,
+{
+    astar(start, successors, |_| C::default(), is_goal)
+}
+
+/// Like [`dijkstra`], but expands the states with the lowest cost plus
+/// `heuristic` first. The path is only the cheapest if the heuristic never
+/// overestimates the cost left to a goal, and never drops by more than the
+/// cost of a step.
+pub fn astar<S, C, I>(
+    start: S,
+    successors: impl FnMut(&S) -> I,
+    heuristic: impl FnMut(&S) -> C,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> Option<Path<S, C>>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Add<Output = C> + Default,
+    I: IntoIterator<Item = (S, C)>,
+{
+    let (costs, goal) = search(start, successors, heuristic, |state| is_goal(state));
+
+    goal.map(|goal| costs.path_to(goal))
+}
+
+/// The cheapest cost to every state reachable from `start`. Unlike
+/// [`dijkstra`], costs may be negative as long as no cycle costs less than
+/// nothing, e.g. negated lengths to find the longest paths in a DAG. States
+/// are expanded again whenever a cheaper way to them turns up.
+pub fn costs_from<S, C, I>(start: S, successors: impl FnMut(&S) -> I) -> Costs<S, C>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Add<Output = C> + Default,
+    I: IntoIterator<Item = (S, C)>,
+{
+    search(start, successors, |_| C::default(), |_| false).0
+}
+
+/// Expands states cheapest first until one is a goal, returning all costs
+/// found so far and the index of the goal.
+fn search<S, C, I>(
+    start: S,
+    mut successors: impl FnMut(&S) -> I,
+    mut heuristic: impl FnMut(&S) -> C,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> (Costs<S, C>, Option<usize>)
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Add<Output = C> + Default,
+    I: IntoIterator<Item = (S, C)>,
+{
+    let mut costs = Costs {
+        states: Vec::new(),
+        index: HashMap::new(),
+        costs: Vec::new(),
+        parents: Vec::new(),
+        stats: Stats::default(),
+    };
+
+    let start_estimate = heuristic(&start);
+    let (start, _) = costs.intern(start, C::default(), usize::MAX);
+
+    // (estimated total cost, cost so far, state), cheapest estimate first
+    let mut frontier = BinaryHeap::from([Reverse((start_estimate, C::default(), start))]);
+    costs.stats.frontier_peak = 1;
+
+    while let Some(Reverse((_, cost, idx))) = frontier.pop() {
+        // A cheaper way to this state was found after this one was queued
+        if cost > costs.costs[idx] {
+            continue;
+        }
+
+        let state = costs.states[idx].clone();
+
+        if is_goal(&state) {
+            return (costs, Some(idx));
+        }
+
+        costs.stats.expanded += 1;
+
+        for (next, step_cost) in successors(&state) {
+            let next_cost = cost + step_cost;
+            let estimate = next_cost + heuristic(&next);
+            let (next, is_new) = costs.intern(next, next_cost, idx);
+
+            if is_new || next_cost < costs.costs[next] {
+                costs.costs[next] = next_cost;
+                costs.parents[next] = idx;
+                frontier.push(Reverse((estimate, next_cost, next)));
+            }
+        }
+
+        costs.stats.frontier_peak = costs.stats.frontier_peak.max(frontier.len());
+    }
+
+    (costs, None)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Grid;
+
+    const MAZE: &str = "\
+..#....
+.##.##.
+....#..
+.#.##.#
+...#...";
+
+    fn maze() -> Grid<bool> {
+        Grid::parse(MAZE, |c| Some(c == '#')).unwrap()
+    }
+
+    fn steps(maze: &Grid<bool>, pos: &(usize, usize)) -> Vec<((usize, usize), u32)> {
+        maze.neighbours4(*pos)
+            .filter(|next| !maze[*next])
+            .map(|next| (next, 1))
+            .collect()
+    }
+
+    #[test]
+    fn finds_the_cheapest_path() {
+        let maze = maze();
+        let end = (6, 4);
+
+        let path = dijkstra((0, 0), |pos| steps(&maze, pos), |pos| *pos == end).unwrap();
+
+        assert_eq!(path.cost, 16);
+        assert_eq!(path.states.len(), 17);
+        assert_eq!(path.states[0], (0, 0));
+        assert_eq!(path.states[16], end);
+        assert!(path
+            .states
+            .windows(2)
+            .all(|pair| pair[0].0.abs_diff(pair[1].0) + pair[0].1.abs_diff(pair[1].1) == 1));
+    }
+
+    #[test]
+    fn heuristic_expands_fewer_states() {
+        let field = Grid::new(10, 10, false);
+        let end: (usize, usize) = (9, 0);
+        let manhattan =
+            |pos: &(usize, usize)| (end.0.abs_diff(pos.0) + end.1.abs_diff(pos.1)) as u32;
+
+        let plain = dijkstra((0, 0), |pos| steps(&field, pos), |pos| *pos == end).unwrap();
+        let guided = astar(
+            (0, 0),
+            |pos| steps(&field, pos),
+            manhattan,
+            |pos| *pos == end,
+        )
+        .unwrap();
+
+        assert_eq!(guided.cost, 9);
+        assert_eq!(plain.cost, 9);
+        assert_eq!(guided.stats.expanded, 9);
+        assert!(plain.stats.expanded > 40);
+    }
+
+    #[test]
+    fn gives_up_on_unreachable_goals() {
+        let maze = maze();
+
+        assert_eq!(
+            dijkstra((0, 0), |pos| steps(&maze, pos), |pos| *pos == (2, 0)),
+            None
+        );
+    }
+
+    #[test]
+    fn finds_longest_paths_with_negative_costs() {
+        // 0 -> 1 -> 3 is shorter than 0 -> 2 -> 1 -> 3
+        let edges = |node: &u32| match node {
+            0 => vec![(1, -1), (2, -1)],
+            1 => vec![(3, -1)],
+            2 => vec![(1, -1)],
+            _ => vec![],
+        };
+
+        let costs = costs_from(0, edges);
+
+        assert_eq!(costs.cost(&3), Some(-3));
+        assert_eq!(costs.path(&3).unwrap().states, vec![0, 2, 1, 3]);
+        assert_eq!(costs.cost(&4), None);
+    }
+}
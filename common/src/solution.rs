@@ -0,0 +1,146 @@
+use std::{any::Any, fmt};
+
+use crate::{
+    error::ParseError,
+    generate::{Rng, Size},
+};
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Answer {
+    Int(i64),
+    Str(String),
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Int(n) => write!(f, "{n}"),
+            Answer::Str(s) => write!(f, "{s}"),
+        }
+    }
+}
+
+macro_rules! impl_answer_from_int {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(n: $t) -> Self {
+                    Answer::Int(i64::try_from(n).expect("Answer doesn't fit into an i64"))
+                }
+            }
+        )*
+    };
+}
+
+impl_answer_from_int!(i32, i64, isize, u32, u64, usize);
+
+impl From<String> for Answer {
+    fn from(s: String) -> Self {
+        Answer::Str(s)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(s: &str) -> Self {
+        Answer::Str(s.to_string())
+    }
+}
+
+/// A single day's puzzle. The input is parsed once and then shared by both parts.
+pub trait Solution {
+    type Parsed: 'static;
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed, ParseError>;
+
+    fn part1(&self, parsed: &Self::Parsed) -> Answer;
+
+    fn part2(&self, parsed: &Self::Parsed) -> Answer;
+
+    /// Number of parts the day has, day 25 only has one.
+    fn parts(&self) -> u32 {
+        2
+    }
+
+    /// Generates a random input the parser accepts, if the day has a generator.
+    fn generate(&self, _rng: &mut Rng, _size: Size) -> Option<String> {
+        None
+    }
+}
+
+/// Object safe version of [`Solution`] so days can be stored in a registry.
+pub trait Solver: Sync {
+    fn parts(&self) -> u32;
+
+    /// Parses the input, with the error located within `input`.
+    fn parse(&self, input: &str) -> Result<Box<dyn Any>, ParseError>;
+
+    /// Solves `part` for input previously returned by [`Solver::parse`].
+    fn solve(&self, parsed: &dyn Any, part: u32) -> Answer;
+
+    /// Generates a random input, see [`Solution::generate`].
+    fn generate(&self, rng: &mut Rng, size: Size) -> Option<String>;
+
+    /// Parses the input and solves `part` in one go.
+    fn run(&self, input: &str, part: u32) -> Result<Answer, ParseError> {
+        Ok(self.solve(self.parse(input)?.as_ref(), part))
+    }
+}
+
+impl<S: Solution + Sync> Solver for S {
+    fn parts(&self) -> u32 {
+        Solution::parts(self)
+    }
+
+    fn parse(&self, input: &str) -> Result<Box<dyn Any>, ParseError> {
+        match Solution::parse(self, input) {
+            Ok(parsed) => Ok(Box::new(parsed)),
+            Err(err) => Err(err.locate(input)),
+        }
+    }
+
+    fn solve(&self, parsed: &dyn Any, part: u32) -> Answer {
+        let parsed = parsed
+            .downcast_ref::<S::Parsed>()
+            .expect("Parsed input belongs to a different solution");
+
+        match part {
+            1 => self.part1(parsed),
+            2 => self.part2(parsed),
+            _ => panic!("There is no part {part}"),
+        }
+    }
+
+    fn generate(&self, rng: &mut Rng, size: Size) -> Option<String> {
+        Solution::generate(self, rng, size)
+    }
+}
+
+/// Generates one `#[test]` per entry that parses an example input with the
+/// solution and checks the answer of the given part, e.g.
+///
+/// ```ignore
+/// example_tests! {
+///     Day1,
+///     part1_example: part1(include_str!("example")) == 142,
+///     part2_example: part2(include_str!("example2")) == 281,
+/// }
+/// ```
+#[macro_export]
+macro_rules! example_tests {
+    ($solution:expr, $($name:ident: $part:ident($input:expr) == $expected:expr),+ $(,)?) => {
+        $(
+            #[test]
+            fn $name() {
+                let solution = $solution;
+                let input = $input;
+                let parsed = $crate::Solution::parse(&solution, input)
+                    .unwrap_or_else(|err| panic!("{}", err.locate(input)));
+
+                assert_eq!(
+                    $crate::Solution::$part(&solution, &parsed),
+                    $crate::Answer::from($expected)
+                );
+            }
+        )+
+    };
+}
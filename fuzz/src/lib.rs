@@ -0,0 +1,21 @@
+use aoc_common::Solver;
+
+/// Parses `input` with the solution for `year` and `day`. Any panic is a bug:
+/// malformed input has to be reported as a [`aoc_common::ParseError`], on a
+/// line that exists.
+pub fn parse(year: u32, day: u32, input: &str) {
+    let solution: &dyn Solver = match year {
+        2023 => aoc_2023::solution(day),
+        _ => aoc_2024::solution(day),
+    }
+    .expect("Fuzz target for a day without a solution");
+
+    if let Err(err) = solution.parse(input) {
+        assert!(
+            err.line <= input.lines().count() + 1,
+            "Error on line {} of {}",
+            err.line,
+            input.lines().count()
+        );
+    }
+}